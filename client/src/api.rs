@@ -0,0 +1,598 @@
+use crate::module_bindings::move_window;
+use crate::module_bindings::set_taskbar_policy;
+use crate::module_bindings::DbConnection;
+use crate::module_bindings::TaskbarTableAccess;
+use crate::taskbar_snapshot::Alignment;
+use crate::taskbar_snapshot::CombineButtons;
+use crate::taskbar_snapshot::Edge;
+use crate::taskbar_snapshot::TaskbarSnapshot;
+use eyre::Context;
+use eyre::OptionExt;
+use eyre::Result;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::json;
+use serde_json::Value;
+use spacetimedb_sdk::Table;
+use std::collections::BTreeMap;
+use std::net::SocketAddr;
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::RwLock;
+use std::thread::JoinHandle;
+use tiny_http::Header;
+use tiny_http::Method;
+use tiny_http::Response;
+use tiny_http::Server;
+
+const OPENAPI: &str = include_str!("../openapi.yaml");
+
+/// A taskbar as served by the API, with the ID the server assigned it.
+#[derive(Debug, Clone, Serialize)]
+pub struct ApiTaskbar {
+    pub id: u32,
+    #[serde(flatten)]
+    pub taskbar: TaskbarSnapshot,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WindowMoveBody {
+    pub machine: String,
+    pub window_id: u64,
+    pub target_taskbar_id: u32,
+}
+
+/// Settings to enforce, on `machine` or on every machine when it is left out.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskbarPolicyBody {
+    pub machine: Option<String>,
+    pub auto_hide: Option<bool>,
+    pub edge: Option<Edge>,
+    pub alignment: Option<Alignment>,
+    pub combine_buttons: Option<CombineButtons>,
+}
+
+/// What the API reads taskbars from and sends requests to.
+pub trait ApiBackend: Send + Sync {
+    fn taskbars(&self) -> Vec<ApiTaskbar>;
+    fn move_window(&self, body: WindowMoveBody) -> Result<()>;
+    fn set_taskbar_policy(&self, body: TaskbarPolicyBody) -> Result<()>;
+}
+
+impl ApiBackend for DbConnection {
+    fn taskbars(&self) -> Vec<ApiTaskbar> {
+        self.db
+            .taskbar()
+            .iter()
+            .map(|x| ApiTaskbar {
+                id: x.id,
+                taskbar: x.into(),
+            })
+            .collect()
+    }
+
+    fn move_window(&self, body: WindowMoveBody) -> Result<()> {
+        self.reducers
+            .move_window(body.machine, body.window_id, body.target_taskbar_id)?;
+        Ok(())
+    }
+
+    fn set_taskbar_policy(&self, body: TaskbarPolicyBody) -> Result<()> {
+        self.reducers.set_taskbar_policy(
+            body.machine,
+            body.auto_hide,
+            body.edge.map(Into::into),
+            body.alignment.map(Into::into),
+            body.combine_buttons.map(Into::into),
+        )?;
+        Ok(())
+    }
+}
+
+/// Serves through whichever connection to the server is current, so the API outlives reconnects.
+#[derive(Default)]
+pub struct LiveApiBackend {
+    connection: RwLock<Option<Arc<DbConnection>>>,
+}
+
+impl LiveApiBackend {
+    pub fn connected(&self, connection: Arc<DbConnection>) {
+        if let Ok(mut current) = self.connection.write() {
+            *current = Some(connection);
+        }
+    }
+
+    fn current(&self) -> Result<Arc<DbConnection>> {
+        self.connection
+            .read()
+            .ok()
+            .and_then(|x| x.clone())
+            .ok_or_eyre("Not connected to the server")
+    }
+}
+
+impl ApiBackend for LiveApiBackend {
+    fn taskbars(&self) -> Vec<ApiTaskbar> {
+        self.current().map(|x| x.taskbars()).unwrap_or_default()
+    }
+
+    fn move_window(&self, body: WindowMoveBody) -> Result<()> {
+        self.current()?.move_window(body)
+    }
+
+    fn set_taskbar_policy(&self, body: TaskbarPolicyBody) -> Result<()> {
+        self.current()?.set_taskbar_policy(body)
+    }
+}
+
+fn error(status: u16, message: impl std::fmt::Display) -> (u16, Value) {
+    (status, json!({ "error": message.to_string() }))
+}
+
+fn parse<T: for<'de> Deserialize<'de>>(body: &str) -> Result<T, (u16, Value)> {
+    serde_json::from_str(body).map_err(|e| error(400, format!("Invalid body: {e}")))
+}
+
+fn find_taskbar(backend: &dyn ApiBackend, id: &str) -> Result<ApiTaskbar, (u16, Value)> {
+    let id = id
+        .parse::<u32>()
+        .map_err(|_| error(400, format!("Invalid taskbar ID {id}")))?;
+    backend
+        .taskbars()
+        .into_iter()
+        .find(|x| x.id == id)
+        .ok_or_else(|| error(404, format!("No taskbar with ID {id}")))
+}
+
+/// Decodes a query string value, where `+` is a space and `%XX` a byte.
+fn percent_decode(value: &str) -> String {
+    let mut bytes = Vec::new();
+    let mut rest = value.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        rest = tail;
+        match byte {
+            b'+' => bytes.push(b' '),
+            b'%' => match rest
+                .get(..2)
+                .and_then(|x| std::str::from_utf8(x).ok())
+                .and_then(|x| u8::from_str_radix(x, 16).ok())
+            {
+                Some(decoded) => {
+                    bytes.push(decoded);
+                    rest = &rest[2..];
+                }
+                None => bytes.push(byte),
+            },
+            _ => bytes.push(byte),
+        }
+    }
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+/// Reads a token from `path`, ignoring the whitespace around it.
+pub fn read_token(path: &Path) -> Result<String> {
+    let token = std::fs::read_to_string(path)
+        .wrap_err_with(|| format!("Failed to read {}", path.display()))?;
+    let token = token.trim();
+    if token.is_empty() {
+        eyre::bail!("{} holds no token", path.display());
+    }
+    Ok(token.to_string())
+}
+
+fn header<'a>(headers: &'a [Header], name: &'static str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|x| x.field.equiv(name))
+        .map(|x| x.value.as_str())
+}
+
+/// Whether `host`, with or without a port, names this machine's loopback interface.
+fn is_local_host(host: &str) -> bool {
+    let name = match host.strip_prefix('[') {
+        Some(rest) => rest.split(']').next().unwrap_or_default(),
+        None => host.rsplit_once(':').map_or(host, |(name, _)| name),
+    };
+    name.eq_ignore_ascii_case("localhost")
+        || name
+            .parse::<std::net::IpAddr>()
+            .is_ok_and(|x| x.is_loopback())
+}
+
+/// Turns away what a web page could send from the user's browser, and anyone without the token.
+///
+/// The `Host` check stops DNS rebinding, the `Origin` check pages elsewhere, and requiring JSON
+/// forces a CORS preflight that the API never answers.
+fn check_request(
+    token: Option<&str>,
+    method: &Method,
+    headers: &[Header],
+) -> Result<(), (u16, Value)> {
+    if let Some(host) = header(headers, "Host").filter(|x| !is_local_host(x)) {
+        return Err(error(403, format!("Host {host} is not local")));
+    }
+    if let Some(origin) = header(headers, "Origin") {
+        let host = origin
+            .strip_prefix("http://")
+            .or_else(|| origin.strip_prefix("https://"));
+        if !host.is_some_and(is_local_host) {
+            return Err(error(
+                403,
+                format!("Requests from {origin} are not allowed"),
+            ));
+        }
+    }
+    if let Some(token) = token {
+        let sent = header(headers, "Authorization").and_then(|x| x.strip_prefix("Bearer "));
+        if sent != Some(token) {
+            return Err(error(
+                401,
+                "Send the API token as `Authorization: Bearer <token>`",
+            ));
+        }
+    }
+    if *method == Method::Post {
+        let content_type = header(headers, "Content-Type")
+            .and_then(|x| x.split(';').next())
+            .map(str::trim);
+        if !content_type.is_some_and(|x| x.eq_ignore_ascii_case("application/json")) {
+            return Err(error(415, "The body must be sent as application/json"));
+        }
+    }
+    Ok(())
+}
+
+/// Answers one request with a status and a JSON body.
+fn route(backend: &dyn ApiBackend, method: &Method, url: &str, body: &str) -> (u16, Value) {
+    let (path, query) = url.split_once('?').unwrap_or((url, ""));
+    let machine = query
+        .split('&')
+        .find_map(|x| x.strip_prefix("machine="))
+        .map(percent_decode);
+    let segments = path
+        .split('/')
+        .filter(|x| !x.is_empty())
+        .collect::<Vec<_>>();
+    let result = match (method, segments.as_slice()) {
+        (Method::Get, ["machines"]) => {
+            let mut machines = BTreeMap::<String, usize>::new();
+            for taskbar in backend.taskbars() {
+                *machines.entry(taskbar.taskbar.machine).or_default() += 1;
+            }
+            Ok((
+                200,
+                machines
+                    .into_iter()
+                    .map(|(name, taskbars)| json!({ "name": name, "taskbars": taskbars }))
+                    .collect(),
+            ))
+        }
+        (Method::Get, ["taskbars"]) => {
+            let mut taskbars = backend
+                .taskbars()
+                .into_iter()
+                .filter(|x| machine.as_ref().is_none_or(|m| x.taskbar.machine == *m))
+                .collect::<Vec<_>>();
+            taskbars.sort_by_key(|x| x.id);
+            Ok((200, json!(taskbars)))
+        }
+        (Method::Get, ["taskbars", id]) => find_taskbar(backend, id).map(|x| (200, json!(x))),
+        (Method::Get, ["taskbars", id, "apps"]) => {
+            find_taskbar(backend, id).map(|x| (200, json!(x.taskbar.apps)))
+        }
+        (Method::Post, ["window-moves"]) => parse(body).and_then(|body| {
+            backend
+                .move_window(body)
+                .map(|_| (202, json!({ "status": "requested" })))
+                .map_err(|e| error(502, e))
+        }),
+        (Method::Post, ["taskbar-policy"]) => parse(body).and_then(|body| {
+            backend
+                .set_taskbar_policy(body)
+                .map(|_| (202, json!({ "status": "requested" })))
+                .map_err(|e| error(502, e))
+        }),
+        (_, ["machines" | "taskbars" | "window-moves" | "taskbar-policy", ..]) => {
+            Err(error(405, format!("{method} is not allowed on {path}")))
+        }
+        _ => Err(error(404, format!("Nothing at {path}"))),
+    };
+    result.unwrap_or_else(|e| e)
+}
+
+/// Serves the API on `address` from a thread of its own, returning the address it is bound to.
+///
+/// Only loopback addresses are accepted, and requests need `token` when one is given.
+pub fn serve(
+    address: SocketAddr,
+    backend: Arc<dyn ApiBackend>,
+    token: Option<String>,
+) -> Result<(SocketAddr, JoinHandle<()>)> {
+    if !address.ip().is_loopback() {
+        eyre::bail!("The API only listens on loopback addresses, not {address}");
+    }
+    let server =
+        Server::http(address).map_err(|e| eyre::eyre!("Failed to listen on {address}: {e}"))?;
+    let address = server
+        .server_addr()
+        .to_ip()
+        .ok_or_else(|| eyre::eyre!("Not listening on an IP address"))?;
+    let handle = std::thread::spawn(move || {
+        for mut request in server.incoming_requests() {
+            let mut body = String::new();
+            let _ = request.as_reader().read_to_string(&mut body);
+            let response = if *request.method() == Method::Get && request.url() == "/openapi.yaml" {
+                Response::from_string(OPENAPI).with_header(
+                    Header::from_bytes("Content-Type", "application/yaml")
+                        .expect("Header is valid"),
+                )
+            } else {
+                let (status, json) =
+                    check_request(token.as_deref(), request.method(), request.headers())
+                        .map(|()| route(backend.as_ref(), request.method(), request.url(), &body))
+                        .unwrap_or_else(|e| e);
+                Response::from_string(json.to_string())
+                    .with_status_code(status)
+                    .with_header(
+                        Header::from_bytes("Content-Type", "application/json")
+                            .expect("Header is valid"),
+                    )
+            };
+            if let Err(e) = request.respond(response) {
+                tracing::warn!("Failed to answer an API request: {e}");
+            }
+        }
+    });
+    tracing::info!("Serving the API on http://{address}");
+    Ok((address, handle))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    /// Serves the taskbars of the API fixture, numbered from 1, and records the reducer calls
+    /// requests would make instead of making them.
+    struct FixtureBackend {
+        taskbars: Vec<ApiTaskbar>,
+        calls: Mutex<Vec<Value>>,
+    }
+
+    impl FixtureBackend {
+        fn new() -> Self {
+            let json = std::fs::read_to_string(
+                Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/api/taskbars.json"),
+            )
+            .unwrap();
+            let taskbars: Vec<TaskbarSnapshot> = serde_json::from_str(&json).unwrap();
+            FixtureBackend {
+                taskbars: taskbars
+                    .into_iter()
+                    .zip(1..)
+                    .map(|(taskbar, id)| ApiTaskbar { id, taskbar })
+                    .collect(),
+                calls: Mutex::default(),
+            }
+        }
+
+        fn call(&self, reducer: &str, args: impl Serialize) -> Result<()> {
+            self.calls
+                .lock()
+                .unwrap()
+                .push(json!({ "reducer": reducer, "args": args }));
+            Ok(())
+        }
+    }
+
+    impl ApiBackend for FixtureBackend {
+        fn taskbars(&self) -> Vec<ApiTaskbar> {
+            self.taskbars.clone()
+        }
+
+        fn move_window(&self, body: WindowMoveBody) -> Result<()> {
+            self.call("move_window", body)
+        }
+
+        fn set_taskbar_policy(&self, body: TaskbarPolicyBody) -> Result<()> {
+            self.call("set_taskbar_policy", body)
+        }
+    }
+
+    struct Api {
+        backend: Arc<FixtureBackend>,
+        url: String,
+    }
+
+    impl Api {
+        fn start(token: Option<&str>) -> Api {
+            let backend = Arc::new(FixtureBackend::new());
+            let (address, _) = serve(
+                "127.0.0.1:0".parse().unwrap(),
+                backend.clone(),
+                token.map(String::from),
+            )
+            .unwrap();
+            Api {
+                backend,
+                url: format!("http://{address}"),
+            }
+        }
+
+        fn request(&self, method: &str, path: &str) -> ureq::Request {
+            ureq::request(method, &format!("{}{path}", self.url))
+        }
+
+        fn get(&self, path: &str) -> (u16, Value) {
+            respond(self.request("GET", path).call())
+        }
+
+        fn post(&self, path: &str, body: Value) -> (u16, Value) {
+            respond(self.request("POST", path).send_json(body))
+        }
+
+        /// The reducer calls made so far, taking them out.
+        fn calls(&self) -> Vec<Value> {
+            std::mem::take(&mut *self.backend.calls.lock().unwrap())
+        }
+    }
+
+    fn respond(result: Result<ureq::Response, ureq::Error>) -> (u16, Value) {
+        let response = match result {
+            Ok(x) | Err(ureq::Error::Status(_, x)) => x,
+            Err(e) => panic!("{e}"),
+        };
+        (response.status(), response.into_json().unwrap())
+    }
+
+    fn window_move() -> Value {
+        json!({ "machine": "office-pc", "window_id": 4242, "target_taskbar_id": 2 })
+    }
+
+    #[test]
+    fn lists_machines_and_taskbars() {
+        let api = Api::start(None);
+        assert_eq!(
+            api.get("/machines"),
+            (
+                200,
+                json!([
+                    { "name": "build kiosk", "taskbars": 1 },
+                    { "name": "office-pc", "taskbars": 2 },
+                ])
+            )
+        );
+
+        let (status, taskbars) = api.get("/taskbars");
+        assert_eq!(status, 200);
+        let ids = taskbars
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|x| (x["id"].clone(), x["machine"].clone(), x["index"].clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            ids,
+            [
+                (json!(1), json!("office-pc"), json!(0)),
+                (json!(2), json!("office-pc"), json!(1)),
+                (json!(3), json!("build kiosk"), json!(0)),
+            ]
+        );
+        assert_eq!(taskbars[2]["settings"]["auto_hide"], true);
+
+        let (status, kiosk) = api.get("/taskbars?machine=build+kiosk");
+        assert_eq!(status, 200);
+        assert_eq!(kiosk.as_array().unwrap().len(), 1);
+        assert_eq!(kiosk[0]["id"], 3);
+    }
+
+    #[test]
+    fn lists_the_apps_of_a_taskbar() {
+        let api = Api::start(None);
+        assert_eq!(
+            api.get("/taskbars/1/apps"),
+            (200, json!(["Terminal", "Files — Downloads"]))
+        );
+        assert_eq!(api.get("/taskbars/9/apps").0, 404);
+        assert_eq!(api.get("/taskbars/first/apps").0, 400);
+        assert_eq!(api.get("/windows").0, 404);
+    }
+
+    #[test]
+    fn forwards_posts_to_reducers() {
+        let api = Api::start(None);
+        assert_eq!(
+            api.post("/window-moves", window_move()),
+            (202, json!({ "status": "requested" }))
+        );
+        assert_eq!(
+            api.calls(),
+            [json!({ "reducer": "move_window", "args": window_move() })]
+        );
+
+        let (status, _) = api.post(
+            "/taskbar-policy",
+            json!({ "auto_hide": true, "edge": "Top" }),
+        );
+        assert_eq!(status, 202);
+        assert_eq!(
+            api.calls(),
+            [json!({
+                "reducer": "set_taskbar_policy",
+                "args": {
+                    "machine": null,
+                    "auto_hide": true,
+                    "edge": "Top",
+                    "alignment": null,
+                    "combine_buttons": null,
+                },
+            })]
+        );
+
+        let (status, error) = api.post("/window-moves", json!({ "machine": "office-pc" }));
+        assert_eq!(status, 400);
+        assert!(
+            error["error"].as_str().unwrap().contains("window_id"),
+            "{error}"
+        );
+        assert_eq!(api.get("/window-moves").0, 405);
+        assert!(api.calls().is_empty());
+    }
+
+    #[test]
+    fn turns_away_requests_browsers_could_forge() {
+        let api = Api::start(None);
+        let post = |header: &str, value: &str| {
+            respond(
+                api.request("POST", "/window-moves")
+                    .set(header, value)
+                    .send_json(window_move()),
+            )
+            .0
+        };
+        assert_eq!(post("Origin", "https://example.com"), 403);
+        assert_eq!(post("Origin", "null"), 403);
+        assert_eq!(post("Host", "attacker.test:7878"), 403);
+        assert_eq!(post("Origin", "http://localhost:3000"), 202);
+
+        let text = api
+            .request("POST", "/window-moves")
+            .set("Content-Type", "text/plain")
+            .send_string(&window_move().to_string());
+        assert_eq!(respond(text).0, 415);
+        assert_eq!(api.calls().len(), 1);
+    }
+
+    #[test]
+    fn requires_the_token_when_one_is_set() {
+        let api = Api::start(Some("s3cret"));
+        let post = |authorization: &str| {
+            respond(
+                api.request("POST", "/window-moves")
+                    .set("Authorization", authorization)
+                    .send_json(window_move()),
+            )
+            .0
+        };
+        assert_eq!(api.get("/machines").0, 401);
+        assert_eq!(api.post("/window-moves", window_move()).0, 401);
+        assert_eq!(post("Bearer wrong"), 401);
+        assert_eq!(post("Bearer s3cret"), 202);
+        assert_eq!(
+            api.calls(),
+            [json!({ "reducer": "move_window", "args": window_move() })]
+        );
+    }
+
+    #[test]
+    fn refuses_to_listen_beyond_loopback() {
+        let error = serve(
+            "0.0.0.0:0".parse().unwrap(),
+            Arc::new(FixtureBackend::new()),
+            None,
+        )
+        .unwrap_err();
+        assert!(error.to_string().contains("loopback"), "{error}");
+    }
+}
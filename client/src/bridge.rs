@@ -0,0 +1,425 @@
+use crate::module_bindings::DbConnection;
+use crate::module_bindings::EventContext;
+use crate::module_bindings::Taskbar;
+use crate::module_bindings::TaskbarTableAccess;
+use crate::recording::RecordedSnapshot;
+use crate::taskbar_snapshot::TaskbarSnapshot;
+use chrono::DateTime;
+use chrono::Utc;
+use eyre::Context;
+use eyre::Result;
+use serde::Serialize;
+use spacetimedb_sdk::Event;
+use spacetimedb_sdk::Table;
+use spacetimedb_sdk::TableWithPrimaryKey;
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::mpsc::Sender;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// A change to the `taskbar` table, as delivered to sinks.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum TaskbarEvent {
+    Inserted {
+        at: DateTime<Utc>,
+        after: TaskbarSnapshot,
+    },
+    Updated {
+        at: DateTime<Utc>,
+        before: TaskbarSnapshot,
+        after: TaskbarSnapshot,
+    },
+    Deleted {
+        at: DateTime<Utc>,
+        before: TaskbarSnapshot,
+    },
+}
+
+pub trait EventSink {
+    fn send(&mut self, event: &TaskbarEvent) -> Result<()>;
+}
+
+/// Posts each event as JSON, retrying failed requests with exponential backoff.
+pub struct HttpSink {
+    url: String,
+    retries: u32,
+    backoff: Duration,
+}
+
+impl HttpSink {
+    pub fn new(url: String, retries: u32) -> Self {
+        HttpSink {
+            url,
+            retries,
+            backoff: Duration::from_millis(250),
+        }
+    }
+}
+
+/// Whether posting again could succeed, which it cannot when the receiver refused the event.
+fn is_transient(error: &ureq::Error) -> bool {
+    match error {
+        ureq::Error::Status(status, _) => *status == 429 || *status >= 500,
+        ureq::Error::Transport(_) => true,
+    }
+}
+
+impl EventSink for HttpSink {
+    fn send(&mut self, event: &TaskbarEvent) -> Result<()> {
+        let mut backoff = self.backoff;
+        let mut attempt = 0;
+        loop {
+            match ureq::post(&self.url).send_json(event) {
+                Ok(_) => return Ok(()),
+                Err(e) if attempt < self.retries && is_transient(&e) => {
+                    tracing::debug!(
+                        "Posting to {} failed, retrying in {backoff:?}: {e}",
+                        self.url
+                    );
+                    std::thread::sleep(backoff);
+                    backoff *= 2;
+                    attempt += 1;
+                }
+                Err(e) => {
+                    return Err(e).wrap_err_with(|| {
+                        format!("Failed to post to {} after {attempt} retries", self.url)
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Writes each event as a line of JSON to a Unix socket, reconnecting after a failed write.
+#[cfg(unix)]
+pub struct UnixSocketSink {
+    path: PathBuf,
+    stream: Option<std::os::unix::net::UnixStream>,
+}
+
+#[cfg(unix)]
+impl EventSink for UnixSocketSink {
+    fn send(&mut self, event: &TaskbarEvent) -> Result<()> {
+        let mut line = serde_json::to_vec(event)?;
+        line.push(b'\n');
+        let stream = match self.stream.as_mut() {
+            Some(stream) => stream,
+            None => self.stream.insert(
+                std::os::unix::net::UnixStream::connect(&self.path)
+                    .wrap_err_with(|| format!("Failed to connect to {}", self.path.display()))?,
+            ),
+        };
+        if let Err(e) = stream.write_all(&line) {
+            self.stream = None;
+            return Err(e).wrap_err_with(|| format!("Failed to write to {}", self.path.display()));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(unix)]
+fn unix_socket_sink(path: PathBuf) -> Result<Box<dyn EventSink>> {
+    Ok(Box::new(UnixSocketSink { path, stream: None }))
+}
+
+#[cfg(not(unix))]
+fn unix_socket_sink(_path: PathBuf) -> Result<Box<dyn EventSink>> {
+    eyre::bail!("Unix sockets are only supported on Unix, use `--http` or `--file` instead")
+}
+
+/// Appends each event as a line of JSON to a file.
+pub struct FileSink {
+    file: File,
+}
+
+impl FileSink {
+    pub fn open(path: &Path) -> Result<Self> {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .wrap_err_with(|| format!("Failed to open {}", path.display()))?;
+        Ok(FileSink { file })
+    }
+}
+
+impl EventSink for FileSink {
+    fn send(&mut self, event: &TaskbarEvent) -> Result<()> {
+        let mut line = serde_json::to_vec(event)?;
+        line.push(b'\n');
+        self.file.write_all(&line)?;
+        Ok(())
+    }
+}
+
+pub fn sinks(
+    http: &[String],
+    http_retries: u32,
+    unix_sockets: &[PathBuf],
+    files: &[PathBuf],
+) -> Result<Vec<Box<dyn EventSink>>> {
+    let mut sinks = Vec::<Box<dyn EventSink>>::new();
+    for url in http {
+        sinks.push(Box::new(HttpSink::new(url.clone(), http_retries)));
+    }
+    for path in unix_sockets {
+        sinks.push(unix_socket_sink(path.clone())?);
+    }
+    for path in files {
+        sinks.push(Box::new(FileSink::open(path)?));
+    }
+    if sinks.is_empty() {
+        eyre::bail!("Give at least one of `--http`, `--unix-socket` or `--file`");
+    }
+    Ok(sinks)
+}
+
+/// Sends `event` to every sink, logging rather than failing on the ones that cannot take it.
+pub fn deliver(sinks: &mut [Box<dyn EventSink>], event: &TaskbarEvent) {
+    for sink in sinks.iter_mut() {
+        if let Err(e) = sink.send(event) {
+            tracing::warn!("Failed to deliver taskbar event: {e:#}");
+        }
+    }
+}
+
+/// Turns the row callbacks of the `taskbar` table into events.
+struct TaskbarForwarder {
+    events: Sender<TaskbarEvent>,
+}
+
+impl TaskbarForwarder {
+    /// `initial` rows are the ones the subscription starts with, which are not changes.
+    fn inserted(&self, initial: bool, row: &Taskbar) {
+        if !initial {
+            let _ = self.events.send(TaskbarEvent::Inserted {
+                at: Utc::now(),
+                after: row.clone().into(),
+            });
+        }
+    }
+
+    fn updated(&self, old: &Taskbar, new: &Taskbar) {
+        // Every sync rewrites the row, changed or not
+        if old == new {
+            return;
+        }
+        let _ = self.events.send(TaskbarEvent::Updated {
+            at: Utc::now(),
+            before: old.clone().into(),
+            after: new.clone().into(),
+        });
+    }
+
+    fn deleted(&self, initial: bool, row: &Taskbar) {
+        if !initial {
+            let _ = self.events.send(TaskbarEvent::Deleted {
+                at: Utc::now(),
+                before: row.clone().into(),
+            });
+        }
+    }
+}
+
+/// Forwards the changes to the `taskbar` table to `events`, leaving out the rows the
+/// subscription starts with.
+pub fn forward_taskbar_events(connection: &DbConnection, events: Sender<TaskbarEvent>) {
+    let is_initial = |ctx: &EventContext| {
+        matches!(
+            ctx.event,
+            Event::SubscribeApplied | Event::UnsubscribeApplied
+        )
+    };
+    let forwarder = Arc::new(TaskbarForwarder { events });
+    let inserted = forwarder.clone();
+    connection
+        .db
+        .taskbar()
+        .on_insert(move |ctx: &EventContext, row: &Taskbar| {
+            inserted.inserted(is_initial(ctx), row)
+        });
+    let updated = forwarder.clone();
+    connection
+        .db
+        .taskbar()
+        .on_update(move |_ctx: &EventContext, old: &Taskbar, new: &Taskbar| {
+            updated.updated(old, new)
+        });
+    connection
+        .db
+        .taskbar()
+        .on_delete(move |ctx: &EventContext, row: &Taskbar| {
+            forwarder.deleted(is_initial(ctx), row)
+        });
+}
+
+/// The events the server would raise while syncing a recording, matching taskbars on machine and
+/// index as `sync_taskbars` does.
+pub fn replay_events(snapshots: &[RecordedSnapshot]) -> Vec<TaskbarEvent> {
+    let mut known = BTreeMap::<(String, u32), TaskbarSnapshot>::new();
+    let mut events = Vec::new();
+    for snapshot in snapshots {
+        for taskbar in &snapshot.taskbars {
+            let key = (taskbar.machine.clone(), taskbar.index);
+            match known.insert(key, taskbar.clone()) {
+                None => events.push(TaskbarEvent::Inserted {
+                    at: snapshot.at,
+                    after: taskbar.clone(),
+                }),
+                Some(before) if before != *taskbar => events.push(TaskbarEvent::Updated {
+                    at: snapshot.at,
+                    before,
+                    after: taskbar.clone(),
+                }),
+                Some(_) => {}
+            }
+        }
+    }
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    fn row(id: u32, apps: &[&str]) -> Taskbar {
+        TaskbarSnapshot {
+            machine: "office-pc".to_string(),
+            index: id - 1,
+            rect: Default::default(),
+            is_secondary: false,
+            apps: apps.iter().map(|x| x.to_string()).collect(),
+            settings: Default::default(),
+        }
+        .into_row(id)
+    }
+
+    /// Keeps the events it is sent, or fails them all.
+    struct FixtureSink {
+        received: Arc<Mutex<Vec<TaskbarEvent>>>,
+        broken: bool,
+    }
+
+    impl EventSink for FixtureSink {
+        fn send(&mut self, event: &TaskbarEvent) -> Result<()> {
+            eyre::ensure!(!self.broken, "sink is down");
+            self.received.lock().unwrap().push(event.clone());
+            Ok(())
+        }
+    }
+
+    /// The kind and app lists after and before the change of each event.
+    fn summary(events: &[TaskbarEvent]) -> Vec<(&str, Vec<String>, Vec<String>)> {
+        events
+            .iter()
+            .map(|x| match x {
+                TaskbarEvent::Inserted { after, .. } => ("inserted", after.apps.clone(), vec![]),
+                TaskbarEvent::Updated { before, after, .. } => {
+                    ("updated", after.apps.clone(), before.apps.clone())
+                }
+                TaskbarEvent::Deleted { before, .. } => ("deleted", vec![], before.apps.clone()),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn forwards_changes_but_not_the_initial_rows_or_rewrites() {
+        let (events, received) = std::sync::mpsc::channel();
+        let forwarder = TaskbarForwarder { events };
+        forwarder.inserted(true, &row(1, &["Terminal"]));
+        forwarder.inserted(false, &row(2, &[]));
+        forwarder.updated(&row(1, &["Terminal"]), &row(1, &["Terminal"]));
+        forwarder.updated(&row(1, &["Terminal"]), &row(1, &["Terminal", "Files"]));
+        forwarder.deleted(true, &row(3, &["Mail"]));
+        forwarder.deleted(false, &row(2, &[]));
+        drop(forwarder);
+
+        let events = received.into_iter().collect::<Vec<_>>();
+        let strings = |x: &[&str]| x.iter().map(|x| x.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            summary(&events),
+            vec![
+                ("inserted", vec![], vec![]),
+                (
+                    "updated",
+                    strings(&["Terminal", "Files"]),
+                    strings(&["Terminal"])
+                ),
+                ("deleted", vec![], vec![]),
+            ]
+        );
+        let TaskbarEvent::Updated { after, .. } = &events[1] else {
+            panic!("Expected an update, got {:?}", events[1]);
+        };
+        assert_eq!((after.machine.as_str(), after.index), ("office-pc", 0));
+    }
+
+    #[test]
+    fn delivers_to_the_sinks_that_work() {
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let mut sinks: Vec<Box<dyn EventSink>> = vec![
+            Box::new(FixtureSink {
+                received: received.clone(),
+                broken: true,
+            }),
+            Box::new(FixtureSink {
+                received: received.clone(),
+                broken: false,
+            }),
+        ];
+        let (events, events_rx) = std::sync::mpsc::channel();
+        TaskbarForwarder { events }.updated(&row(1, &[]), &row(1, &["Files"]));
+        for event in events_rx {
+            deliver(&mut sinks, &event);
+        }
+        assert_eq!(
+            summary(&received.lock().unwrap()),
+            vec![("updated", vec!["Files".to_string()], vec![])]
+        );
+    }
+
+    /// How many times `send` posts when a local receiver answers with `statuses` in turn.
+    fn posts(retries: u32, statuses: &[u16]) -> (usize, bool) {
+        let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/events", server.server_addr().to_ip().unwrap());
+        let statuses = statuses.to_vec();
+        let receiver = std::thread::spawn(move || {
+            let mut posts = 0;
+            for status in statuses {
+                let Ok(Some(request)) = server.recv_timeout(Duration::from_millis(500)) else {
+                    break;
+                };
+                posts += 1;
+                let _ = request.respond(tiny_http::Response::empty(status));
+            }
+            posts
+        });
+        let mut sink = HttpSink {
+            backoff: Duration::from_millis(1),
+            ..HttpSink::new(url, retries)
+        };
+        let (events, received) = std::sync::mpsc::channel();
+        TaskbarForwarder { events }.updated(&row(1, &[]), &row(1, &["Files"]));
+        let sent = sink.send(&received.recv().unwrap()).is_ok();
+        (receiver.join().unwrap(), sent)
+    }
+
+    #[test]
+    fn retries_server_errors_and_rate_limits() {
+        assert_eq!(posts(3, &[503, 429, 200]), (3, true));
+        assert_eq!(posts(1, &[500, 502, 200]), (2, false));
+    }
+
+    #[test]
+    fn gives_up_on_requests_the_receiver_refuses() {
+        assert_eq!(posts(3, &[400, 200]), (1, false));
+        assert_eq!(posts(3, &[404, 200]), (1, false));
+    }
+}
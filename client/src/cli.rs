@@ -0,0 +1,384 @@
+use crate::fan_out::Target;
+use crate::logging::LogFormat;
+use crate::logging::LogRotation;
+use crate::presence_report::ReportFormat;
+use crate::taskbar_collector::CollectorKind;
+use crate::taskbar_snapshot::Rect;
+use crate::watch::NotifierKind;
+use clap::Args;
+use clap::Parser;
+use clap::Subcommand;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+
+#[derive(Parser, Debug)]
+#[command(
+    version,
+    about,
+    args_conflicts_with_subcommands = true,
+    after_help = "Exit codes: 0 when done or shut down cleanly, 1 on errors, 2 on invalid arguments, \
+                  3 when shutting down without the server confirming the client left"
+)]
+pub struct Cli {
+    /// SpacetimeDB host to connect to
+    #[arg(long, global = true, default_value = "http://localhost:3000")]
+    pub uri: String,
+
+    /// Name of the published module
+    #[arg(long, global = true, default_value = "explorer-wrangler")]
+    pub module: String,
+
+    #[command(flatten)]
+    pub logging: LoggingArgs,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    #[command(flatten)]
+    pub sync: SyncArgs,
+}
+
+#[derive(Args, Debug)]
+pub struct LoggingArgs {
+    #[arg(long, global = true, value_enum, default_value_t)]
+    pub log_format: LogFormat,
+
+    /// Write logs to this file instead of stderr
+    #[arg(long, global = true)]
+    pub log_file: Option<PathBuf>,
+
+    /// When to start a new log file, keeping the old ones as `<file>.1`, `<file>.2` and so on
+    #[arg(long, global = true, value_enum, default_value_t)]
+    pub log_rotation: LogRotation,
+
+    /// How many rotated log files to keep
+    #[arg(long, global = true, default_value_t = 7)]
+    pub log_max_files: usize,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Collect the local taskbars periodically and sync them (the default)
+    Sync(SyncArgs),
+    /// Feed a recorded session back through the sync pipeline
+    Replay(ReplayArgs),
+    /// Collect the local taskbars once and print them as JSON
+    Collect(CollectArgs),
+    /// Show which placement rule each open window matches, without moving anything
+    Place(PlaceArgs),
+    /// Snap a window into a zone of its monitor's layout
+    Snap(SnapArgs),
+    /// Print the zones a layout would give a monitor, leaving room for this machine's taskbars
+    Zones(ZonesArgs),
+    /// List and rearrange Explorer tabs
+    Tabs(TabsArgs),
+    /// Print the most visited folders, ranked by frequency and recency
+    Recent(RecentArgs),
+    /// Total how long each app was on a taskbar per machine and day, from recordings
+    Report(ReportArgs),
+    /// Raise alerts when apps matching a watch rule appear on or disappear from any taskbar
+    Notify(NotifyArgs),
+    /// Deliver taskbar changes as JSON events to HTTP endpoints, Unix sockets or files
+    Bridge(BridgeArgs),
+    /// Serve the taskbars on a local HTTP API, see `openapi.yaml`
+    Api(ApiArgs),
+    /// Run the sync loop in the background as a systemd user service, or on Windows as a Task
+    /// Scheduler task started when you sign in
+    ///
+    /// Windows services run in session 0, away from your desktop and taskbars, so on Windows this
+    /// registers a logon task running as you instead of a Windows service. It leaves the server when
+    /// you sign out.
+    Service(ServiceArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct SyncArgs {
+    /// Seconds to wait between collections
+    #[arg(long, default_value_t = 5)]
+    pub interval: u64,
+
+    /// Where to collect taskbars from, defaults to this platform's desktop
+    #[arg(long, value_enum, default_value_t)]
+    pub collector: CollectorKind,
+
+    /// Append every collected snapshot to this recording file
+    #[arg(long)]
+    pub record: Option<PathBuf>,
+
+    /// Apply the server's `taskbar_policy` to this machine and report drift
+    #[arg(long)]
+    pub enforce_policy: bool,
+
+    /// Only log the changes `--enforce-policy` would make
+    #[arg(long, requires = "enforce_policy")]
+    pub dry_run: bool,
+
+    /// Carry out `move_window` requests addressed to this machine
+    #[arg(long)]
+    pub allow_window_moves: bool,
+
+    /// Sync virtual desktops and the desktop each window is on
+    #[arg(long)]
+    pub virtual_desktops: bool,
+
+    /// Move newly opened windows according to the server's `placement_rule`s
+    #[arg(long)]
+    pub placement_rules: bool,
+
+    /// Only log where `--placement-rules` would put windows
+    #[arg(long, requires = "placement_rules")]
+    pub placement_dry_run: bool,
+
+    /// Sync the tabs of the open Explorer windows
+    #[arg(long)]
+    pub explorer_tabs: bool,
+
+    /// Record the folders Explorer tabs visit and how long they have focus
+    #[arg(long, requires = "explorer_tabs")]
+    pub record_visits: bool,
+
+    /// Report which window is in the foreground and for how long
+    #[arg(long)]
+    pub track_focus: bool,
+
+    /// Serve the local HTTP API on this loopback address, like `127.0.0.1:7878`
+    #[arg(long)]
+    pub api: Option<SocketAddr>,
+
+    /// File holding the token API clients must send as `Authorization: Bearer <token>`
+    #[arg(long, requires = "api")]
+    pub api_token_file: Option<PathBuf>,
+
+    /// Serve Prometheus metrics at `/metrics` on this address, like `127.0.0.1:9184`
+    #[arg(long)]
+    pub metrics: Option<SocketAddr>,
+
+    /// File holding the token to connect to `--uri` with, a fresh identity is used otherwise
+    #[arg(long)]
+    pub token_file: Option<PathBuf>,
+
+    /// Also push the taskbars to this database, as `URI,MODULE` or `URI,MODULE,TOKEN_FILE`, can
+    /// be given more than once
+    #[arg(long)]
+    pub target: Vec<Target>,
+
+    /// How often to resend taskbars that the server ran out of energy for
+    #[arg(long, default_value_t = 3)]
+    pub sync_retries: u32,
+}
+
+#[derive(Args, Debug)]
+pub struct ReplayArgs {
+    /// Recording file written by `sync --record`
+    pub file: PathBuf,
+
+    /// Playback speed multiplier, 2.0 replays twice as fast as recorded
+    #[arg(long, default_value_t = 1.0)]
+    pub speed: f64,
+}
+
+#[derive(Args, Debug)]
+pub struct CollectArgs {
+    /// Where to collect taskbars from, defaults to this platform's desktop
+    #[arg(long, value_enum, default_value_t)]
+    pub collector: CollectorKind,
+
+    /// Write the snapshot to this file instead of stdout
+    #[arg(long)]
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Args, Debug)]
+pub struct PlaceArgs {
+    /// JSON file with the placement rules to evaluate
+    #[arg(long)]
+    pub rules: PathBuf,
+
+    /// Machine to select rules for, defaults to this one
+    #[arg(long)]
+    pub machine: Option<String>,
+}
+
+#[derive(Args, Debug)]
+pub struct SnapArgs {
+    /// Handle of the window to snap, decimal or `0x` hex
+    #[arg(long, value_parser = parse_window_id)]
+    pub window: u64,
+
+    /// Zone to snap into, counting row by row from 0 at the top left
+    #[arg(long)]
+    pub zone: usize,
+
+    /// Layout to use instead of the one assigned to the window's monitor
+    #[arg(long)]
+    pub layout: Option<String>,
+
+    /// Only print where the window would go
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct ZonesArgs {
+    /// Name of the layout
+    #[arg(long)]
+    pub layout: String,
+
+    /// Monitor bounds in physical pixels, as `left,top,right,bottom`
+    #[arg(long)]
+    pub monitor: Rect,
+
+    /// Monitor scaling, 96 being 100%
+    #[arg(long, default_value_t = 96)]
+    pub dpi: u32,
+}
+
+#[derive(Args, Debug)]
+pub struct TabsArgs {
+    #[command(subcommand)]
+    pub command: TabsCommand,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum TabsCommand {
+    /// Print the open Explorer tabs as JSON
+    List,
+    /// Open a folder in a new tab of an Explorer window
+    Open {
+        /// Handle of the Explorer window, decimal or `0x` hex
+        #[arg(long, value_parser = parse_window_id)]
+        window: u64,
+        path: String,
+    },
+    /// Close one Explorer tab
+    Close {
+        /// Handle of the tab, as printed by `tabs list`
+        #[arg(long, value_parser = parse_window_id)]
+        tab: u64,
+    },
+    /// Merge the Explorer windows on a monitor into one tabbed window
+    Merge {
+        /// Monitor to merge on, by the index of its taskbar
+        #[arg(long, default_value_t = 0)]
+        taskbar: u32,
+    },
+}
+
+#[derive(Args, Debug)]
+pub struct RecentArgs {
+    /// Rank the folders of this machine instead of those of every machine
+    #[arg(long)]
+    pub machine: Option<String>,
+
+    /// How many folders to print
+    #[arg(long, default_value_t = 20)]
+    pub limit: usize,
+
+    /// Open the folder ranked at this position instead of printing the list
+    #[arg(long)]
+    pub open: Option<usize>,
+}
+
+#[derive(Args, Debug)]
+pub struct ReportArgs {
+    /// Recording files written by `sync --record`, from one or more machines
+    #[arg(required = true)]
+    pub recordings: Vec<PathBuf>,
+
+    #[arg(long, value_enum, default_value_t)]
+    pub format: ReportFormat,
+
+    /// Longest gap between two samples, in seconds, that still counts as present
+    #[arg(long, default_value_t = 60)]
+    pub max_gap: u64,
+
+    /// Write the report to this file instead of stdout
+    #[arg(long)]
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Args, Debug)]
+pub struct NotifyArgs {
+    /// Where to send alerts, can be given more than once
+    #[arg(long = "notifier", value_enum, default_value = "stdout")]
+    pub notifiers: Vec<NotifierKind>,
+
+    /// URL the webhook notifier posts each alert to as JSON, like `http://localhost:8080/alerts`
+    #[arg(long)]
+    pub webhook_url: Option<String>,
+
+    /// Raise the alerts of a recording made with `sync --record` instead of watching the server
+    #[arg(long, requires = "rules")]
+    pub replay: Option<PathBuf>,
+
+    /// Watch rules to replay the recording with, as a JSON file
+    #[arg(long, requires = "replay")]
+    pub rules: Option<PathBuf>,
+}
+
+#[derive(Args, Debug)]
+pub struct BridgeArgs {
+    /// URL to post each event to, can be given more than once
+    #[arg(long)]
+    pub http: Vec<String>,
+
+    /// How often to retry a post that failed to get through or got a 5xx or 429 answer
+    #[arg(long, default_value_t = 3)]
+    pub http_retries: u32,
+
+    /// Unix socket to write each event to as a line of JSON, can be given more than once
+    #[arg(long)]
+    pub unix_socket: Vec<PathBuf>,
+
+    /// File to append each event to as a line of JSON, can be given more than once
+    #[arg(long)]
+    pub file: Vec<PathBuf>,
+
+    /// Deliver the changes of a recording written by `sync --record` instead of the server's
+    #[arg(long)]
+    pub replay: Option<PathBuf>,
+}
+
+#[derive(Args, Debug)]
+pub struct ApiArgs {
+    /// Loopback address to listen on, port 0 picks a free one
+    #[arg(long, default_value = "127.0.0.1:7878")]
+    pub listen: SocketAddr,
+
+    /// File holding the token clients must send as `Authorization: Bearer <token>`
+    #[arg(long)]
+    pub token_file: Option<PathBuf>,
+}
+
+#[derive(Args, Debug)]
+pub struct ServiceArgs {
+    #[command(subcommand)]
+    pub command: ServiceCommand,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ServiceCommand {
+    /// Register the logon task on Windows or the user service on Linux, starting when you sign in
+    Install {
+        /// Only print what would be registered
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Arguments for `service run`, like `--interval 10 --log-file wrangler.log`, after `--`
+        #[arg(last = true)]
+        args: Vec<String>,
+    },
+    /// Stop the service and remove its registration
+    Uninstall,
+    Start,
+    Stop,
+    /// Run the sync loop the way the service manager starts it, stopping when asked to
+    Run(Box<SyncArgs>),
+}
+
+fn parse_window_id(s: &str) -> Result<u64, std::num::ParseIntError> {
+    match s.strip_prefix("0x") {
+        Some(hex) => u64::from_str_radix(hex, 16),
+        None => s.parse(),
+    }
+}
@@ -0,0 +1,359 @@
+use crate::module_bindings::report_duplicates_consolidated;
+use crate::module_bindings::sync_explorer_tabs;
+use crate::module_bindings::ConsolidateDuplicatesRequestTableAccess;
+use crate::module_bindings::DbConnection;
+use crate::module_bindings::DuplicateWindowGroup;
+use crate::module_bindings::DuplicateWindowGroupTableAccess;
+use crate::module_bindings::ErrorContext;
+use crate::module_bindings::ExplorerTab;
+use crate::module_bindings::ReducerEventContext;
+use eyre::Result;
+use serde::Deserialize;
+use serde::Serialize;
+use spacetimedb_sdk::DbContext;
+use spacetimedb_sdk::Status;
+use spacetimedb_sdk::Table;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TabSnapshot {
+    pub window_id: u64,
+    pub tab_id: u64,
+    /// `None` for virtual folders like "This PC"
+    pub path: Option<String>,
+    pub title: String,
+    pub active: bool,
+    /// Position of the tab's window among Explorer windows by last focus, 0 being the most recent
+    pub focus_rank: u32,
+}
+
+/// Lists and rearranges the tabs of the Explorer windows on this machine.
+pub trait ExplorerShell {
+    fn tabs(&mut self) -> Result<Vec<TabSnapshot>>;
+    /// Opens `path` in a new tab of the Explorer window `window_id`.
+    fn open_tab(&mut self, window_id: u64, path: &str) -> Result<()>;
+    /// Closes one tab, leaving the other tabs of its window open.
+    fn close_tab(&mut self, tab_id: u64) -> Result<()>;
+    /// The window with keyboard focus, if any.
+    fn foreground_window(&self) -> Option<u64>;
+}
+
+#[cfg(windows)]
+pub fn live_explorer_shell() -> Result<Box<dyn ExplorerShell>> {
+    Ok(Box::new(
+        crate::windows_explorer_tabs::WindowsExplorerShell::new()?,
+    ))
+}
+
+#[cfg(not(windows))]
+pub fn live_explorer_shell() -> Result<Box<dyn ExplorerShell>> {
+    eyre::bail!("Explorer tabs are only supported on Windows")
+}
+
+pub fn sync_tabs(connection: &DbConnection, machine: &str, tabs: Vec<TabSnapshot>) -> Result<()> {
+    let tabs = tabs
+        .into_iter()
+        .map(|x| ExplorerTab {
+            id: 0,
+            machine: machine.to_string(),
+            window_id: x.window_id,
+            tab_id: x.tab_id,
+            path: x.path,
+            title: x.title,
+            active: x.active,
+            focus_rank: x.focus_rank,
+        })
+        .collect();
+    connection
+        .reducers
+        .sync_explorer_tabs(machine.to_string(), tabs)?;
+    Ok(())
+}
+
+/// Moves the tabs of all `windows` into the one of them that already has the most tabs.
+///
+/// Tabs showing virtual folders cannot be reopened by path and stay where they are. A window whose
+/// tabs fail to move is left with the rest of its tabs while the other windows are still merged.
+pub fn merge_windows(shell: &mut dyn ExplorerShell, windows: &HashSet<u64>) -> Result<()> {
+    let tabs = shell
+        .tabs()?
+        .into_iter()
+        .filter(|x| windows.contains(&x.window_id))
+        .collect::<Vec<_>>();
+    let mut counts = HashMap::<u64, usize>::new();
+    for tab in &tabs {
+        *counts.entry(tab.window_id).or_default() += 1;
+    }
+    let Some(target) = counts
+        .into_iter()
+        .max_by_key(|(window_id, count)| (*count, std::cmp::Reverse(*window_id)))
+        .map(|(window_id, _)| window_id)
+    else {
+        tracing::info!("No Explorer windows to merge");
+        return Ok(());
+    };
+    let mut moved = 0;
+    let mut failed = HashMap::<u64, String>::new();
+    for tab in tabs.iter().filter(|x| x.window_id != target) {
+        if failed.contains_key(&tab.window_id) {
+            continue;
+        }
+        let Some(path) = &tab.path else {
+            tracing::warn!("Leaving tab {} behind, it has no path", tab.title);
+            continue;
+        };
+        // The tab is only closed once its copy is open, so a failure loses nothing
+        let result = shell
+            .open_tab(target, path)
+            .and_then(|()| shell.close_tab(tab.tab_id));
+        match result {
+            Ok(()) => moved += 1,
+            Err(e) => {
+                tracing::warn!(
+                    "Failed to move tab {} out of Explorer window {:#x}: {e}",
+                    tab.title,
+                    tab.window_id
+                );
+                failed.insert(tab.window_id, e.to_string());
+            }
+        }
+    }
+    tracing::info!("Moved {moved} tabs into Explorer window {target:#x}");
+    if !failed.is_empty() {
+        let mut failed = failed
+            .into_iter()
+            .map(|(window_id, error)| format!("{window_id:#x}: {error}"))
+            .collect::<Vec<_>>();
+        failed.sort();
+        eyre::bail!(
+            "Failed to merge {} Explorer windows, {}",
+            failed.len(),
+            failed.join("; ")
+        );
+    }
+    Ok(())
+}
+
+/// Closes every tab of the groups except the one each keeps, returning how many closed and what failed.
+fn close_duplicates(
+    shell: &mut dyn ExplorerShell,
+    groups: impl Iterator<Item = DuplicateWindowGroup>,
+) -> (u32, Option<String>) {
+    let mut closed = 0;
+    let mut errors = Vec::new();
+    for group in groups {
+        for tab in group.tab_ids.iter().filter(|x| **x != group.keep_tab_id) {
+            // Tabs can close between syncs, that should not stop the rest from closing
+            match shell.close_tab(*tab) {
+                Ok(()) => closed += 1,
+                Err(e) => errors.push(e.to_string()),
+            }
+        }
+    }
+    (closed, (!errors.is_empty()).then(|| errors.join("; ")))
+}
+
+/// What the server made of the `sync_explorer_tabs` calls of one connection.
+#[derive(Debug, Default)]
+struct TabSyncs {
+    answered: u64,
+    last_committed: bool,
+}
+
+/// Whether the duplicate groups in the cache were computed from the last tabs sent, which is once
+/// the server committed the last of the `sent` calls.
+fn groups_current(sent: u64, syncs: &TabSyncs) -> bool {
+    sent > 0 && syncs.answered >= sent && syncs.last_committed
+}
+
+/// Carries out the `consolidate_duplicates` requests queued for this machine.
+#[derive(Default)]
+pub struct DuplicateConsolidator {
+    handled: HashSet<u64>,
+    /// `sync_explorer_tabs` calls made on the current connection
+    sent: u64,
+    syncs: Arc<Mutex<TabSyncs>>,
+}
+
+impl DuplicateConsolidator {
+    /// Subscribes `connection` to this machine's requests, again after every reconnect.
+    pub fn subscribe(&mut self, connection: &DbConnection, machine: &str) {
+        self.sent = 0;
+        self.syncs = Arc::default();
+        let syncs = self.syncs.clone();
+        connection.reducers.on_sync_explorer_tabs(
+            move |ctx: &ReducerEventContext, _machine: &String, _tabs: &Vec<ExplorerTab>| {
+                if ctx.event.caller_connection_id != Some(ctx.connection_id()) {
+                    return;
+                }
+                if let Ok(mut syncs) = syncs.lock() {
+                    syncs.answered += 1;
+                    syncs.last_committed = matches!(ctx.event.status, Status::Committed);
+                }
+            },
+        );
+        let machine = machine.replace('\'', "''");
+        connection
+            .subscription_builder()
+            .on_error(|_ctx: &ErrorContext, err| {
+                tracing::error!("Duplicate window subscription error: {:?}", err)
+            })
+            .subscribe([
+                format!("SELECT * FROM duplicate_window_group WHERE machine = '{machine}'"),
+                format!("SELECT * FROM consolidate_duplicates_request WHERE machine = '{machine}'"),
+            ]);
+    }
+
+    /// Notes a `sync_explorer_tabs` call, whose groups requests then wait for.
+    pub fn tabs_sent(&mut self) {
+        self.sent += 1;
+    }
+
+    pub fn run(&mut self, connection: &DbConnection, shell: &mut dyn ExplorerShell) -> Result<()> {
+        let pending = connection
+            .db
+            .consolidate_duplicates_request()
+            .iter()
+            .filter(|x| !self.handled.contains(&x.id))
+            .collect::<Vec<_>>();
+        if pending.is_empty() {
+            return Ok(());
+        }
+        // Groups from before the tabs just sent could close tabs that now show other folders
+        let current = self
+            .syncs
+            .lock()
+            .is_ok_and(|syncs| groups_current(self.sent, &syncs));
+        if !current {
+            tracing::debug!("Waiting for the server to regroup the Explorer tabs");
+            return Ok(());
+        }
+        self.handled.extend(pending.iter().map(|x| x.id));
+        // Requests queued together are all answered by one pass over the groups
+        let (closed, error) =
+            close_duplicates(shell, connection.db.duplicate_window_group().iter());
+        tracing::info!("Closed {closed} duplicate Explorer tabs");
+        for request in pending {
+            connection.reducers.report_duplicates_consolidated(
+                request.id,
+                closed,
+                error.clone(),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Explorer windows in memory, failing to open the `unreachable` paths.
+    #[derive(Default)]
+    struct FixtureShell {
+        tabs: Vec<TabSnapshot>,
+        unreachable: HashSet<String>,
+        next_tab: u64,
+    }
+
+    impl FixtureShell {
+        fn tab(&mut self, window_id: u64, path: Option<&str>) {
+            self.next_tab += 1;
+            self.tabs.push(TabSnapshot {
+                window_id,
+                tab_id: self.next_tab,
+                path: path.map(String::from),
+                title: path.unwrap_or("This PC").to_string(),
+                active: false,
+                focus_rank: 0,
+            });
+        }
+
+        fn paths_of(&self, window_id: u64) -> Vec<Option<&str>> {
+            self.tabs
+                .iter()
+                .filter(|x| x.window_id == window_id)
+                .map(|x| x.path.as_deref())
+                .collect()
+        }
+    }
+
+    impl ExplorerShell for FixtureShell {
+        fn tabs(&mut self) -> Result<Vec<TabSnapshot>> {
+            Ok(self.tabs.clone())
+        }
+
+        fn open_tab(&mut self, window_id: u64, path: &str) -> Result<()> {
+            eyre::ensure!(!self.unreachable.contains(path), "{path} is unreachable");
+            self.tab(window_id, Some(path));
+            Ok(())
+        }
+
+        fn close_tab(&mut self, tab_id: u64) -> Result<()> {
+            self.tabs.retain(|x| x.tab_id != tab_id);
+            Ok(())
+        }
+
+        fn foreground_window(&self) -> Option<u64> {
+            None
+        }
+    }
+
+    #[test]
+    fn waits_for_the_groups_of_the_last_tabs_sent() {
+        let syncs = |answered, last_committed| TabSyncs {
+            answered,
+            last_committed,
+        };
+        assert!(!groups_current(0, &syncs(0, false)));
+        assert!(!groups_current(2, &syncs(1, true)));
+        assert!(groups_current(2, &syncs(2, true)));
+        // The last call failed, so the groups are older than the tabs sent
+        assert!(!groups_current(2, &syncs(2, false)));
+    }
+
+    #[test]
+    fn merges_into_the_window_with_the_most_tabs() {
+        let mut shell = FixtureShell::default();
+        shell.tab(1, Some("C:\\a"));
+        shell.tab(2, Some("C:\\b"));
+        shell.tab(2, Some("C:\\c"));
+        shell.tab(3, None);
+        shell.tab(4, Some("C:\\elsewhere"));
+        merge_windows(&mut shell, &HashSet::from([1, 2, 3])).unwrap();
+        assert_eq!(
+            shell.paths_of(2),
+            vec![Some("C:\\b"), Some("C:\\c"), Some("C:\\a")]
+        );
+        assert!(shell.paths_of(1).is_empty());
+        // Virtual folders stay, and windows that were not asked for are left alone
+        assert_eq!(shell.paths_of(3), vec![None]);
+        assert_eq!(shell.paths_of(4), vec![Some("C:\\elsewhere")]);
+    }
+
+    #[test]
+    fn keeps_merging_past_a_window_that_fails() {
+        let mut shell = FixtureShell::default();
+        shell.tab(1, Some("\\\\nas\\gone"));
+        shell.tab(1, Some("C:\\a"));
+        shell.tab(2, Some("C:\\b"));
+        shell.tab(3, Some("C:\\c"));
+        shell.tab(3, Some("C:\\d"));
+        shell.tab(3, Some("C:\\e"));
+        shell.unreachable.insert("\\\\nas\\gone".to_string());
+        let error = merge_windows(&mut shell, &HashSet::from([1, 2, 3])).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("0x1: \\\\nas\\gone is unreachable"));
+        // The failed window keeps all its tabs, the other one was still merged
+        assert_eq!(
+            shell.paths_of(1),
+            vec![Some("\\\\nas\\gone"), Some("C:\\a")]
+        );
+        assert!(shell.paths_of(2).is_empty());
+        assert_eq!(shell.paths_of(3).len(), 4);
+    }
+}
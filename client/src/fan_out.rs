@@ -0,0 +1,417 @@
+use crate::api::read_token;
+use crate::metrics::Metrics;
+use crate::module_bindings::client_leaving;
+use crate::module_bindings::DbConnection;
+use crate::module_bindings::ErrorContext;
+use crate::module_bindings::ReducerEventContext;
+use crate::schema_check::check_schema;
+use crate::sync_tracker::SyncTracker;
+use crate::taskbar_snapshot::TaskbarSnapshot;
+use eyre::Result;
+use spacetimedb_sdk::DbContext;
+use spacetimedb_sdk::Identity;
+use spacetimedb_sdk::Status;
+use std::fmt;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::mpsc::Receiver;
+use std::sync::mpsc::Sender;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+const FIRST_BACKOFF: Duration = Duration::from_secs(5);
+const MAX_BACKOFF: Duration = Duration::from_secs(5 * 60);
+
+/// A database to push taskbars to, written `URI,MODULE` or `URI,MODULE,TOKEN_FILE` on the command
+/// line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Target {
+    pub uri: String,
+    pub module: String,
+    /// File holding the token that identifies the client to the database, which gives it a fresh
+    /// identity otherwise. Read on every connect, so a replaced token is picked up.
+    pub token_file: Option<PathBuf>,
+}
+
+impl FromStr for Target {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.splitn(3, ',');
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some(uri), Some(module), token_file) if !uri.is_empty() && !module.is_empty() => {
+                Ok(Target {
+                    uri: uri.to_string(),
+                    module: module.to_string(),
+                    token_file: token_file.filter(|x| !x.is_empty()).map(PathBuf::from),
+                })
+            }
+            _ => Err(format!(
+                "Expected `URI,MODULE` or `URI,MODULE,TOKEN_FILE`, got `{s}`"
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Target {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.uri, self.module)
+    }
+}
+
+/// Connects to `target`, keeping the `connected` metric up to date and calling `on_disconnect`
+/// once the connection ends.
+fn connect(
+    target: &Target,
+    metrics: &Arc<Metrics>,
+    on_disconnect: impl FnOnce() + Send + 'static,
+) -> Result<DbConnection> {
+    let token = target.token_file.as_deref().map(read_token).transpose()?;
+    let label = target.to_string();
+    let connected = metrics.connected.with_label_values(&[&label]);
+    let disconnected = connected.clone();
+    let connection = DbConnection::builder()
+        .on_connect({
+            let label = label.clone();
+            move |_: &DbConnection, _: Identity, _: &str| {
+                connected.set(1);
+                tracing::info!(database = %label, "Connected to SpacetimeDB");
+            }
+        })
+        .on_connect_error({
+            let label = label.clone();
+            move |_: &ErrorContext, err| tracing::error!(database = %label, "Failed to connect: {err}")
+        })
+        .on_disconnect(move |_: &ErrorContext, err| {
+            disconnected.set(0);
+            tracing::info!(
+                database = %label,
+                "Disconnected: {}",
+                err.map_or("Clean disconnect".into(), |e| e.to_string())
+            );
+            on_disconnect();
+        })
+        .with_uri(&target.uri)
+        .with_module_name(&target.module)
+        .with_token(token)
+        .build()?;
+    Ok(connection)
+}
+
+/// Tells the server this machine is leaving, returning whether it confirmed within `timeout`.
+fn leave(connection: &DbConnection, machine: &str, timeout: Duration) -> bool {
+    let (left_tx, left_rx) = std::sync::mpsc::channel();
+    connection
+        .reducers
+        .on_client_leaving(move |ctx: &ReducerEventContext, _machine: &String| {
+            if ctx.event.caller_connection_id == Some(ctx.connection_id()) {
+                let _ = left_tx.send(ctx.event.status.clone());
+            }
+        });
+    if let Err(e) = connection.reducers.client_leaving(machine.to_string()) {
+        tracing::warn!("Failed to tell the server {machine} is leaving: {e}");
+        return false;
+    }
+    match left_rx.recv_timeout(timeout) {
+        Ok(Status::Committed) => true,
+        Ok(Status::Failed(error)) => {
+            tracing::warn!(%error, "Server rejected client_leaving");
+            false
+        }
+        Ok(Status::OutOfEnergy) => {
+            tracing::warn!("Server ran out of energy for client_leaving");
+            false
+        }
+        Err(_) => {
+            tracing::warn!("Server did not confirm {machine} left within {timeout:?}");
+            false
+        }
+    }
+}
+
+/// Spaces out attempts to connect, doubling the wait after each failure up to `MAX_BACKOFF`.
+struct Backoff {
+    retry_at: Instant,
+    delay: Duration,
+}
+
+impl Default for Backoff {
+    fn default() -> Self {
+        Backoff {
+            retry_at: Instant::now(),
+            delay: FIRST_BACKOFF,
+        }
+    }
+}
+
+impl Backoff {
+    fn ready(&self) -> bool {
+        Instant::now() >= self.retry_at
+    }
+
+    /// Records a failed attempt, returning how long to wait before the next.
+    fn failed(&mut self) -> Duration {
+        let delay = self.delay;
+        self.retry_at = Instant::now() + delay;
+        self.delay = (delay * 2).min(MAX_BACKOFF);
+        delay
+    }
+
+    fn reset(&mut self) {
+        *self = Backoff::default();
+    }
+}
+
+/// Connects to `target` and checks its schema, disconnecting again if the schema does not fit.
+fn open(
+    target: &Target,
+    metrics: &Arc<Metrics>,
+    on_disconnect: impl FnOnce() + Send + 'static,
+) -> Result<DbConnection> {
+    let connection = connect(target, metrics, on_disconnect)?;
+    connection.run_threaded();
+    if let Err(e) = check_schema(&connection) {
+        let _ = connection.disconnect();
+        return Err(e);
+    }
+    Ok(connection)
+}
+
+enum LinkCommand {
+    Sync(Vec<TaskbarSnapshot>),
+    /// Tells the target `leaving` left if given, disconnects, and reports whether it confirmed
+    Close {
+        leaving: Option<String>,
+        timeout: Duration,
+        closed: Sender<bool>,
+    },
+}
+
+/// One target, with a connection of its own that is rebuilt whenever it drops.
+struct Link {
+    target: Target,
+    connection: Option<Arc<DbConnection>>,
+    /// Hears from the connection once it has ended
+    disconnected: Option<Receiver<()>>,
+    /// Where to hand each new connection, for the sync loop to use the primary's
+    connected: Option<Sender<Arc<DbConnection>>>,
+    tracker: Arc<SyncTracker>,
+    backoff: Backoff,
+    /// Whether the next connection replaces one that dropped
+    reconnecting: bool,
+}
+
+impl Link {
+    /// The live connection, reconnecting first if the last one dropped and its backoff has passed.
+    fn connection(&mut self, metrics: &Arc<Metrics>) -> Option<Arc<DbConnection>> {
+        if self.connection.as_ref().is_some_and(|x| x.is_active()) {
+            return self.connection.clone();
+        }
+        if self.connection.take().is_some() {
+            tracing::warn!(database = %self.target, "Lost the connection, reconnecting");
+            self.reconnecting = true;
+        }
+        if !self.backoff.ready() {
+            return None;
+        }
+        let (disconnected_tx, disconnected_rx) = std::sync::mpsc::channel();
+        match open(&self.target, metrics, move || {
+            let _ = disconnected_tx.send(());
+        }) {
+            Ok(connection) => {
+                let connection = Arc::new(connection);
+                if std::mem::take(&mut self.reconnecting) {
+                    metrics
+                        .reconnects
+                        .with_label_values(&[&self.target.to_string()])
+                        .inc();
+                }
+                self.tracker.forget_pending();
+                self.tracker.track(&connection);
+                self.backoff.reset();
+                if let Some(connected) = &self.connected {
+                    let _ = connected.send(connection.clone());
+                }
+                self.disconnected = Some(disconnected_rx);
+                self.connection = Some(connection.clone());
+                Some(connection)
+            }
+            Err(e) => {
+                let delay = self.backoff.failed();
+                tracing::warn!(
+                    database = %self.target,
+                    "Failed to connect, retrying in {delay:?}: {e}"
+                );
+                None
+            }
+        }
+    }
+
+    /// Follows `commands` until told to close or until the `FanOut` is dropped.
+    fn run(mut self, commands: Receiver<LinkCommand>, metrics: Arc<Metrics>) {
+        let mut next = commands.recv().ok();
+        while let Some(command) = next.take() {
+            let following = commands.try_recv().ok();
+            match command {
+                // A target that fell behind only needs the latest snapshot
+                LinkCommand::Sync(_) if matches!(following, Some(LinkCommand::Sync(_))) => {}
+                LinkCommand::Sync(taskbars) => match self.connection(&metrics) {
+                    Some(connection) => {
+                        if let Err(e) = self.tracker.send(&connection, taskbars) {
+                            tracing::warn!(database = %self.target, "Failed to sync taskbars: {e}");
+                        }
+                    }
+                    None => {
+                        tracing::debug!(database = %self.target, "Not connected, skipping a snapshot");
+                    }
+                },
+                LinkCommand::Close {
+                    leaving,
+                    timeout,
+                    closed,
+                } => {
+                    let _ = closed.send(self.close(leaving.as_deref(), timeout));
+                    return;
+                }
+            }
+            next = following.or_else(|| commands.recv().ok());
+        }
+        // Nobody waits for a link the `FanOut` dropped
+        if let Some(connection) = self.connection.take() {
+            let _ = connection.disconnect();
+        }
+    }
+
+    /// Tells the target `leaving` left if given and disconnects, returning whether it confirmed
+    /// both within `timeout` each.
+    fn close(&mut self, leaving: Option<&str>, timeout: Duration) -> bool {
+        let Some(connection) = self.connection.take() else {
+            return true;
+        };
+        let mut confirmed = leaving.is_none_or(|machine| leave(&connection, machine, timeout));
+        match connection.disconnect() {
+            Ok(()) => {
+                let disconnected = self.disconnected.take();
+                if disconnected.is_some_and(|x| x.recv_timeout(timeout).is_err()) {
+                    tracing::warn!(
+                        database = %self.target,
+                        "Gave up waiting to disconnect after {timeout:?}"
+                    );
+                    confirmed = false;
+                }
+            }
+            Err(e) => tracing::warn!(database = %self.target, "Failed to disconnect: {e}"),
+        }
+        confirmed
+    }
+}
+
+/// Pushes every snapshot to each target from a thread of its own, so a target that is slow or down
+/// cannot hold up the others or the sync loop.
+pub struct FanOut {
+    links: Vec<(String, Sender<LinkCommand>)>,
+    metrics: Arc<Metrics>,
+    max_retries: u32,
+}
+
+impl FanOut {
+    pub fn new(metrics: Arc<Metrics>, max_retries: u32) -> Self {
+        FanOut {
+            links: Vec::new(),
+            metrics,
+            max_retries,
+        }
+    }
+
+    /// Starts pushing to `target`, handing each connection it makes to `connected` if given, and
+    /// returns the tracker of its syncs.
+    pub fn add(
+        &mut self,
+        target: Target,
+        connected: Option<Sender<Arc<DbConnection>>>,
+    ) -> Arc<SyncTracker> {
+        let label = target.to_string();
+        self.metrics.add_target(&label);
+        let tracker = SyncTracker::new(self.metrics.clone(), label.clone(), self.max_retries);
+        let link = Link {
+            target,
+            connection: None,
+            disconnected: None,
+            connected,
+            tracker: tracker.clone(),
+            backoff: Backoff::default(),
+            reconnecting: false,
+        };
+        let (commands_tx, commands_rx) = std::sync::mpsc::channel();
+        let metrics = self.metrics.clone();
+        std::thread::spawn(move || link.run(commands_rx, metrics));
+        self.links.push((label, commands_tx));
+        tracker
+    }
+
+    pub fn send(&self, taskbars: &[TaskbarSnapshot]) {
+        for (target, commands) in &self.links {
+            if commands.send(LinkCommand::Sync(taskbars.to_vec())).is_err() {
+                tracing::warn!(database = %target, "The target's sync thread has stopped");
+            }
+        }
+    }
+
+    /// Closes every link at once, waiting up to twice `timeout` for them all.
+    fn close(&mut self, leaving: Option<&str>, timeout: Duration) -> bool {
+        let deadline = Instant::now() + timeout * 2;
+        let pending = self
+            .links
+            .drain(..)
+            .filter_map(|(target, commands)| {
+                let (closed_tx, closed_rx) = std::sync::mpsc::channel();
+                let command = LinkCommand::Close {
+                    leaving: leaving.map(String::from),
+                    timeout,
+                    closed: closed_tx,
+                };
+                commands.send(command).ok().map(|()| (target, closed_rx))
+            })
+            .collect::<Vec<_>>();
+        let mut confirmed = true;
+        for (target, closed) in pending {
+            match closed.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+                Ok(x) => confirmed &= x,
+                Err(_) => {
+                    tracing::warn!(database = %target, "Gave up waiting for the target to close");
+                    confirmed = false;
+                }
+            }
+        }
+        confirmed
+    }
+
+    /// Tells every connected target this machine is leaving and disconnects, returning whether
+    /// all of them confirmed.
+    pub fn leave(&mut self, machine: &str, timeout: Duration) -> bool {
+        self.close(Some(machine), timeout)
+    }
+
+    pub fn disconnect(&mut self, timeout: Duration) {
+        self.close(None, timeout);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backs_off_exponentially_up_to_the_cap() {
+        let mut backoff = Backoff::default();
+        assert!(backoff.ready());
+        let delays = (0..9).map(|_| backoff.failed()).collect::<Vec<_>>();
+        assert!(!backoff.ready());
+        assert_eq!(delays[..4], [5, 10, 20, 40].map(Duration::from_secs));
+        assert_eq!(delays[6..], [MAX_BACKOFF; 3]);
+        backoff.reset();
+        assert!(backoff.ready());
+        assert_eq!(backoff.failed(), FIRST_BACKOFF);
+    }
+}
@@ -0,0 +1,132 @@
+use crate::module_bindings::end_focus;
+use crate::module_bindings::report_focus;
+use crate::module_bindings::DbConnection;
+use crate::window_source::WindowInfo;
+use eyre::Result;
+use spacetimedb_sdk::Timestamp;
+use std::time::SystemTime;
+
+/// The foreground window changed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FocusEvent {
+    /// `None` when no window has focus, like on the lock screen
+    pub window: Option<WindowInfo>,
+    pub at: SystemTime,
+}
+
+pub trait FocusEventSource {
+    /// The foreground changes since the last call, oldest first.
+    fn poll(&mut self) -> Result<Vec<FocusEvent>>;
+}
+
+#[cfg(windows)]
+pub fn live_focus_source() -> Result<Box<dyn FocusEventSource>> {
+    Ok(Box::new(
+        crate::windows_focus_events::WindowsFocusSource::new()?,
+    ))
+}
+
+#[cfg(not(windows))]
+pub fn live_focus_source() -> Result<Box<dyn FocusEventSource>> {
+    eyre::bail!("Focus tracking is only supported on Windows")
+}
+
+/// Where focus changes are reported to.
+pub trait FocusReporter {
+    fn report_focus(&self, machine: &str, window: WindowInfo, at: Timestamp) -> Result<()>;
+    fn end_focus(&self, machine: &str, at: Timestamp) -> Result<()>;
+}
+
+impl FocusReporter for DbConnection {
+    fn report_focus(&self, machine: &str, window: WindowInfo, at: Timestamp) -> Result<()> {
+        self.reducers.report_focus(
+            machine.to_string(),
+            window.window_id,
+            window.title,
+            window.process,
+            window.path,
+            at,
+        )?;
+        Ok(())
+    }
+
+    fn end_focus(&self, machine: &str, at: Timestamp) -> Result<()> {
+        self.reducers.end_focus(machine.to_string(), at)?;
+        Ok(())
+    }
+}
+
+pub fn report_focus_events(
+    reporter: &dyn FocusReporter,
+    machine: &str,
+    events: Vec<FocusEvent>,
+) -> Result<()> {
+    for event in events {
+        let at = Timestamp::from_system_time(event.at);
+        match event.window {
+            Some(window) => reporter.report_focus(machine, window, at)?,
+            None => reporter.end_focus(machine, at)?,
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    #[derive(Default)]
+    struct FixtureReporter {
+        reported: RefCell<Vec<(String, Option<String>, Timestamp)>>,
+    }
+
+    impl FocusReporter for FixtureReporter {
+        fn report_focus(&self, machine: &str, window: WindowInfo, at: Timestamp) -> Result<()> {
+            self.reported
+                .borrow_mut()
+                .push((machine.to_string(), Some(window.title), at));
+            Ok(())
+        }
+
+        fn end_focus(&self, machine: &str, at: Timestamp) -> Result<()> {
+            self.reported
+                .borrow_mut()
+                .push((machine.to_string(), None, at));
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn reports_each_focus_change_in_order() {
+        let at = SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1_700_000_000);
+        let window = WindowInfo {
+            window_id: 4096,
+            title: "Downloads - File Explorer".to_string(),
+            process: Some("explorer.exe".to_string()),
+            path: Some(r"C:\Users\me\Downloads".to_string()),
+        };
+        let events = vec![
+            FocusEvent {
+                window: Some(window),
+                at,
+            },
+            FocusEvent { window: None, at },
+        ];
+        let reporter = FixtureReporter::default();
+        report_focus_events(&reporter, "office", events).unwrap();
+        report_focus_events(&reporter, "office", Vec::new()).unwrap();
+        let at = Timestamp::from_system_time(at);
+        assert_eq!(
+            reporter.reported.into_inner(),
+            vec![
+                (
+                    "office".to_string(),
+                    Some("Downloads - File Explorer".to_string()),
+                    at
+                ),
+                ("office".to_string(), None, at),
+            ]
+        );
+    }
+}
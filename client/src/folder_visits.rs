@@ -0,0 +1,146 @@
+use crate::explorer_tabs::TabSnapshot;
+use crate::module_bindings::record_folder_visit;
+use crate::module_bindings::DbConnection;
+use crate::module_bindings::FolderScore;
+use eyre::Result;
+use spacetimedb_sdk::Timestamp;
+use std::collections::HashMap;
+use std::process::Command;
+use std::time::Duration;
+use std::time::Instant;
+use std::time::SystemTime;
+
+/// Must match the server's `HALF_LIFE`, which the stored scores are decayed by and a test checks.
+const HALF_LIFE: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// A finished stretch of time a tab spent on one folder.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Visit {
+    pub path: String,
+    pub visited_at: SystemTime,
+    pub focused: Duration,
+}
+
+struct OpenVisit {
+    visit: Visit,
+    /// Whether the tab had focus when last observed
+    focused: bool,
+}
+
+/// Turns periodic tab snapshots into visits, ending one whenever its tab navigates elsewhere or closes.
+#[derive(Default)]
+pub struct VisitTracker {
+    open: HashMap<u64, OpenVisit>,
+    observed_at: Option<Instant>,
+}
+
+impl VisitTracker {
+    /// Takes in the current tabs and returns the visits that ended since the last observation.
+    ///
+    /// Focus is sampled, so the time since the last observation goes to the tab that had focus then.
+    pub fn observe(&mut self, tabs: &[TabSnapshot], foreground: Option<u64>) -> Vec<Visit> {
+        let now = Instant::now();
+        let elapsed = self
+            .observed_at
+            .replace(now)
+            .map(|x| now - x)
+            .unwrap_or_default();
+        for open in self.open.values_mut().filter(|x| x.focused) {
+            open.visit.focused += elapsed;
+        }
+
+        let mut finished = Vec::new();
+        let mut still_open = HashMap::new();
+        for tab in tabs {
+            // Virtual folders have no path to score
+            let Some(path) = &tab.path else {
+                continue;
+            };
+            let visit = match self.open.remove(&tab.tab_id) {
+                Some(open) if open.visit.path == *path => open.visit,
+                previous => {
+                    finished.extend(previous.map(|x| x.visit));
+                    Visit {
+                        path: path.clone(),
+                        visited_at: SystemTime::now(),
+                        focused: Duration::ZERO,
+                    }
+                }
+            };
+            let focused = tab.active && Some(tab.window_id) == foreground;
+            still_open.insert(tab.tab_id, OpenVisit { visit, focused });
+        }
+        // Whatever is left belongs to tabs that closed or moved to a virtual folder
+        finished.extend(self.open.drain().map(|(_, x)| x.visit));
+        self.open = still_open;
+        finished
+    }
+
+    /// Ends every open visit, like when the client stops.
+    pub fn finish(&mut self) -> Vec<Visit> {
+        self.observe(&[], None)
+    }
+}
+
+pub fn record_visits(connection: &DbConnection, machine: &str, visits: Vec<Visit>) -> Result<()> {
+    for visit in visits {
+        connection.reducers.record_folder_visit(
+            machine.to_string(),
+            visit.path,
+            Timestamp::from_system_time(visit.visited_at),
+            visit.focused.as_millis() as u64,
+        )?;
+    }
+    Ok(())
+}
+
+/// The score of a folder as of now, so folders last visited at different times compare fairly.
+pub fn current_score(score: &FolderScore, now: SystemTime) -> f64 {
+    let age = now
+        .duration_since(score.last_visit.to_system_time())
+        .unwrap_or_default();
+    score.score * 0.5f64.powf(age.as_secs_f64() / HALF_LIFE.as_secs_f64())
+}
+
+/// Formats a duration as hours and minutes, or minutes and seconds when under an hour.
+pub fn format_focused(ms: u64) -> String {
+    let secs = ms / 1000;
+    if secs >= 3600 {
+        format!("{}h {:02}m", secs / 3600, secs % 3600 / 60)
+    } else {
+        format!("{}m {:02}s", secs / 60, secs % 60)
+    }
+}
+
+/// Opens a folder in the platform's file manager.
+pub fn open_folder(path: &str) -> Result<()> {
+    let program = if cfg!(windows) {
+        "explorer"
+    } else {
+        "xdg-open"
+    };
+    Command::new(program).arg(path).spawn()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decays_like_the_server() {
+        let path =
+            std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../server/src/folder_visit.rs");
+        let server = std::fs::read_to_string(path).unwrap();
+        let seconds = server
+            .lines()
+            .find_map(|x| x.strip_prefix("pub const HALF_LIFE: Duration = Duration::from_secs("))
+            .and_then(|x| x.strip_suffix(");"))
+            .expect("The server declares its HALF_LIFE in seconds");
+        let seconds = seconds
+            .split('*')
+            .map(|x| x.trim().parse::<u64>().unwrap())
+            .product::<u64>();
+        assert_eq!(Duration::from_secs(seconds), HALF_LIFE);
+    }
+}
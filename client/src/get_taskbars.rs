@@ -1,6 +1,11 @@
+use crate::taskbar_settings::read_taskbar_settings;
+use crate::taskbar_snapshot::local_machine_name;
+use crate::taskbar_snapshot::TaskbarSettings;
 use crate::windows_taskbar::WindowsTaskbar;
 use eyre::Result;
-use log::info;
+use tracing::debug;
+use tracing::info;
+use tracing::warn;
 use windows::core::*;
 use windows::Win32::Foundation::BOOL;
 use windows::Win32::Foundation::HWND;
@@ -11,11 +16,12 @@ use windows::Win32::UI::WindowsAndMessaging::GetWindowRect;
 
 pub fn get_taskbars() -> Result<Vec<WindowsTaskbar>> {
     let mut rtn = Vec::new();
+    let machine = local_machine_name();
 
     let primary_class = to_string("Shell_TrayWnd");
     let secondary_class = to_string("Shell_SecondaryTrayWnd");
 
-    println!("Enumerating taskbars:");
+    debug!("Enumerating taskbars");
 
     // Find the primary taskbar
     let mut hwnd: HWND =
@@ -28,12 +34,15 @@ pub fn get_taskbars() -> Result<Vec<WindowsTaskbar>> {
             GetWindowRect(hwnd, &mut rect)?;
         }
         let apps = get_taskbar_apps(hwnd);
+        let settings = read_settings(hwnd, rect);
 
         let taskbar = WindowsTaskbar {
+            machine: machine.clone(),
             id: i,
             rect,
             is_secondary: false,
             apps,
+            settings,
         };
         i += 1;
         info!("Taskbar {:?}", taskbar);
@@ -60,11 +69,14 @@ pub fn get_taskbars() -> Result<Vec<WindowsTaskbar>> {
         }
 
         let apps = get_taskbar_apps(hwnd);
+        let settings = read_settings(hwnd, rect);
         let taskbar = WindowsTaskbar {
+            machine: machine.clone(),
             id: i,
             rect,
             is_secondary: true,
             apps,
+            settings,
         };
         info!("Taskbar {:?}", taskbar);
         i += 1;
@@ -76,6 +88,14 @@ pub fn get_taskbars() -> Result<Vec<WindowsTaskbar>> {
     Ok(rtn)
 }
 
+/// Falls back to the default settings, so one unreadable registry value does not lose the taskbar.
+fn read_settings(hwnd: HWND, rect: RECT) -> TaskbarSettings {
+    read_taskbar_settings(hwnd, rect).unwrap_or_else(|e| {
+        warn!("Failed to read the taskbar settings, reporting the defaults: {e}");
+        TaskbarSettings::default()
+    })
+}
+
 fn to_string(name: &str) -> Vec<u16> {
     use std::ffi::OsStr;
     use std::os::windows::ffi::OsStrExt;
@@ -0,0 +1,66 @@
+use crate::cli::LoggingArgs;
+use clap::ValueEnum;
+use eyre::Context;
+use eyre::Result;
+use rolling_file::BasicRollingFileAppender;
+use rolling_file::RollingConditionBasic;
+use std::io::IsTerminal;
+use std::sync::Mutex;
+use tracing_subscriber::fmt::writer::BoxMakeWriter;
+use tracing_subscriber::EnvFilter;
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LogFormat {
+    /// Human-readable lines
+    #[default]
+    Text,
+    /// One JSON object per line, with the spans an event happened in
+    Json,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LogRotation {
+    Hourly,
+    #[default]
+    Daily,
+    Never,
+}
+
+/// Sends logs to stderr, or to a rotated file, at the level `RUST_LOG` asks for or `info`.
+///
+/// Records of crates that use `log` rather than `tracing` are picked up too.
+pub fn init_logging(args: &LoggingArgs) -> Result<()> {
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+    let writer = match &args.log_file {
+        Some(path) => {
+            let condition = match args.log_rotation {
+                LogRotation::Hourly => RollingConditionBasic::new().hourly(),
+                LogRotation::Daily => RollingConditionBasic::new().daily(),
+                LogRotation::Never => RollingConditionBasic::new(),
+            };
+            // Unbuffered, so lines are not lost in memory when the client dies
+            let appender = BasicRollingFileAppender::new_with_buffer_capacity(
+                path,
+                condition,
+                args.log_max_files,
+                0,
+            )
+            .wrap_err_with(|| format!("Failed to open log file {}", path.display()))?;
+            BoxMakeWriter::new(Mutex::new(appender))
+        }
+        None => BoxMakeWriter::new(std::io::stderr),
+    };
+    let builder = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(writer)
+        .with_ansi(args.log_file.is_none() && std::io::stderr().is_terminal());
+    let result = match args.log_format {
+        LogFormat::Text => builder.try_init(),
+        LogFormat::Json => builder
+            .json()
+            .with_current_span(true)
+            .with_span_list(true)
+            .try_init(),
+    };
+    result.map_err(|e| eyre::eyre!("Failed to initialize logging: {e}"))
+}
@@ -0,0 +1,133 @@
+/// Quotes an argument the way `CommandLineToArgvW` splits them back apart.
+fn quote(arg: &str) -> String {
+    if !arg.is_empty() && !arg.contains([' ', '\t', '"']) {
+        return arg.to_string();
+    }
+    let mut quoted = String::from("\"");
+    let mut backslashes = 0;
+    for c in arg.chars() {
+        match c {
+            '\\' => backslashes += 1,
+            '"' => {
+                // Backslashes before a quote escape, and the quote needs escaping too
+                quoted.extend(std::iter::repeat_n('\\', backslashes * 2 + 1));
+                backslashes = 0;
+            }
+            _ => {
+                quoted.extend(std::iter::repeat_n('\\', backslashes));
+                backslashes = 0;
+            }
+        }
+        if c != '\\' {
+            quoted.push(c);
+        }
+    }
+    // Doubled so the closing quote is not escaped
+    quoted.extend(std::iter::repeat_n('\\', backslashes * 2));
+    quoted.push('"');
+    quoted
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// The Task Scheduler definition that runs `executable service run` with `args` when `user`
+/// signs in, in that user's session so the client sees their taskbars.
+pub fn task_xml(user: &str, executable: &str, args: &[String]) -> String {
+    let user = escape(user);
+    let command = escape(executable);
+    let arguments = escape(
+        &["service", "run"]
+            .into_iter()
+            .chain(args.iter().map(String::as_str))
+            .map(quote)
+            .collect::<Vec<_>>()
+            .join(" "),
+    );
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-16"?>
+<Task version="1.2" xmlns="http://schemas.microsoft.com/windows/2004/02/mit/task">
+  <RegistrationInfo>
+    <Description>Syncs the taskbars of this machine to SpacetimeDB</Description>
+  </RegistrationInfo>
+  <Triggers>
+    <LogonTrigger>
+      <UserId>{user}</UserId>
+    </LogonTrigger>
+  </Triggers>
+  <Principals>
+    <Principal id="Author">
+      <UserId>{user}</UserId>
+      <LogonType>InteractiveToken</LogonType>
+      <RunLevel>LeastPrivilege</RunLevel>
+    </Principal>
+  </Principals>
+  <Settings>
+    <MultipleInstancesPolicy>IgnoreNew</MultipleInstancesPolicy>
+    <DisallowStartIfOnBatteries>false</DisallowStartIfOnBatteries>
+    <StopIfGoingOnBatteries>false</StopIfGoingOnBatteries>
+    <ExecutionTimeLimit>PT0S</ExecutionTimeLimit>
+    <RestartOnFailure>
+      <Interval>PT1M</Interval>
+      <Count>3</Count>
+    </RestartOnFailure>
+  </Settings>
+  <Actions Context="Author">
+    <Exec>
+      <Command>{command}</Command>
+      <Arguments>{arguments}</Arguments>
+    </Exec>
+  </Actions>
+</Task>
+"#
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn element<'a>(xml: &'a str, name: &str) -> Vec<&'a str> {
+        xml.split(&format!("<{name}>"))
+            .skip(1)
+            .filter_map(|x| x.split(&format!("</{name}>")).next())
+            .collect()
+    }
+
+    #[test]
+    fn runs_as_the_user_in_their_session() {
+        let xml = task_xml(r"OFFICE\ada", r"C:\Tools\wrangler.exe", &[]);
+        assert_eq!(element(&xml, "UserId"), [r"OFFICE\ada", r"OFFICE\ada"]);
+        assert!(xml.contains("<LogonTrigger>"));
+        assert_eq!(element(&xml, "LogonType"), ["InteractiveToken"]);
+        assert_eq!(element(&xml, "RunLevel"), ["LeastPrivilege"]);
+        assert_eq!(element(&xml, "ExecutionTimeLimit"), ["PT0S"]);
+    }
+
+    #[test]
+    fn runs_the_service_with_the_given_arguments() {
+        let args = ["--interval", "10", "--log-file", r"C:\Logs\R&D <1>\"]
+            .map(String::from)
+            .to_vec();
+        let xml = task_xml(r"OFFICE\ada", r"C:\Program Files\wrangler.exe", &args);
+        assert_eq!(element(&xml, "Command"), [r"C:\Program Files\wrangler.exe"]);
+        assert_eq!(
+            element(&xml, "Arguments"),
+            [r#"service run --interval 10 --log-file &quot;C:\Logs\R&amp;D &lt;1&gt;\\&quot;"#]
+        );
+    }
+
+    #[test]
+    fn quotes_arguments_for_command_line_to_argv() {
+        assert_eq!(quote("plain"), "plain");
+        assert_eq!(quote(""), r#""""#);
+        assert_eq!(quote(r"C:\My Files\"), r#""C:\My Files\\""#);
+        assert_eq!(quote(r#"say "hi""#), r#""say \"hi\"""#);
+        assert_eq!(quote(r#"a\"b"#), r#""a\\\"b""#);
+    }
+}
@@ -1,59 +1,515 @@
+mod api;
+mod bridge;
+mod cli;
+mod explorer_tabs;
+mod fan_out;
+mod focus_events;
+mod folder_visits;
+#[cfg(windows)]
 mod get_taskbars;
+mod logging;
+#[cfg(any(windows, test))]
+mod logon_task;
+mod metrics;
 mod module_bindings;
+mod placement_rules;
+mod presence_report;
+mod recording;
+mod schema_check;
+mod service;
+mod shutdown;
+mod sync_tracker;
+#[cfg(target_os = "linux")]
+mod systemd_service;
+mod taskbar_collector;
+mod taskbar_policy;
+#[cfg(windows)]
+mod taskbar_settings;
+mod taskbar_snapshot;
+mod taskbar_source;
+mod virtual_desktops;
+mod watch;
+mod window_move;
+mod window_source;
+#[cfg(windows)]
+mod windows_console;
+#[cfg(windows)]
+mod windows_explorer_tabs;
+#[cfg(windows)]
+mod windows_focus_events;
+#[cfg(windows)]
+mod windows_logon_task;
+#[cfg(windows)]
+mod windows_registry;
+#[cfg(windows)]
+mod windows_shell;
+#[cfg(windows)]
 pub mod windows_taskbar;
-use get_taskbars::get_taskbars;
+#[cfg(windows)]
+mod windows_virtual_desktops;
+#[cfg(windows)]
+mod windows_window_list;
+#[cfg(windows)]
+mod windows_window_mover;
+#[cfg(windows)]
+mod windows_window_source;
+#[cfg(unix)]
+mod x11_taskbars;
+mod zones;
+use api::read_token;
+use api::serve;
+use api::ApiBackend;
+use api::LiveApiBackend;
+use bridge::deliver;
+use bridge::forward_taskbar_events;
+use bridge::replay_events;
+use bridge::sinks;
+use clap::Parser;
+use cli::ApiArgs;
+use cli::BridgeArgs;
+use cli::Cli;
+use cli::CollectArgs;
+use cli::Command;
+use cli::NotifyArgs;
+use cli::PlaceArgs;
+use cli::RecentArgs;
+use cli::ReportArgs;
+use cli::ServiceArgs;
+use cli::ServiceCommand;
+use cli::SnapArgs;
+use cli::SyncArgs;
+use cli::TabsArgs;
+use cli::TabsCommand;
+use cli::ZonesArgs;
+use explorer_tabs::live_explorer_shell;
+use explorer_tabs::merge_windows;
+use explorer_tabs::sync_tabs;
+use explorer_tabs::DuplicateConsolidator;
+use explorer_tabs::ExplorerShell;
+use eyre::Context;
+use eyre::OptionExt;
+use fan_out::FanOut;
+use fan_out::Target;
+use focus_events::live_focus_source;
+use focus_events::report_focus_events;
+use focus_events::FocusEventSource;
+use folder_visits::current_score;
+use folder_visits::format_focused;
+use folder_visits::open_folder;
+use folder_visits::record_visits;
+use folder_visits::VisitTracker;
+use logging::init_logging;
+use metrics::serve_metrics;
+use metrics::Metrics;
 // Where your generated code will be
 use module_bindings::*;
-use simple_logger::SimpleLogger;
+use placement_rules::PlacementEngine;
+use placement_rules::RuleSet;
+use placement_rules::RuleSpec;
+use recording::read_recording;
+use recording::Recorder;
+use service::run_service;
+use service::service_controller;
+use shutdown::handle_signals;
+use shutdown::Ending;
+use shutdown::Shutdown;
 use spacetimedb_sdk::DbContext;
 use spacetimedb_sdk::Identity;
+use spacetimedb_sdk::Table;
+use std::collections::HashSet;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::sync::mpsc::RecvTimeoutError;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+use taskbar_collector::CollectorKind;
+use taskbar_policy::live_settings_writer;
+use taskbar_policy::DryRunSettingsWriter;
+use taskbar_policy::PolicyReconciler;
+use taskbar_policy::TaskbarSettingsWriter;
+use taskbar_snapshot::local_machine_name;
+use taskbar_source::live_source;
+use taskbar_source::ReplayTaskbarSource;
+use taskbar_source::TaskbarSource;
+use virtual_desktops::live_desktop_collector;
+use virtual_desktops::sync_desktops;
+use virtual_desktops::VirtualDesktopCollector;
+use watch::compile;
+use watch::notifier;
+use watch::replay_alerts;
+use watch::watch_taskbars;
+use watch::WatchRuleSpec;
+use window_move::handle_window_moves;
+use window_move::live_window_mover;
+use window_move::WindowMover;
+use window_move::WindowPlacement;
+use window_move::WindowState;
+use window_source::live_window_source;
+use zones::on_monitor;
+use zones::pick_layout;
+use zones::work_area;
+use zones::zone_rects;
+use zones::LayoutSpec;
 
-// Define Constants
-const DB_NAME: &str = "explorer-wrangler";
-const DB_ADDRESS: &str = "http://localhost:3000";
+/// Everything the sync loop needs besides the connection.
+struct Session {
+    source: Box<dyn TaskbarSource>,
+    recorder: Option<Recorder>,
+    reconciler: Option<PolicyReconciler>,
+    window_mover: Option<Box<dyn WindowMover + Send>>,
+    desktop_collector: Option<Box<dyn VirtualDesktopCollector>>,
+    placement: Option<PlacementEngine>,
+    explorer_shell: Option<Box<dyn ExplorerShell>>,
+    visit_tracker: Option<VisitTracker>,
+    focus_source: Option<Box<dyn FocusEventSource>>,
+    api: Option<SocketAddr>,
+    api_token: Option<String>,
+    metrics: Option<SocketAddr>,
+    sync_retries: u32,
+    /// Whether to mark this machine offline when shutting down, which replays must not
+    announce_leaving: bool,
+    token_file: Option<PathBuf>,
+    /// Databases to push the taskbars to besides the one at `--uri`
+    targets: Vec<Target>,
+}
+
+/// How long after the last accepted sync to start warning about it.
+const SYNC_STALE_AFTER: Duration = Duration::from_secs(60);
 
-fn main() -> eyre::Result<()> {
+/// How long to wait for the server to confirm the client left, and then for the disconnect.
+const LEAVE_TIMEOUT: Duration = Duration::from_secs(5);
+
+fn main() -> eyre::Result<ExitCode> {
     color_eyre::install()?;
-    // Initialize a basic logger.
-    SimpleLogger::new()
-        .with_level(log::LevelFilter::Info)
-        .init()
-        .expect("Failed to initialize logger");
+    let cli = Cli::parse();
+    init_logging(&cli.logging)?;
+    let shutdown = Shutdown::default();
+    let session = match cli.command.unwrap_or(Command::Sync(cli.sync)) {
+        Command::Sync(args) => sync_session(args, shutdown.clone())?,
+        Command::Replay(args) => Session {
+            source: Box::new(ReplayTaskbarSource::open(
+                &args.file,
+                args.speed,
+                shutdown.clone(),
+            )?),
+            recorder: None,
+            reconciler: None,
+            window_mover: None,
+            desktop_collector: None,
+            placement: None,
+            explorer_shell: None,
+            visit_tracker: None,
+            focus_source: None,
+            api: None,
+            api_token: None,
+            metrics: None,
+            sync_retries: 0,
+            announce_leaving: false,
+            token_file: None,
+            targets: Vec::new(),
+        },
+        Command::Service(args) => return service(args, cli.uri, cli.module),
+        command => {
+            run_command(command, &cli.uri, &cli.module)?;
+            return Ok(ExitCode::SUCCESS);
+        }
+    };
+    handle_signals(&shutdown)?;
+    let ending = run_session(session, &cli.uri, &cli.module, &shutdown)?;
+    Ok(ExitCode::from(ending.exit_code()))
+}
 
-    // Build connection
-    let connection = DbConnection::builder()
-        .on_connect(on_connected)
-        .on_connect_error(on_connect_error)
-        .on_disconnect(on_disconnected)
-        .with_uri(DB_ADDRESS)
-        .with_module_name(DB_NAME)
-        .build()
-        .expect("Failed to build connection");
+/// Runs the commands that do their work once and return.
+fn run_command(command: Command, uri: &str, module: &str) -> eyre::Result<()> {
+    match command {
+        Command::Collect(args) => collect(args),
+        Command::Place(args) => place(args),
+        Command::Snap(args) => snap(args, uri, module),
+        Command::Zones(args) => zones(args, uri, module),
+        Command::Tabs(args) => tabs(args),
+        Command::Recent(args) => recent(args, uri, module),
+        Command::Report(args) => report(args),
+        Command::Notify(args) => notify(args, uri, module),
+        Command::Bridge(args) => bridge(args, uri, module),
+        Command::Api(args) => api(args, uri, module),
+        Command::Sync(_) | Command::Replay(_) | Command::Service(_) => {
+            unreachable!("Handled by main")
+        }
+    }
+}
 
-    // Subscribe to taskbars to view changes
-    subscribe_to_tables(&connection);
+fn sync_session(args: SyncArgs, shutdown: Shutdown) -> eyre::Result<Session> {
+    let reconciler = if args.enforce_policy {
+        let writer: Box<dyn TaskbarSettingsWriter> = if args.dry_run {
+            Box::new(DryRunSettingsWriter)
+        } else {
+            live_settings_writer()?
+        };
+        Some(PolicyReconciler::new(writer))
+    } else {
+        None
+    };
+    Ok(Session {
+        source: live_source(Duration::from_secs(args.interval), args.collector, shutdown)?,
+        recorder: args.record.as_deref().map(Recorder::create).transpose()?,
+        reconciler,
+        window_mover: args
+            .allow_window_moves
+            .then(live_window_mover)
+            .transpose()?,
+        desktop_collector: args
+            .virtual_desktops
+            .then(live_desktop_collector)
+            .transpose()?,
+        placement: if args.placement_rules {
+            Some(PlacementEngine::new(
+                live_window_source()?,
+                live_window_mover()?,
+                args.placement_dry_run,
+            ))
+        } else {
+            None
+        },
+        explorer_shell: args.explorer_tabs.then(live_explorer_shell).transpose()?,
+        visit_tracker: args.record_visits.then(VisitTracker::default),
+        focus_source: args.track_focus.then(live_focus_source).transpose()?,
+        api: args.api,
+        api_token: args.api_token_file.as_deref().map(read_token).transpose()?,
+        metrics: args.metrics,
+        sync_retries: args.sync_retries,
+        announce_leaving: true,
+        token_file: args.token_file,
+        targets: args.target,
+    })
+}
 
-    //Run connection in thread
-    connection.run_threaded();
+fn run_session(
+    mut session: Session,
+    uri: &str,
+    module: &str,
+    shutdown: &Shutdown,
+) -> eyre::Result<Ending> {
+    let metrics = Arc::new(Metrics::new()?);
+
+    let primary = Target {
+        uri: uri.to_string(),
+        module: module.to_string(),
+        token_file: session.token_file.take(),
+    };
+    let api = match session.api {
+        Some(address) => {
+            let backend = Arc::new(LiveApiBackend::default());
+            serve(address, backend.clone(), session.api_token.take())?;
+            Some(backend)
+        }
+        None => None,
+    };
+    let window_mover = session.window_mover.take().map(|x| Arc::new(Mutex::new(x)));
+    let mut consolidator = session
+        .explorer_shell
+        .is_some()
+        .then(DuplicateConsolidator::default);
+    // Every database, the primary included, is synced from a link of its own that reconnects
+    // with backoff, so one that is down holds up neither the others nor the collection
+    let mut fan_out = FanOut::new(metrics.clone(), session.sync_retries);
+    let (connected_tx, connected_rx) = std::sync::mpsc::channel();
+    let tracker = fan_out.add(primary.clone(), Some(connected_tx));
+    for target in std::mem::take(&mut session.targets) {
+        fan_out.add(target, None);
+    }
+    if let Some(address) = session.metrics {
+        serve_metrics(address, metrics.clone())?;
+    }
 
     // Main program loop or other logic here
-    loop {
-        sync_taskbars(&connection)?;
-        std::thread::sleep(std::time::Duration::from_secs(5));
+    let machine = local_machine_name();
+    let mut pass = 0u64;
+    // The primary's connection, for everything besides the taskbars
+    let mut current: Option<Arc<DbConnection>> = None;
+
+    // Failures end the loop, but the servers are still told this machine left
+    let mut sync = || -> eyre::Result<()> {
+        while let Some(taskbars) = session.source.next_snapshot()? {
+            for connection in connected_rx.try_iter() {
+                // Subscribe to taskbars to view changes
+                subscribe_to_tables(&connection);
+                if let Some(mover) = &window_mover {
+                    handle_window_moves(&connection, &machine, mover.clone());
+                }
+                if let Some(consolidator) = &mut consolidator {
+                    consolidator.subscribe(&connection, &machine);
+                }
+                if let Some(api) = &api {
+                    api.connected(connection.clone());
+                }
+                current = Some(connection);
+            }
+            let connection = current.as_ref().filter(|x| x.is_active());
+            pass += 1;
+            let _span = tracing::info_span!("sync", %machine, pass).entered();
+            let started = Instant::now();
+            metrics.observe_taskbars(&taskbars);
+            if let Some(recorder) = session.recorder.as_mut() {
+                recorder.record(&taskbars)?;
+            }
+            match connection {
+                Some(connection) => {
+                    if let Some(reconciler) = session.reconciler.as_mut() {
+                        reconciler.reconcile(connection, &taskbars)?;
+                    }
+                    if let Some(engine) = session.placement.as_mut() {
+                        let rules = RuleSet::compile(
+                            connection.db.placement_rule().iter().map(RuleSpec::from),
+                            &machine,
+                        );
+                        engine.run(&rules, &taskbars)?;
+                    }
+                }
+                None => tracing::debug!(
+                    database = %primary,
+                    "Not connected, only handing the taskbars to the links"
+                ),
+            }
+            fan_out.send(&taskbars);
+            let since_success = tracker
+                .last_success()
+                .map(|x| x.elapsed().unwrap_or_default());
+            if since_success.is_some_and(|x| x > SYNC_STALE_AFTER) {
+                tracing::warn!(
+                    since_success = ?since_success,
+                    last_error = tracker.last_error(),
+                    "The server has not accepted this machine's taskbars lately"
+                );
+            }
+            if let Some(connection) = connection {
+                sync_extras(&mut session, connection, &machine, consolidator.as_mut());
+            }
+            metrics
+                .sync_duration
+                .observe(started.elapsed().as_secs_f64());
+        }
+        Ok(())
+    };
+    let synced = sync();
+    if let Err(e) = &synced {
+        tracing::error!("Sync failed: {e:#}");
+    }
+    let ending = if synced.is_ok() && !shutdown.is_requested() {
+        tracing::info!("Taskbar source exhausted, disconnecting");
+        Ending::Exhausted
+    } else if session.announce_leaving {
+        if let Some(tracker) = session.visit_tracker.as_mut() {
+            let visits = tracker.finish();
+            let recorded = current
+                .as_ref()
+                .filter(|x| x.is_active())
+                .ok_or_eyre("Not connected")
+                .and_then(|x| record_visits(x, &machine, visits));
+            if let Err(e) = recorded {
+                tracing::warn!("Failed to record the open folder visits: {e}");
+            }
+        }
+        tracing::info!("Shutting down, telling the servers {machine} is leaving");
+        if fan_out.leave(&machine, LEAVE_TIMEOUT) {
+            Ending::Stopped
+        } else {
+            Ending::Unconfirmed
+        }
+    } else {
+        tracing::info!("Shutting down");
+        Ending::Stopped
+    };
+    fan_out.disconnect(LEAVE_TIMEOUT);
+    synced?;
+    Ok(ending)
+}
+
+/// Syncs what besides the taskbars this machine shares over the primary `connection`, logging
+/// failures so they do not end the session.
+fn sync_extras(
+    session: &mut Session,
+    connection: &DbConnection,
+    machine: &str,
+    consolidator: Option<&mut DuplicateConsolidator>,
+) {
+    if let Some(collector) = session.desktop_collector.as_mut() {
+        let synced = collector
+            .collect()
+            .and_then(|x| sync_desktops(connection, machine, x));
+        if let Err(e) = synced {
+            tracing::warn!("Failed to sync the virtual desktops: {e}");
+        }
+    }
+    if let Some(source) = session.focus_source.as_mut() {
+        let reported = source
+            .poll()
+            .and_then(|x| report_focus_events(connection, machine, x));
+        if let Err(e) = reported {
+            tracing::warn!("Failed to report focus changes: {e}");
+        }
+    }
+    if let Some(shell) = session.explorer_shell.as_mut() {
+        sync_explorer(
+            connection,
+            machine,
+            shell.as_mut(),
+            session.visit_tracker.as_mut(),
+            consolidator,
+        );
+    }
+}
+
+/// Syncs the Explorer tabs and what follows from them, logging failures so a COM error in one
+/// pass does not end the session.
+fn sync_explorer(
+    connection: &DbConnection,
+    machine: &str,
+    shell: &mut dyn ExplorerShell,
+    visit_tracker: Option<&mut VisitTracker>,
+    mut consolidator: Option<&mut DuplicateConsolidator>,
+) {
+    let tabs = match shell.tabs() {
+        Ok(tabs) => tabs,
+        Err(e) => {
+            tracing::warn!("Failed to list the Explorer tabs: {e}");
+            return;
+        }
+    };
+    if let Some(tracker) = visit_tracker {
+        let visits = tracker.observe(&tabs, shell.foreground_window());
+        if let Err(e) = record_visits(connection, machine, visits) {
+            tracing::warn!("Failed to record folder visits: {e}");
+        }
+    }
+    match sync_tabs(connection, machine, tabs) {
+        Ok(()) => {
+            if let Some(consolidator) = consolidator.as_deref_mut() {
+                consolidator.tabs_sent();
+            }
+        }
+        Err(e) => tracing::warn!("Failed to sync the Explorer tabs: {e}"),
+    }
+    if let Some(consolidator) = consolidator {
+        if let Err(e) = consolidator.run(connection, shell) {
+            tracing::warn!("Failed to consolidate duplicate Explorer tabs: {e}");
+        }
     }
 }
 
 //Connection status changes
 fn on_connected(_conn: &DbConnection, _who: Identity, _token: &str) {
-    log::info!("Client connected to SpacetimeDB!");
+    tracing::info!("Client connected to SpacetimeDB!");
 }
 
 fn on_connect_error(_err_ctx: &ErrorContext, err: spacetimedb_sdk::Error) {
-    log::error!("Failed to connect: {}", err);
+    tracing::error!("Failed to connect: {}", err);
 }
 
 fn on_disconnected(_err_ctx: &ErrorContext, err: Option<spacetimedb_sdk::Error>) {
-    log::info!(
+    tracing::info!(
         "Client disconnected: {}",
         err.map_or("Clean disconnect".into(), |e| e.to_string())
     );
@@ -65,24 +521,367 @@ fn subscribe_to_tables(connection: &DbConnection) {
         .subscription_builder()
         .on_applied(on_subscribed)
         .on_error(on_sub_error)
-        .subscribe(["SELECT * FROM taskbar"]);
-    log::info!("Subscribed to Taskbars!");
+        .subscribe([
+            "SELECT * FROM taskbar",
+            "SELECT * FROM taskbar_policy",
+            "SELECT * FROM placement_rule",
+        ]);
+    tracing::info!("Subscribed to Taskbars!");
 }
 
 fn on_subscribed(_ctx: &SubscriptionEventContext) {
-    log::info!("Successfully Subscribed!");
+    tracing::info!("Successfully Subscribed!");
 }
 
 fn on_sub_error(_err_ctx: &ErrorContext, err: spacetimedb_sdk::Error) {
-    log::error!("Subscription error: {:?}", err);
+    tracing::error!("Subscription error: {:?}", err);
+}
+
+fn collect(args: CollectArgs) -> eyre::Result<()> {
+    let taskbars = live_source(Duration::ZERO, args.collector, Shutdown::default())?
+        .next_snapshot()?
+        .unwrap_or_default();
+    let json = serde_json::to_string_pretty(&taskbars)?;
+    match args.output {
+        Some(path) => std::fs::write(path, json)?,
+        None => println!("{json}"),
+    }
+    Ok(())
+}
+
+fn place(args: PlaceArgs) -> eyre::Result<()> {
+    let machine = args.machine.unwrap_or_else(local_machine_name);
+    let rules = RuleSet::compile(RuleSpec::load(&args.rules)?, &machine);
+    let mut source = live_window_source()?;
+    for window in source.windows()? {
+        match rules.find(&window) {
+            Some(rule) => println!(
+                "{:#x} {}: {} -> taskbar {}",
+                window.window_id, window.title, rule.spec.name, rule.spec.target_taskbar_index
+            ),
+            None => println!("{:#x} {}: no rule", window.window_id, window.title),
+        }
+    }
+    Ok(())
 }
 
-// Calling reducer functions
-fn sync_taskbars(connection: &DbConnection) -> eyre::Result<()> {
-    let taskbars = get_taskbars()?;
+/// Connects, waits until `queries` are in the client cache and hands the connection over.
+fn query_once(uri: &str, module: &str, queries: Vec<String>) -> eyre::Result<DbConnection> {
+    let connection = DbConnection::builder()
+        .on_connect_error(on_connect_error)
+        .with_uri(uri)
+        .with_module_name(module)
+        .build()?;
+    let (applied, wait) = std::sync::mpsc::channel();
     connection
-        .reducers
-        .sync_taskbars(taskbars.into_iter().map(|x| x.into()).collect())?;
-    log::info!("Synced taskbars!");
+        .subscription_builder()
+        .on_applied(move |_ctx: &SubscriptionEventContext| {
+            let _ = applied.send(());
+        })
+        .on_error(on_sub_error)
+        .subscribe(queries);
+    connection.run_threaded();
+    wait.recv_timeout(Duration::from_secs(10))
+        .wrap_err("Timed out waiting for the server")?;
+    Ok(connection)
+}
+
+/// Reads the zone layouts and this machine's monitor assignments from the server.
+fn fetch_zone_layouts(
+    uri: &str,
+    module: &str,
+) -> eyre::Result<(Vec<LayoutSpec>, Vec<MonitorZoneLayout>)> {
+    let connection = query_once(
+        uri,
+        module,
+        vec![
+            "SELECT * FROM zone_layout".to_string(),
+            format!(
+                "SELECT * FROM monitor_zone_layout WHERE machine = '{}'",
+                local_machine_name().replace('\'', "''")
+            ),
+        ],
+    )?;
+    let layouts = connection
+        .db
+        .zone_layout()
+        .iter()
+        .map(LayoutSpec::from)
+        .collect();
+    let assignments = connection.db.monitor_zone_layout().iter().collect();
+    connection.disconnect()?;
+    Ok((layouts, assignments))
+}
+
+fn snap(args: SnapArgs, uri: &str, module: &str) -> eyre::Result<()> {
+    let mut mover = live_window_mover()?;
+    let monitor = mover.monitor(mover.placement(args.window)?.rect)?;
+    let taskbars = live_source(
+        Duration::ZERO,
+        CollectorKind::default(),
+        Shutdown::default(),
+    )?
+    .next_snapshot()?
+    .unwrap_or_default();
+    let (layouts, assignments) = fetch_zone_layouts(uri, module)?;
+    let name = match args.layout {
+        Some(name) => name,
+        None => {
+            // Monitors are known to the server by the index of their taskbar
+            let index = taskbars
+                .iter()
+                .find(|x| on_monitor(x.rect, monitor.rect))
+                .map(|x| x.index);
+            assignments
+                .into_iter()
+                .find(|x| Some(x.taskbar_index) == index)
+                .map(|x| x.layout)
+                .ok_or_eyre("No zone layout is assigned to this monitor, pass --layout")?
+        }
+    };
+    let layout = pick_layout(&layouts, &name, monitor.rect)
+        .ok_or_else(|| eyre::eyre!("No zone layout named {name}"))?;
+    let zones = zone_rects(layout, work_area(monitor.rect, &taskbars), monitor.dpi);
+    let rect = *zones
+        .get(args.zone)
+        .ok_or_else(|| eyre::eyre!("Layout {name} only has {} zones", zones.len()))?;
+    if args.dry_run {
+        println!("{:#x} -> {rect:?}", args.window);
+        return Ok(());
+    }
+    mover.set_placement(
+        args.window,
+        WindowPlacement {
+            rect,
+            state: WindowState::Normal,
+        },
+    )
+}
+
+fn zones(args: ZonesArgs, uri: &str, module: &str) -> eyre::Result<()> {
+    let (layouts, _) = fetch_zone_layouts(uri, module)?;
+    let taskbars = live_source(
+        Duration::ZERO,
+        CollectorKind::default(),
+        Shutdown::default(),
+    )?
+    .next_snapshot()?
+    .unwrap_or_default();
+    let layout = pick_layout(&layouts, &args.layout, args.monitor)
+        .ok_or_else(|| eyre::eyre!("No zone layout named {}", args.layout))?;
+    tracing::info!(
+        "Using the {}x{} variant of {}",
+        layout.width,
+        layout.height,
+        layout.name
+    );
+    let zones = zone_rects(layout, work_area(args.monitor, &taskbars), args.dpi);
+    for (index, zone) in zones.iter().enumerate() {
+        println!(
+            "{index}: {},{},{},{}",
+            zone.left, zone.top, zone.right, zone.bottom
+        );
+    }
+    Ok(())
+}
+
+fn tabs(args: TabsArgs) -> eyre::Result<()> {
+    let mut shell = live_explorer_shell()?;
+    match args.command {
+        TabsCommand::List => println!("{}", serde_json::to_string_pretty(&shell.tabs()?)?),
+        TabsCommand::Open { window, path } => shell.open_tab(window, &path)?,
+        TabsCommand::Close { tab } => shell.close_tab(tab)?,
+        TabsCommand::Merge { taskbar } => {
+            let mover = live_window_mover()?;
+            let taskbars = live_source(
+                Duration::ZERO,
+                CollectorKind::default(),
+                Shutdown::default(),
+            )?
+            .next_snapshot()?
+            .unwrap_or_default();
+            let taskbar = taskbars
+                .iter()
+                .find(|x| x.index == taskbar)
+                .ok_or_else(|| eyre::eyre!("No taskbar with index {taskbar}"))?;
+            let monitor = mover.monitor(taskbar.rect)?;
+            let windows = shell
+                .tabs()?
+                .into_iter()
+                .map(|x| x.window_id)
+                .filter(|x| {
+                    mover
+                        .placement(*x)
+                        .is_ok_and(|x| on_monitor(x.rect, monitor.rect))
+                })
+                .collect::<HashSet<_>>();
+            merge_windows(shell.as_mut(), &windows)?;
+        }
+    }
+    Ok(())
+}
+
+fn recent(args: RecentArgs, uri: &str, module: &str) -> eyre::Result<()> {
+    let connection = query_once(uri, module, vec!["SELECT * FROM folder_score".to_string()])?;
+    let now = std::time::SystemTime::now();
+    let mut scores = connection
+        .db
+        .folder_score()
+        .iter()
+        .filter(|x| x.machine == args.machine)
+        .map(|x| (current_score(&x, now), x))
+        .collect::<Vec<_>>();
+    connection.disconnect()?;
+    scores.sort_by(|a, b| b.0.total_cmp(&a.0));
+    scores.truncate(args.limit);
+
+    if let Some(rank) = args.open {
+        let (_, score) = rank
+            .checked_sub(1)
+            .and_then(|x| scores.get(x))
+            .ok_or_else(|| eyre::eyre!("There is no entry {rank}"))?;
+        return open_folder(&score.path);
+    }
+    for (rank, (current, score)) in scores.iter().enumerate() {
+        let last_visit = chrono::DateTime::<chrono::Local>::from(score.last_visit.to_system_time());
+        println!(
+            "{:>3}. {current:>7.2}  {}  ({} visits, {} focused, last {})",
+            rank + 1,
+            score.path,
+            score.visits,
+            format_focused(score.focused_ms),
+            last_visit.format("%Y-%m-%d %H:%M")
+        );
+    }
+    Ok(())
+}
+
+fn report(args: ReportArgs) -> eyre::Result<()> {
+    let mut snapshots = Vec::new();
+    for path in &args.recordings {
+        snapshots.extend(read_recording(path)?);
+    }
+    let presence = presence_report::aggregate(&snapshots, Duration::from_secs(args.max_gap));
+    let report = presence_report::render(&presence, args.format);
+    match &args.output {
+        Some(path) => std::fs::write(path, report)
+            .wrap_err_with(|| format!("Failed to write {}", path.display()))?,
+        None => print!("{report}"),
+    }
     Ok(())
 }
+
+fn notify(args: NotifyArgs, uri: &str, module: &str) -> eyre::Result<()> {
+    let mut notifiers = args
+        .notifiers
+        .iter()
+        .map(|kind| notifier(*kind, args.webhook_url.as_deref()))
+        .collect::<eyre::Result<Vec<_>>>()?;
+    if let (Some(recording), Some(rules)) = (&args.replay, &args.rules) {
+        let rules = compile(WatchRuleSpec::load(rules)?);
+        for alert in replay_alerts(&rules, &read_recording(recording)?) {
+            for notifier in notifiers.iter_mut() {
+                if let Err(e) = notifier.notify(&alert) {
+                    tracing::warn!("Failed to send alert \"{alert}\": {e}");
+                }
+            }
+        }
+        return Ok(());
+    }
+    let connection = DbConnection::builder()
+        .on_connect(on_connected)
+        .on_connect_error(on_connect_error)
+        .on_disconnect(on_disconnected)
+        .with_uri(uri)
+        .with_module_name(module)
+        .build()?;
+    watch_taskbars(&connection, notifiers);
+    connection
+        .subscription_builder()
+        .on_applied(on_subscribed)
+        .on_error(on_sub_error)
+        .subscribe(["SELECT * FROM taskbar", "SELECT * FROM watch_rule"]);
+    // Runs until the connection drops
+    connection
+        .run_threaded()
+        .join()
+        .map_err(|_| eyre::eyre!("The connection thread panicked"))
+}
+
+fn bridge(args: BridgeArgs, uri: &str, module: &str) -> eyre::Result<()> {
+    let mut sinks = sinks(&args.http, args.http_retries, &args.unix_socket, &args.file)?;
+    if let Some(path) = &args.replay {
+        for event in replay_events(&read_recording(path)?) {
+            deliver(&mut sinks, &event);
+        }
+        return Ok(());
+    }
+
+    let connection = DbConnection::builder()
+        .on_connect(on_connected)
+        .on_connect_error(on_connect_error)
+        .on_disconnect(on_disconnected)
+        .with_uri(uri)
+        .with_module_name(module)
+        .build()?;
+    // Sinks can block for a while on retries, so they run here rather than in the callbacks
+    let (events, received) = std::sync::mpsc::channel();
+    forward_taskbar_events(&connection, events);
+    connection
+        .subscription_builder()
+        .on_applied(on_subscribed)
+        .on_error(on_sub_error)
+        .subscribe(["SELECT * FROM taskbar"]);
+    let running = connection.run_threaded();
+    loop {
+        match received.recv_timeout(Duration::from_secs(1)) {
+            Ok(event) => deliver(&mut sinks, &event),
+            Err(RecvTimeoutError::Timeout) if !running.is_finished() => {}
+            // The connection dropped
+            Err(_) => return Ok(()),
+        }
+    }
+}
+
+fn api(args: ApiArgs, uri: &str, module: &str) -> eyre::Result<()> {
+    let connection = query_once(uri, module, vec!["SELECT * FROM taskbar".to_string()])?;
+    let backend: Arc<dyn ApiBackend> = Arc::new(connection);
+    let token = args.token_file.as_deref().map(read_token).transpose()?;
+    let (address, server) = serve(args.listen, backend, token)?;
+    // Lets scripts that asked for port 0 find the one picked
+    println!("Listening on http://{address}");
+    server
+        .join()
+        .map_err(|_| eyre::eyre!("The API thread panicked"))
+}
+
+fn service(args: ServiceArgs, uri: String, module: String) -> eyre::Result<ExitCode> {
+    match args.command {
+        ServiceCommand::Install { dry_run, args } => {
+            // Refuse arguments the service would fail to start with
+            Cli::try_parse_from(
+                ["explorer_wrangler_client", "service", "run"]
+                    .into_iter()
+                    .map(String::from)
+                    .chain(args.iter().cloned()),
+            )?;
+            let controller = service_controller()?;
+            if dry_run {
+                print!("{}", controller.describe(&args)?);
+            } else {
+                controller.install(&args)?;
+            }
+        }
+        ServiceCommand::Uninstall => service_controller()?.uninstall()?,
+        ServiceCommand::Start => service_controller()?.start()?,
+        ServiceCommand::Stop => service_controller()?.stop()?,
+        ServiceCommand::Run(args) => {
+            let ending = run_service(Box::new(move |shutdown| {
+                let session = sync_session(*args, shutdown.clone())?;
+                run_session(session, &uri, &module, &shutdown)
+            }))?;
+            return Ok(ExitCode::from(ending.exit_code()));
+        }
+    }
+    Ok(ExitCode::SUCCESS)
+}
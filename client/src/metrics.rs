@@ -0,0 +1,178 @@
+use crate::taskbar_snapshot::TaskbarSnapshot;
+use eyre::Result;
+use prometheus::Encoder;
+use prometheus::GaugeVec;
+use prometheus::Histogram;
+use prometheus::HistogramOpts;
+use prometheus::IntCounterVec;
+use prometheus::IntGauge;
+use prometheus::IntGaugeVec;
+use prometheus::Opts;
+use prometheus::Registry;
+use prometheus::TextEncoder;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+use tiny_http::Header;
+use tiny_http::Response;
+use tiny_http::Server;
+
+/// Health of the sync loop, served in the Prometheus text format.
+pub struct Metrics {
+    registry: Registry,
+    pub sync_duration: Histogram,
+    pub reducer_failures: IntCounterVec,
+    pub taskbars: IntGauge,
+    pub taskbar_apps: IntGaugeVec,
+    /// Labelled by target, the `<uri>/<module>` of each database the taskbars go to
+    pub connected: IntGaugeVec,
+    pub reconnects: IntCounterVec,
+    pub subscription_lag: GaugeVec,
+    pub last_sync_success: GaugeVec,
+}
+
+impl Metrics {
+    pub fn new() -> Result<Self> {
+        let registry = Registry::new_custom(Some("explorer_wrangler".into()), None)?;
+        let sync_duration = Histogram::with_opts(HistogramOpts::new(
+            "sync_duration_seconds",
+            "Time one pass of the sync loop took, from collecting taskbars to the last reducer call",
+        ))?;
+        let reducer_failures = IntCounterVec::new(
+            Opts::new(
+                "reducer_failures_total",
+                "Reducer calls that could not be sent or that the server rejected",
+            ),
+            &["target", "reducer"],
+        )?;
+        let taskbars = IntGauge::new("taskbars", "Taskbars in the last collection")?;
+        let taskbar_apps = IntGaugeVec::new(
+            Opts::new(
+                "taskbar_apps",
+                "Apps on each taskbar in the last collection",
+            ),
+            &["taskbar"],
+        )?;
+        let connected = IntGaugeVec::new(
+            Opts::new("connected", "1 while connected to the target"),
+            &["target"],
+        )?;
+        let reconnects = IntCounterVec::new(
+            Opts::new(
+                "reconnects_total",
+                "Connections made to the target after an earlier one dropped",
+            ),
+            &["target"],
+        )?;
+        let subscription_lag = GaugeVec::new(
+            Opts::new(
+                "subscription_lag_seconds",
+                "Time the last `sync_taskbars` call took to come back through the subscription",
+            ),
+            &["target"],
+        )?;
+        let last_sync_success = GaugeVec::new(
+            Opts::new(
+                "last_sync_success_timestamp_seconds",
+                "Unix time the target last accepted this client's taskbars",
+            ),
+            &["target"],
+        )?;
+        registry.register(Box::new(sync_duration.clone()))?;
+        registry.register(Box::new(reducer_failures.clone()))?;
+        registry.register(Box::new(taskbars.clone()))?;
+        registry.register(Box::new(taskbar_apps.clone()))?;
+        registry.register(Box::new(connected.clone()))?;
+        registry.register(Box::new(reconnects.clone()))?;
+        registry.register(Box::new(subscription_lag.clone()))?;
+        registry.register(Box::new(last_sync_success.clone()))?;
+        Ok(Metrics {
+            registry,
+            sync_duration,
+            reducer_failures,
+            taskbars,
+            taskbar_apps,
+            connected,
+            reconnects,
+            subscription_lag,
+            last_sync_success,
+        })
+    }
+
+    pub fn observe_taskbars(&self, taskbars: &[TaskbarSnapshot]) {
+        self.taskbars.set(taskbars.len() as i64);
+        // Taskbars of unplugged monitors should not linger
+        self.taskbar_apps.reset();
+        for taskbar in taskbars {
+            self.taskbar_apps
+                .with_label_values(&[&taskbar.index.to_string()])
+                .set(taskbar.apps.len() as i64);
+        }
+    }
+
+    /// Starts the connection series of `target` at 0, so targets that never connect show up.
+    pub fn add_target(&self, target: &str) {
+        self.connected.with_label_values(&[target]).set(0);
+        self.reconnects.with_label_values(&[target]).reset();
+    }
+
+    pub fn observe_sync_success(&self, target: &str, at: SystemTime) {
+        if let Ok(since_epoch) = at.duration_since(UNIX_EPOCH) {
+            self.last_sync_success
+                .with_label_values(&[target])
+                .set(since_epoch.as_secs_f64());
+        }
+    }
+
+    pub fn render(&self) -> Result<String> {
+        let mut text = Vec::new();
+        TextEncoder::new().encode(&self.registry.gather(), &mut text)?;
+        Ok(String::from_utf8(text)?)
+    }
+}
+
+/// Serves `GET /metrics` on `address` from a thread of its own.
+pub fn serve_metrics(address: SocketAddr, metrics: Arc<Metrics>) -> Result<()> {
+    let server =
+        Server::http(address).map_err(|e| eyre::eyre!("Failed to listen on {address}: {e}"))?;
+    std::thread::spawn(move || {
+        for request in server.incoming_requests() {
+            let response = match (request.url(), metrics.render()) {
+                ("/metrics", Ok(text)) => Response::from_string(text).with_header(
+                    Header::from_bytes("Content-Type", TextEncoder::new().format_type())
+                        .expect("Header is valid"),
+                ),
+                ("/metrics", Err(e)) => Response::from_string(e.to_string()).with_status_code(500),
+                _ => Response::from_string("Metrics are at /metrics").with_status_code(404),
+            };
+            if let Err(e) = request.respond(response) {
+                tracing::warn!("Failed to answer a metrics request: {e}");
+            }
+        }
+    });
+    tracing::info!("Serving metrics on http://{address}/metrics");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_targets_before_they_connect() {
+        let metrics = Metrics::new().unwrap();
+        metrics.add_target("http://home:3000/wrangler");
+        let text = metrics.render().unwrap();
+        assert!(
+            text.contains(r#"explorer_wrangler_connected{target="http://home:3000/wrangler"} 0"#),
+            "{text}"
+        );
+        assert!(
+            text.contains(
+                r#"explorer_wrangler_reconnects_total{target="http://home:3000/wrangler"} 0"#
+            ),
+            "{text}"
+        );
+    }
+}
@@ -7,15 +7,199 @@ use spacetimedb_sdk::__codegen::__sats;
 use spacetimedb_sdk::__codegen::__ws;
 use spacetimedb_sdk::__codegen::{self as __sdk};
 
+pub mod active_window_span_table;
+pub mod active_window_span_type;
+pub mod add_placement_rule_reducer;
+pub mod add_watch_rule_reducer;
+pub mod assign_zone_layout_reducer;
+pub mod clear_taskbar_policy_reducer;
+pub mod client_leaving_reducer;
+pub mod consolidate_duplicates_reducer;
+pub mod consolidate_duplicates_request_table;
+pub mod consolidate_duplicates_request_type;
+pub mod delete_placement_rule_reducer;
+pub mod delete_watch_rule_reducer;
+pub mod delete_zone_layout_reducer;
+pub mod desktop_window_table;
+pub mod desktop_window_type;
+pub mod drift_outcome_type;
+pub mod duplicate_window_group_table;
+pub mod duplicate_window_group_type;
+pub mod end_focus_reducer;
+pub mod explorer_tab_table;
+pub mod explorer_tab_type;
+pub mod folder_score_table;
+pub mod folder_score_type;
+pub mod folder_visit_table;
+pub mod folder_visit_type;
+pub mod machine_status_table;
+pub mod machine_status_type;
+pub mod migrate_schema_reducer;
+pub mod monitor_zone_layout_table;
+pub mod monitor_zone_layout_type;
+pub mod move_window_reducer;
+pub mod placement_region_type;
+pub mod placement_rule_table;
+pub mod placement_rule_type;
+pub mod record_folder_visit_reducer;
+pub mod report_duplicates_consolidated_reducer;
+pub mod report_focus_reducer;
+pub mod report_taskbar_policy_reducer;
+pub mod report_window_move_reducer;
+pub mod schema_info_table;
+pub mod schema_info_type;
+pub mod set_taskbar_policy_reducer;
+pub mod set_zone_layout_reducer;
+pub mod stats_table;
+pub mod sync_explorer_tabs_reducer;
+pub mod sync_stats_type;
 pub mod sync_taskbars_reducer;
+pub mod sync_virtual_desktops_reducer;
+pub mod taskbar_alignment_type;
+pub mod taskbar_combine_buttons_type;
+pub mod taskbar_edge_type;
+pub mod taskbar_policy_report_table;
+pub mod taskbar_policy_report_type;
+pub mod taskbar_policy_table;
+pub mod taskbar_policy_type;
+pub mod taskbar_setting_drift_type;
 pub mod taskbar_table;
 pub mod taskbar_type;
-
+pub mod update_placement_rule_reducer;
+pub mod update_watch_rule_reducer;
+pub mod virtual_desktop_table;
+pub mod virtual_desktop_type;
+pub mod watch_event_type;
+pub mod watch_rule_table;
+pub mod watch_rule_type;
+pub mod window_move_request_table;
+pub mod window_move_request_type;
+pub mod window_move_result_table;
+pub mod window_move_result_type;
+pub mod window_show_state_type;
+pub mod zone_layout_table;
+pub mod zone_layout_type;
+
+pub use active_window_span_table::*;
+pub use active_window_span_type::ActiveWindowSpan;
+pub use add_placement_rule_reducer::add_placement_rule;
+pub use add_placement_rule_reducer::set_flags_for_add_placement_rule;
+pub use add_placement_rule_reducer::AddPlacementRuleCallbackId;
+pub use add_watch_rule_reducer::add_watch_rule;
+pub use add_watch_rule_reducer::set_flags_for_add_watch_rule;
+pub use add_watch_rule_reducer::AddWatchRuleCallbackId;
+pub use assign_zone_layout_reducer::assign_zone_layout;
+pub use assign_zone_layout_reducer::set_flags_for_assign_zone_layout;
+pub use assign_zone_layout_reducer::AssignZoneLayoutCallbackId;
+pub use clear_taskbar_policy_reducer::clear_taskbar_policy;
+pub use clear_taskbar_policy_reducer::set_flags_for_clear_taskbar_policy;
+pub use clear_taskbar_policy_reducer::ClearTaskbarPolicyCallbackId;
+pub use client_leaving_reducer::client_leaving;
+pub use client_leaving_reducer::set_flags_for_client_leaving;
+pub use client_leaving_reducer::ClientLeavingCallbackId;
+pub use consolidate_duplicates_reducer::consolidate_duplicates;
+pub use consolidate_duplicates_reducer::set_flags_for_consolidate_duplicates;
+pub use consolidate_duplicates_reducer::ConsolidateDuplicatesCallbackId;
+pub use consolidate_duplicates_request_table::*;
+pub use consolidate_duplicates_request_type::ConsolidateDuplicatesRequest;
+pub use delete_placement_rule_reducer::delete_placement_rule;
+pub use delete_placement_rule_reducer::set_flags_for_delete_placement_rule;
+pub use delete_placement_rule_reducer::DeletePlacementRuleCallbackId;
+pub use delete_watch_rule_reducer::delete_watch_rule;
+pub use delete_watch_rule_reducer::set_flags_for_delete_watch_rule;
+pub use delete_watch_rule_reducer::DeleteWatchRuleCallbackId;
+pub use delete_zone_layout_reducer::delete_zone_layout;
+pub use delete_zone_layout_reducer::set_flags_for_delete_zone_layout;
+pub use delete_zone_layout_reducer::DeleteZoneLayoutCallbackId;
+pub use desktop_window_table::*;
+pub use desktop_window_type::DesktopWindow;
+pub use drift_outcome_type::DriftOutcome;
+pub use duplicate_window_group_table::*;
+pub use duplicate_window_group_type::DuplicateWindowGroup;
+pub use end_focus_reducer::end_focus;
+pub use end_focus_reducer::set_flags_for_end_focus;
+pub use end_focus_reducer::EndFocusCallbackId;
+pub use explorer_tab_table::*;
+pub use explorer_tab_type::ExplorerTab;
+pub use folder_score_table::*;
+pub use folder_score_type::FolderScore;
+pub use folder_visit_table::*;
+pub use folder_visit_type::FolderVisit;
+pub use machine_status_table::*;
+pub use machine_status_type::MachineStatus;
+pub use migrate_schema_reducer::migrate_schema;
+pub use migrate_schema_reducer::set_flags_for_migrate_schema;
+pub use migrate_schema_reducer::MigrateSchemaCallbackId;
+pub use monitor_zone_layout_table::*;
+pub use monitor_zone_layout_type::MonitorZoneLayout;
+pub use move_window_reducer::move_window;
+pub use move_window_reducer::set_flags_for_move_window;
+pub use move_window_reducer::MoveWindowCallbackId;
+pub use placement_region_type::PlacementRegion;
+pub use placement_rule_table::*;
+pub use placement_rule_type::PlacementRule;
+pub use record_folder_visit_reducer::record_folder_visit;
+pub use record_folder_visit_reducer::set_flags_for_record_folder_visit;
+pub use record_folder_visit_reducer::RecordFolderVisitCallbackId;
+pub use report_duplicates_consolidated_reducer::report_duplicates_consolidated;
+pub use report_duplicates_consolidated_reducer::set_flags_for_report_duplicates_consolidated;
+pub use report_duplicates_consolidated_reducer::ReportDuplicatesConsolidatedCallbackId;
+pub use report_focus_reducer::report_focus;
+pub use report_focus_reducer::set_flags_for_report_focus;
+pub use report_focus_reducer::ReportFocusCallbackId;
+pub use report_taskbar_policy_reducer::report_taskbar_policy;
+pub use report_taskbar_policy_reducer::set_flags_for_report_taskbar_policy;
+pub use report_taskbar_policy_reducer::ReportTaskbarPolicyCallbackId;
+pub use report_window_move_reducer::report_window_move;
+pub use report_window_move_reducer::set_flags_for_report_window_move;
+pub use report_window_move_reducer::ReportWindowMoveCallbackId;
+pub use schema_info_table::*;
+pub use schema_info_type::SchemaInfo;
+pub use set_taskbar_policy_reducer::set_flags_for_set_taskbar_policy;
+pub use set_taskbar_policy_reducer::set_taskbar_policy;
+pub use set_taskbar_policy_reducer::SetTaskbarPolicyCallbackId;
+pub use set_zone_layout_reducer::set_flags_for_set_zone_layout;
+pub use set_zone_layout_reducer::set_zone_layout;
+pub use set_zone_layout_reducer::SetZoneLayoutCallbackId;
+pub use stats_table::*;
+pub use sync_explorer_tabs_reducer::set_flags_for_sync_explorer_tabs;
+pub use sync_explorer_tabs_reducer::sync_explorer_tabs;
+pub use sync_explorer_tabs_reducer::SyncExplorerTabsCallbackId;
+pub use sync_stats_type::SyncStats;
 pub use sync_taskbars_reducer::set_flags_for_sync_taskbars;
 pub use sync_taskbars_reducer::sync_taskbars;
 pub use sync_taskbars_reducer::SyncTaskbarsCallbackId;
+pub use sync_virtual_desktops_reducer::set_flags_for_sync_virtual_desktops;
+pub use sync_virtual_desktops_reducer::sync_virtual_desktops;
+pub use sync_virtual_desktops_reducer::SyncVirtualDesktopsCallbackId;
+pub use taskbar_alignment_type::TaskbarAlignment;
+pub use taskbar_combine_buttons_type::TaskbarCombineButtons;
+pub use taskbar_edge_type::TaskbarEdge;
+pub use taskbar_policy_report_table::*;
+pub use taskbar_policy_report_type::TaskbarPolicyReport;
+pub use taskbar_policy_table::*;
+pub use taskbar_policy_type::TaskbarPolicy;
+pub use taskbar_setting_drift_type::TaskbarSettingDrift;
 pub use taskbar_table::*;
 pub use taskbar_type::Taskbar;
+pub use update_placement_rule_reducer::set_flags_for_update_placement_rule;
+pub use update_placement_rule_reducer::update_placement_rule;
+pub use update_placement_rule_reducer::UpdatePlacementRuleCallbackId;
+pub use update_watch_rule_reducer::set_flags_for_update_watch_rule;
+pub use update_watch_rule_reducer::update_watch_rule;
+pub use update_watch_rule_reducer::UpdateWatchRuleCallbackId;
+pub use virtual_desktop_table::*;
+pub use virtual_desktop_type::VirtualDesktop;
+pub use watch_event_type::WatchEvent;
+pub use watch_rule_table::*;
+pub use watch_rule_type::WatchRule;
+pub use window_move_request_table::*;
+pub use window_move_request_type::WindowMoveRequest;
+pub use window_move_result_table::*;
+pub use window_move_result_type::WindowMoveResult;
+pub use window_show_state_type::WindowShowState;
+pub use zone_layout_table::*;
+pub use zone_layout_type::ZoneLayout;
 
 #[derive(Clone, PartialEq, Debug)]
 
@@ -25,7 +209,100 @@ pub use taskbar_type::Taskbar;
 /// to indicate which reducer caused the event.
 
 pub enum Reducer {
-    SyncTaskbars { taskbars: Vec<Taskbar> },
+    AddPlacementRule {
+        rule: PlacementRule,
+    },
+    AddWatchRule {
+        rule: WatchRule,
+    },
+    AssignZoneLayout {
+        machine: String,
+        taskbar_index: u32,
+        layout: Option<String>,
+    },
+    ClearTaskbarPolicy {
+        machine: Option<String>,
+    },
+    ClientLeaving {
+        machine: String,
+    },
+    ConsolidateDuplicates {
+        machine: String,
+    },
+    DeletePlacementRule {
+        id: u32,
+    },
+    DeleteWatchRule {
+        id: u32,
+    },
+    DeleteZoneLayout {
+        id: u32,
+    },
+    EndFocus {
+        machine: String,
+        at: __sdk::Timestamp,
+    },
+    MigrateSchema {},
+    MoveWindow {
+        machine: String,
+        window_id: u64,
+        target_taskbar_id: u32,
+    },
+    RecordFolderVisit {
+        machine: String,
+        path: String,
+        visited_at: __sdk::Timestamp,
+        focused_ms: u64,
+    },
+    ReportDuplicatesConsolidated {
+        request_id: u64,
+        closed: u32,
+        error: Option<String>,
+    },
+    ReportFocus {
+        machine: String,
+        window_id: u64,
+        title: String,
+        process: Option<String>,
+        path: Option<String>,
+        at: __sdk::Timestamp,
+    },
+    ReportTaskbarPolicy {
+        machine: String,
+        drift: Vec<TaskbarSettingDrift>,
+    },
+    ReportWindowMove {
+        request_id: u64,
+        error: Option<String>,
+    },
+    SetTaskbarPolicy {
+        machine: Option<String>,
+        auto_hide: Option<bool>,
+        edge: Option<TaskbarEdge>,
+        alignment: Option<TaskbarAlignment>,
+        combine_buttons: Option<TaskbarCombineButtons>,
+    },
+    SetZoneLayout {
+        layout: ZoneLayout,
+    },
+    SyncExplorerTabs {
+        machine: String,
+        tabs: Vec<ExplorerTab>,
+    },
+    SyncTaskbars {
+        taskbars: Vec<Taskbar>,
+    },
+    SyncVirtualDesktops {
+        machine: String,
+        desktops: Vec<VirtualDesktop>,
+        windows: Vec<DesktopWindow>,
+    },
+    UpdatePlacementRule {
+        rule: PlacementRule,
+    },
+    UpdateWatchRule {
+        rule: WatchRule,
+    },
 }
 
 impl __sdk::InModule for Reducer {
@@ -35,7 +312,30 @@ impl __sdk::InModule for Reducer {
 impl __sdk::Reducer for Reducer {
     fn reducer_name(&self) -> &'static str {
         match self {
+            Reducer::AddPlacementRule { .. } => "add_placement_rule",
+            Reducer::AddWatchRule { .. } => "add_watch_rule",
+            Reducer::AssignZoneLayout { .. } => "assign_zone_layout",
+            Reducer::ClearTaskbarPolicy { .. } => "clear_taskbar_policy",
+            Reducer::ClientLeaving { .. } => "client_leaving",
+            Reducer::ConsolidateDuplicates { .. } => "consolidate_duplicates",
+            Reducer::DeletePlacementRule { .. } => "delete_placement_rule",
+            Reducer::DeleteWatchRule { .. } => "delete_watch_rule",
+            Reducer::DeleteZoneLayout { .. } => "delete_zone_layout",
+            Reducer::EndFocus { .. } => "end_focus",
+            Reducer::MigrateSchema { .. } => "migrate_schema",
+            Reducer::MoveWindow { .. } => "move_window",
+            Reducer::RecordFolderVisit { .. } => "record_folder_visit",
+            Reducer::ReportDuplicatesConsolidated { .. } => "report_duplicates_consolidated",
+            Reducer::ReportFocus { .. } => "report_focus",
+            Reducer::ReportTaskbarPolicy { .. } => "report_taskbar_policy",
+            Reducer::ReportWindowMove { .. } => "report_window_move",
+            Reducer::SetTaskbarPolicy { .. } => "set_taskbar_policy",
+            Reducer::SetZoneLayout { .. } => "set_zone_layout",
+            Reducer::SyncExplorerTabs { .. } => "sync_explorer_tabs",
             Reducer::SyncTaskbars { .. } => "sync_taskbars",
+            Reducer::SyncVirtualDesktops { .. } => "sync_virtual_desktops",
+            Reducer::UpdatePlacementRule { .. } => "update_placement_rule",
+            Reducer::UpdateWatchRule { .. } => "update_watch_rule",
         }
     }
 }
@@ -43,10 +343,113 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
     type Error = __sdk::Error;
     fn try_from(value: __ws::ReducerCallInfo<__ws::BsatnFormat>) -> __sdk::Result<Self> {
         match &value.reducer_name[..] {
+            "add_placement_rule" => Ok(__sdk::parse_reducer_args::<
+                add_placement_rule_reducer::AddPlacementRuleArgs,
+            >("add_placement_rule", &value.args)?
+            .into()),
+            "add_watch_rule" => Ok(__sdk::parse_reducer_args::<
+                add_watch_rule_reducer::AddWatchRuleArgs,
+            >("add_watch_rule", &value.args)?
+            .into()),
+            "assign_zone_layout" => Ok(__sdk::parse_reducer_args::<
+                assign_zone_layout_reducer::AssignZoneLayoutArgs,
+            >("assign_zone_layout", &value.args)?
+            .into()),
+            "clear_taskbar_policy" => Ok(__sdk::parse_reducer_args::<
+                clear_taskbar_policy_reducer::ClearTaskbarPolicyArgs,
+            >("clear_taskbar_policy", &value.args)?
+            .into()),
+            "client_leaving" => Ok(__sdk::parse_reducer_args::<
+                client_leaving_reducer::ClientLeavingArgs,
+            >("client_leaving", &value.args)?
+            .into()),
+            "consolidate_duplicates" => Ok(__sdk::parse_reducer_args::<
+                consolidate_duplicates_reducer::ConsolidateDuplicatesArgs,
+            >("consolidate_duplicates", &value.args)?
+            .into()),
+            "delete_placement_rule" => Ok(__sdk::parse_reducer_args::<
+                delete_placement_rule_reducer::DeletePlacementRuleArgs,
+            >("delete_placement_rule", &value.args)?
+            .into()),
+            "delete_watch_rule" => Ok(__sdk::parse_reducer_args::<
+                delete_watch_rule_reducer::DeleteWatchRuleArgs,
+            >("delete_watch_rule", &value.args)?
+            .into()),
+            "delete_zone_layout" => Ok(__sdk::parse_reducer_args::<
+                delete_zone_layout_reducer::DeleteZoneLayoutArgs,
+            >("delete_zone_layout", &value.args)?
+            .into()),
+            "end_focus" => Ok(
+                __sdk::parse_reducer_args::<end_focus_reducer::EndFocusArgs>(
+                    "end_focus",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "migrate_schema" => Ok(__sdk::parse_reducer_args::<
+                migrate_schema_reducer::MigrateSchemaArgs,
+            >("migrate_schema", &value.args)?
+            .into()),
+            "move_window" => Ok(
+                __sdk::parse_reducer_args::<move_window_reducer::MoveWindowArgs>(
+                    "move_window",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "record_folder_visit" => Ok(__sdk::parse_reducer_args::<
+                record_folder_visit_reducer::RecordFolderVisitArgs,
+            >("record_folder_visit", &value.args)?
+            .into()),
+            "report_duplicates_consolidated" => {
+                Ok(__sdk::parse_reducer_args::<
+                    report_duplicates_consolidated_reducer::ReportDuplicatesConsolidatedArgs,
+                >("report_duplicates_consolidated", &value.args)?
+                .into())
+            }
+            "report_focus" => Ok(
+                __sdk::parse_reducer_args::<report_focus_reducer::ReportFocusArgs>(
+                    "report_focus",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "report_taskbar_policy" => Ok(__sdk::parse_reducer_args::<
+                report_taskbar_policy_reducer::ReportTaskbarPolicyArgs,
+            >("report_taskbar_policy", &value.args)?
+            .into()),
+            "report_window_move" => Ok(__sdk::parse_reducer_args::<
+                report_window_move_reducer::ReportWindowMoveArgs,
+            >("report_window_move", &value.args)?
+            .into()),
+            "set_taskbar_policy" => Ok(__sdk::parse_reducer_args::<
+                set_taskbar_policy_reducer::SetTaskbarPolicyArgs,
+            >("set_taskbar_policy", &value.args)?
+            .into()),
+            "set_zone_layout" => Ok(__sdk::parse_reducer_args::<
+                set_zone_layout_reducer::SetZoneLayoutArgs,
+            >("set_zone_layout", &value.args)?
+            .into()),
+            "sync_explorer_tabs" => Ok(__sdk::parse_reducer_args::<
+                sync_explorer_tabs_reducer::SyncExplorerTabsArgs,
+            >("sync_explorer_tabs", &value.args)?
+            .into()),
             "sync_taskbars" => Ok(__sdk::parse_reducer_args::<
                 sync_taskbars_reducer::SyncTaskbarsArgs,
             >("sync_taskbars", &value.args)?
             .into()),
+            "sync_virtual_desktops" => Ok(__sdk::parse_reducer_args::<
+                sync_virtual_desktops_reducer::SyncVirtualDesktopsArgs,
+            >("sync_virtual_desktops", &value.args)?
+            .into()),
+            "update_placement_rule" => Ok(__sdk::parse_reducer_args::<
+                update_placement_rule_reducer::UpdatePlacementRuleArgs,
+            >("update_placement_rule", &value.args)?
+            .into()),
+            "update_watch_rule" => Ok(__sdk::parse_reducer_args::<
+                update_watch_rule_reducer::UpdateWatchRuleArgs,
+            >("update_watch_rule", &value.args)?
+            .into()),
             unknown => {
                 Err(
                     __sdk::InternalError::unknown_name("reducer", unknown, "ReducerCallInfo")
@@ -61,7 +464,26 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
 #[allow(non_snake_case)]
 #[doc(hidden)]
 pub struct DbUpdate {
+    active_window_span: __sdk::TableUpdate<ActiveWindowSpan>,
+    consolidate_duplicates_request: __sdk::TableUpdate<ConsolidateDuplicatesRequest>,
+    desktop_window: __sdk::TableUpdate<DesktopWindow>,
+    duplicate_window_group: __sdk::TableUpdate<DuplicateWindowGroup>,
+    explorer_tab: __sdk::TableUpdate<ExplorerTab>,
+    folder_score: __sdk::TableUpdate<FolderScore>,
+    folder_visit: __sdk::TableUpdate<FolderVisit>,
+    machine_status: __sdk::TableUpdate<MachineStatus>,
+    monitor_zone_layout: __sdk::TableUpdate<MonitorZoneLayout>,
+    placement_rule: __sdk::TableUpdate<PlacementRule>,
+    schema_info: __sdk::TableUpdate<SchemaInfo>,
+    stats: __sdk::TableUpdate<SyncStats>,
     taskbar: __sdk::TableUpdate<Taskbar>,
+    taskbar_policy: __sdk::TableUpdate<TaskbarPolicy>,
+    taskbar_policy_report: __sdk::TableUpdate<TaskbarPolicyReport>,
+    virtual_desktop: __sdk::TableUpdate<VirtualDesktop>,
+    watch_rule: __sdk::TableUpdate<WatchRule>,
+    window_move_request: __sdk::TableUpdate<WindowMoveRequest>,
+    window_move_result: __sdk::TableUpdate<WindowMoveResult>,
+    zone_layout: __sdk::TableUpdate<ZoneLayout>,
 }
 
 impl TryFrom<__ws::DatabaseUpdate<__ws::BsatnFormat>> for DbUpdate {
@@ -70,7 +492,68 @@ impl TryFrom<__ws::DatabaseUpdate<__ws::BsatnFormat>> for DbUpdate {
         let mut db_update = DbUpdate::default();
         for table_update in raw.tables {
             match &table_update.table_name[..] {
-                "taskbar" => db_update.taskbar = taskbar_table::parse_table_update(table_update)?,
+                "active_window_span" => db_update
+                    .active_window_span
+                    .append(active_window_span_table::parse_table_update(table_update)?),
+                "consolidate_duplicates_request" => {
+                    db_update.consolidate_duplicates_request.append(
+                        consolidate_duplicates_request_table::parse_table_update(table_update)?,
+                    )
+                }
+                "desktop_window" => db_update
+                    .desktop_window
+                    .append(desktop_window_table::parse_table_update(table_update)?),
+                "duplicate_window_group" => db_update.duplicate_window_group.append(
+                    duplicate_window_group_table::parse_table_update(table_update)?,
+                ),
+                "explorer_tab" => db_update
+                    .explorer_tab
+                    .append(explorer_tab_table::parse_table_update(table_update)?),
+                "folder_score" => db_update
+                    .folder_score
+                    .append(folder_score_table::parse_table_update(table_update)?),
+                "folder_visit" => db_update
+                    .folder_visit
+                    .append(folder_visit_table::parse_table_update(table_update)?),
+                "machine_status" => db_update
+                    .machine_status
+                    .append(machine_status_table::parse_table_update(table_update)?),
+                "monitor_zone_layout" => db_update
+                    .monitor_zone_layout
+                    .append(monitor_zone_layout_table::parse_table_update(table_update)?),
+                "placement_rule" => db_update
+                    .placement_rule
+                    .append(placement_rule_table::parse_table_update(table_update)?),
+                "schema_info" => db_update
+                    .schema_info
+                    .append(schema_info_table::parse_table_update(table_update)?),
+                "stats" => db_update
+                    .stats
+                    .append(stats_table::parse_table_update(table_update)?),
+                "taskbar" => db_update
+                    .taskbar
+                    .append(taskbar_table::parse_table_update(table_update)?),
+                "taskbar_policy" => db_update
+                    .taskbar_policy
+                    .append(taskbar_policy_table::parse_table_update(table_update)?),
+                "taskbar_policy_report" => db_update.taskbar_policy_report.append(
+                    taskbar_policy_report_table::parse_table_update(table_update)?,
+                ),
+                "virtual_desktop" => db_update
+                    .virtual_desktop
+                    .append(virtual_desktop_table::parse_table_update(table_update)?),
+                "watch_rule" => db_update
+                    .watch_rule
+                    .append(watch_rule_table::parse_table_update(table_update)?),
+                "window_move_request" => db_update
+                    .window_move_request
+                    .append(window_move_request_table::parse_table_update(table_update)?),
+                "window_move_result" => db_update
+                    .window_move_result
+                    .append(window_move_result_table::parse_table_update(table_update)?),
+                "zone_layout" => db_update
+                    .zone_layout
+                    .append(zone_layout_table::parse_table_update(table_update)?),
 
                 unknown => {
                     return Err(__sdk::InternalError::unknown_name(
@@ -97,9 +580,81 @@ impl __sdk::DbUpdate for DbUpdate {
     ) -> AppliedDiff<'_> {
         let mut diff = AppliedDiff::default();
 
+        diff.active_window_span = cache
+            .apply_diff_to_table::<ActiveWindowSpan>("active_window_span", &self.active_window_span)
+            .with_updates_by_pk(|row| &row.id);
+        diff.consolidate_duplicates_request = cache
+            .apply_diff_to_table::<ConsolidateDuplicatesRequest>(
+                "consolidate_duplicates_request",
+                &self.consolidate_duplicates_request,
+            )
+            .with_updates_by_pk(|row| &row.id);
+        diff.desktop_window = cache
+            .apply_diff_to_table::<DesktopWindow>("desktop_window", &self.desktop_window)
+            .with_updates_by_pk(|row| &row.id);
+        diff.duplicate_window_group = cache
+            .apply_diff_to_table::<DuplicateWindowGroup>(
+                "duplicate_window_group",
+                &self.duplicate_window_group,
+            )
+            .with_updates_by_pk(|row| &row.id);
+        diff.explorer_tab = cache
+            .apply_diff_to_table::<ExplorerTab>("explorer_tab", &self.explorer_tab)
+            .with_updates_by_pk(|row| &row.id);
+        diff.folder_score = cache
+            .apply_diff_to_table::<FolderScore>("folder_score", &self.folder_score)
+            .with_updates_by_pk(|row| &row.id);
+        diff.folder_visit = cache
+            .apply_diff_to_table::<FolderVisit>("folder_visit", &self.folder_visit)
+            .with_updates_by_pk(|row| &row.id);
+        diff.machine_status = cache
+            .apply_diff_to_table::<MachineStatus>("machine_status", &self.machine_status)
+            .with_updates_by_pk(|row| &row.machine);
+        diff.monitor_zone_layout = cache
+            .apply_diff_to_table::<MonitorZoneLayout>(
+                "monitor_zone_layout",
+                &self.monitor_zone_layout,
+            )
+            .with_updates_by_pk(|row| &row.id);
+        diff.placement_rule = cache
+            .apply_diff_to_table::<PlacementRule>("placement_rule", &self.placement_rule)
+            .with_updates_by_pk(|row| &row.id);
+        diff.schema_info = cache
+            .apply_diff_to_table::<SchemaInfo>("schema_info", &self.schema_info)
+            .with_updates_by_pk(|row| &row.id);
+        diff.stats = cache
+            .apply_diff_to_table::<SyncStats>("stats", &self.stats)
+            .with_updates_by_pk(|row| &row.machine);
         diff.taskbar = cache
             .apply_diff_to_table::<Taskbar>("taskbar", &self.taskbar)
             .with_updates_by_pk(|row| &row.id);
+        diff.taskbar_policy = cache
+            .apply_diff_to_table::<TaskbarPolicy>("taskbar_policy", &self.taskbar_policy)
+            .with_updates_by_pk(|row| &row.id);
+        diff.taskbar_policy_report = cache
+            .apply_diff_to_table::<TaskbarPolicyReport>(
+                "taskbar_policy_report",
+                &self.taskbar_policy_report,
+            )
+            .with_updates_by_pk(|row| &row.machine);
+        diff.virtual_desktop = cache
+            .apply_diff_to_table::<VirtualDesktop>("virtual_desktop", &self.virtual_desktop)
+            .with_updates_by_pk(|row| &row.id);
+        diff.watch_rule = cache
+            .apply_diff_to_table::<WatchRule>("watch_rule", &self.watch_rule)
+            .with_updates_by_pk(|row| &row.id);
+        diff.window_move_request = cache
+            .apply_diff_to_table::<WindowMoveRequest>(
+                "window_move_request",
+                &self.window_move_request,
+            )
+            .with_updates_by_pk(|row| &row.id);
+        diff.window_move_result = cache
+            .apply_diff_to_table::<WindowMoveResult>("window_move_result", &self.window_move_result)
+            .with_updates_by_pk(|row| &row.request_id);
+        diff.zone_layout = cache
+            .apply_diff_to_table::<ZoneLayout>("zone_layout", &self.zone_layout)
+            .with_updates_by_pk(|row| &row.id);
 
         diff
     }
@@ -109,7 +664,26 @@ impl __sdk::DbUpdate for DbUpdate {
 #[allow(non_snake_case)]
 #[doc(hidden)]
 pub struct AppliedDiff<'r> {
+    active_window_span: __sdk::TableAppliedDiff<'r, ActiveWindowSpan>,
+    consolidate_duplicates_request: __sdk::TableAppliedDiff<'r, ConsolidateDuplicatesRequest>,
+    desktop_window: __sdk::TableAppliedDiff<'r, DesktopWindow>,
+    duplicate_window_group: __sdk::TableAppliedDiff<'r, DuplicateWindowGroup>,
+    explorer_tab: __sdk::TableAppliedDiff<'r, ExplorerTab>,
+    folder_score: __sdk::TableAppliedDiff<'r, FolderScore>,
+    folder_visit: __sdk::TableAppliedDiff<'r, FolderVisit>,
+    machine_status: __sdk::TableAppliedDiff<'r, MachineStatus>,
+    monitor_zone_layout: __sdk::TableAppliedDiff<'r, MonitorZoneLayout>,
+    placement_rule: __sdk::TableAppliedDiff<'r, PlacementRule>,
+    schema_info: __sdk::TableAppliedDiff<'r, SchemaInfo>,
+    stats: __sdk::TableAppliedDiff<'r, SyncStats>,
     taskbar: __sdk::TableAppliedDiff<'r, Taskbar>,
+    taskbar_policy: __sdk::TableAppliedDiff<'r, TaskbarPolicy>,
+    taskbar_policy_report: __sdk::TableAppliedDiff<'r, TaskbarPolicyReport>,
+    virtual_desktop: __sdk::TableAppliedDiff<'r, VirtualDesktop>,
+    watch_rule: __sdk::TableAppliedDiff<'r, WatchRule>,
+    window_move_request: __sdk::TableAppliedDiff<'r, WindowMoveRequest>,
+    window_move_result: __sdk::TableAppliedDiff<'r, WindowMoveResult>,
+    zone_layout: __sdk::TableAppliedDiff<'r, ZoneLayout>,
 }
 
 impl __sdk::InModule for AppliedDiff<'_> {
@@ -122,7 +696,86 @@ impl<'r> __sdk::AppliedDiff<'r> for AppliedDiff<'r> {
         event: &EventContext,
         callbacks: &mut __sdk::DbCallbacks<RemoteModule>,
     ) {
+        callbacks.invoke_table_row_callbacks::<ActiveWindowSpan>(
+            "active_window_span",
+            &self.active_window_span,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<ConsolidateDuplicatesRequest>(
+            "consolidate_duplicates_request",
+            &self.consolidate_duplicates_request,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<DesktopWindow>(
+            "desktop_window",
+            &self.desktop_window,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<DuplicateWindowGroup>(
+            "duplicate_window_group",
+            &self.duplicate_window_group,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<ExplorerTab>(
+            "explorer_tab",
+            &self.explorer_tab,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<FolderScore>(
+            "folder_score",
+            &self.folder_score,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<FolderVisit>(
+            "folder_visit",
+            &self.folder_visit,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<MachineStatus>(
+            "machine_status",
+            &self.machine_status,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<MonitorZoneLayout>(
+            "monitor_zone_layout",
+            &self.monitor_zone_layout,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<PlacementRule>(
+            "placement_rule",
+            &self.placement_rule,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<SchemaInfo>("schema_info", &self.schema_info, event);
+        callbacks.invoke_table_row_callbacks::<SyncStats>("stats", &self.stats, event);
         callbacks.invoke_table_row_callbacks::<Taskbar>("taskbar", &self.taskbar, event);
+        callbacks.invoke_table_row_callbacks::<TaskbarPolicy>(
+            "taskbar_policy",
+            &self.taskbar_policy,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<TaskbarPolicyReport>(
+            "taskbar_policy_report",
+            &self.taskbar_policy_report,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<VirtualDesktop>(
+            "virtual_desktop",
+            &self.virtual_desktop,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<WatchRule>("watch_rule", &self.watch_rule, event);
+        callbacks.invoke_table_row_callbacks::<WindowMoveRequest>(
+            "window_move_request",
+            &self.window_move_request,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<WindowMoveResult>(
+            "window_move_result",
+            &self.window_move_result,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<ZoneLayout>("zone_layout", &self.zone_layout, event);
     }
 }
 
@@ -143,6 +796,16 @@ impl __sdk::InModule for RemoteReducers {
     type Module = RemoteModule;
 }
 
+/// The `procedures` field of [`DbConnection`] and other [`DbContext`] types,
+/// with methods provided by extension traits for each procedure defined by the module.
+pub struct RemoteProcedures {
+    imp: __sdk::DbContextImpl<RemoteModule>,
+}
+
+impl __sdk::InModule for RemoteProcedures {
+    type Module = RemoteModule;
+}
+
 #[doc(hidden)]
 /// The `set_reducer_flags` field of [`DbConnection`],
 /// with methods provided by extension traits for each reducer defined by the module.
@@ -195,6 +858,9 @@ pub struct DbConnection {
     /// This type is currently unstable and may be removed without a major version bump.
     pub set_reducer_flags: SetReducerFlags,
 
+    /// Access to procedures defined by the module via extension traits implemented for [`RemoteProcedures`].
+    pub procedures: RemoteProcedures,
+
     imp: __sdk::DbContextImpl<RemoteModule>,
 }
 
@@ -205,6 +871,7 @@ impl __sdk::InModule for DbConnection {
 impl __sdk::DbContext for DbConnection {
     type DbView = RemoteTables;
     type Reducers = RemoteReducers;
+    type Procedures = RemoteProcedures;
     type SetReducerFlags = SetReducerFlags;
 
     fn db(&self) -> &Self::DbView {
@@ -213,6 +880,9 @@ impl __sdk::DbContext for DbConnection {
     fn reducers(&self) -> &Self::Reducers {
         &self.reducers
     }
+    fn procedures(&self) -> &Self::Procedures {
+        &self.procedures
+    }
     fn set_reducer_flags(&self) -> &Self::SetReducerFlags {
         &self.set_reducer_flags
     }
@@ -237,6 +907,9 @@ impl __sdk::DbContext for DbConnection {
     fn connection_id(&self) -> __sdk::ConnectionId {
         self.imp.connection_id()
     }
+    fn try_connection_id(&self) -> Option<__sdk::ConnectionId> {
+        self.imp.try_connection_id()
+    }
 }
 
 impl DbConnection {
@@ -316,6 +989,7 @@ impl __sdk::DbConnection for DbConnection {
             db: RemoteTables { imp: imp.clone() },
             reducers: RemoteReducers { imp: imp.clone() },
             set_reducer_flags: SetReducerFlags { imp: imp.clone() },
+            procedures: RemoteProcedures { imp: imp.clone() },
             imp,
         }
     }
@@ -367,6 +1041,7 @@ pub trait RemoteDbContext:
     __sdk::DbContext<
     DbView = RemoteTables,
     Reducers = RemoteReducers,
+    Procedures = RemoteProcedures,
     SetReducerFlags = SetReducerFlags,
     SubscriptionBuilder = __sdk::SubscriptionBuilder<RemoteModule>,
 >
@@ -376,6 +1051,7 @@ impl<
         Ctx: __sdk::DbContext<
             DbView = RemoteTables,
             Reducers = RemoteReducers,
+            Procedures = RemoteProcedures,
             SetReducerFlags = SetReducerFlags,
             SubscriptionBuilder = __sdk::SubscriptionBuilder<RemoteModule>,
         >,
@@ -395,6 +1071,8 @@ pub struct EventContext {
     ///
     /// This type is currently unstable and may be removed without a major version bump.
     pub set_reducer_flags: SetReducerFlags,
+    /// Access to procedures defined by the module via extension traits implemented for [`RemoteProcedures`].
+    pub procedures: RemoteProcedures,
     /// The event which caused these callbacks to run.
     pub event: __sdk::Event<Reducer>,
     imp: __sdk::DbContextImpl<RemoteModule>,
@@ -410,6 +1088,7 @@ impl __sdk::AbstractEventContext for EventContext {
             db: RemoteTables { imp: imp.clone() },
             reducers: RemoteReducers { imp: imp.clone() },
             set_reducer_flags: SetReducerFlags { imp: imp.clone() },
+            procedures: RemoteProcedures { imp: imp.clone() },
             event,
             imp,
         }
@@ -423,6 +1102,7 @@ impl __sdk::InModule for EventContext {
 impl __sdk::DbContext for EventContext {
     type DbView = RemoteTables;
     type Reducers = RemoteReducers;
+    type Procedures = RemoteProcedures;
     type SetReducerFlags = SetReducerFlags;
 
     fn db(&self) -> &Self::DbView {
@@ -431,6 +1111,9 @@ impl __sdk::DbContext for EventContext {
     fn reducers(&self) -> &Self::Reducers {
         &self.reducers
     }
+    fn procedures(&self) -> &Self::Procedures {
+        &self.procedures
+    }
     fn set_reducer_flags(&self) -> &Self::SetReducerFlags {
         &self.set_reducer_flags
     }
@@ -455,6 +1138,9 @@ impl __sdk::DbContext for EventContext {
     fn connection_id(&self) -> __sdk::ConnectionId {
         self.imp.connection_id()
     }
+    fn try_connection_id(&self) -> Option<__sdk::ConnectionId> {
+        self.imp.try_connection_id()
+    }
 }
 
 impl __sdk::EventContext for EventContext {}
@@ -471,6 +1157,8 @@ pub struct ReducerEventContext {
     ///
     /// This type is currently unstable and may be removed without a major version bump.
     pub set_reducer_flags: SetReducerFlags,
+    /// Access to procedures defined by the module via extension traits implemented for [`RemoteProcedures`].
+    pub procedures: RemoteProcedures,
     /// The event which caused these callbacks to run.
     pub event: __sdk::ReducerEvent<Reducer>,
     imp: __sdk::DbContextImpl<RemoteModule>,
@@ -486,6 +1174,7 @@ impl __sdk::AbstractEventContext for ReducerEventContext {
             db: RemoteTables { imp: imp.clone() },
             reducers: RemoteReducers { imp: imp.clone() },
             set_reducer_flags: SetReducerFlags { imp: imp.clone() },
+            procedures: RemoteProcedures { imp: imp.clone() },
             event,
             imp,
         }
@@ -499,6 +1188,7 @@ impl __sdk::InModule for ReducerEventContext {
 impl __sdk::DbContext for ReducerEventContext {
     type DbView = RemoteTables;
     type Reducers = RemoteReducers;
+    type Procedures = RemoteProcedures;
     type SetReducerFlags = SetReducerFlags;
 
     fn db(&self) -> &Self::DbView {
@@ -507,6 +1197,9 @@ impl __sdk::DbContext for ReducerEventContext {
     fn reducers(&self) -> &Self::Reducers {
         &self.reducers
     }
+    fn procedures(&self) -> &Self::Procedures {
+        &self.procedures
+    }
     fn set_reducer_flags(&self) -> &Self::SetReducerFlags {
         &self.set_reducer_flags
     }
@@ -531,10 +1224,95 @@ impl __sdk::DbContext for ReducerEventContext {
     fn connection_id(&self) -> __sdk::ConnectionId {
         self.imp.connection_id()
     }
+    fn try_connection_id(&self) -> Option<__sdk::ConnectionId> {
+        self.imp.try_connection_id()
+    }
 }
 
 impl __sdk::ReducerEventContext for ReducerEventContext {}
 
+/// An [`__sdk::DbContext`] passed to on-procedure-call callbacks.
+pub struct ProcedureEventContext {
+    /// Access to tables defined by the module via extension traits implemented for [`RemoteTables`].
+    pub db: RemoteTables,
+    /// Access to reducers defined by the module via extension traits implemented for [`RemoteReducers`].
+    pub reducers: RemoteReducers,
+    /// Access to setting the call-flags of each reducer defined for each reducer defined by the module
+    /// via extension traits implemented for [`SetReducerFlags`].
+    ///
+    /// This type is currently unstable and may be removed without a major version bump.
+    pub set_reducer_flags: SetReducerFlags,
+    /// Access to procedures defined by the module via extension traits implemented for [`RemoteProcedures`].
+    pub procedures: RemoteProcedures,
+    imp: __sdk::DbContextImpl<RemoteModule>,
+}
+
+impl __sdk::AbstractEventContext for ProcedureEventContext {
+    type Event = ();
+    fn event(&self) -> &Self::Event {
+        &()
+    }
+    fn new(imp: __sdk::DbContextImpl<RemoteModule>, _event: Self::Event) -> Self {
+        Self {
+            db: RemoteTables { imp: imp.clone() },
+            reducers: RemoteReducers { imp: imp.clone() },
+            set_reducer_flags: SetReducerFlags { imp: imp.clone() },
+            procedures: RemoteProcedures { imp: imp.clone() },
+            imp,
+        }
+    }
+}
+
+impl __sdk::InModule for ProcedureEventContext {
+    type Module = RemoteModule;
+}
+
+impl __sdk::DbContext for ProcedureEventContext {
+    type DbView = RemoteTables;
+    type Reducers = RemoteReducers;
+    type Procedures = RemoteProcedures;
+    type SetReducerFlags = SetReducerFlags;
+
+    fn db(&self) -> &Self::DbView {
+        &self.db
+    }
+    fn reducers(&self) -> &Self::Reducers {
+        &self.reducers
+    }
+    fn procedures(&self) -> &Self::Procedures {
+        &self.procedures
+    }
+    fn set_reducer_flags(&self) -> &Self::SetReducerFlags {
+        &self.set_reducer_flags
+    }
+
+    fn is_active(&self) -> bool {
+        self.imp.is_active()
+    }
+
+    fn disconnect(&self) -> __sdk::Result<()> {
+        self.imp.disconnect()
+    }
+
+    type SubscriptionBuilder = __sdk::SubscriptionBuilder<RemoteModule>;
+
+    fn subscription_builder(&self) -> Self::SubscriptionBuilder {
+        __sdk::SubscriptionBuilder::new(&self.imp)
+    }
+
+    fn try_identity(&self) -> Option<__sdk::Identity> {
+        self.imp.try_identity()
+    }
+    fn connection_id(&self) -> __sdk::ConnectionId {
+        self.imp.connection_id()
+    }
+    fn try_connection_id(&self) -> Option<__sdk::ConnectionId> {
+        self.imp.try_connection_id()
+    }
+}
+
+impl __sdk::ProcedureEventContext for ProcedureEventContext {}
+
 /// An [`__sdk::DbContext`] passed to [`__sdk::SubscriptionBuilder::on_applied`] and [`SubscriptionHandle::unsubscribe_then`] callbacks.
 pub struct SubscriptionEventContext {
     /// Access to tables defined by the module via extension traits implemented for [`RemoteTables`].
@@ -546,6 +1324,8 @@ pub struct SubscriptionEventContext {
     ///
     /// This type is currently unstable and may be removed without a major version bump.
     pub set_reducer_flags: SetReducerFlags,
+    /// Access to procedures defined by the module via extension traits implemented for [`RemoteProcedures`].
+    pub procedures: RemoteProcedures,
     imp: __sdk::DbContextImpl<RemoteModule>,
 }
 
@@ -559,6 +1339,7 @@ impl __sdk::AbstractEventContext for SubscriptionEventContext {
             db: RemoteTables { imp: imp.clone() },
             reducers: RemoteReducers { imp: imp.clone() },
             set_reducer_flags: SetReducerFlags { imp: imp.clone() },
+            procedures: RemoteProcedures { imp: imp.clone() },
             imp,
         }
     }
@@ -571,6 +1352,7 @@ impl __sdk::InModule for SubscriptionEventContext {
 impl __sdk::DbContext for SubscriptionEventContext {
     type DbView = RemoteTables;
     type Reducers = RemoteReducers;
+    type Procedures = RemoteProcedures;
     type SetReducerFlags = SetReducerFlags;
 
     fn db(&self) -> &Self::DbView {
@@ -579,6 +1361,9 @@ impl __sdk::DbContext for SubscriptionEventContext {
     fn reducers(&self) -> &Self::Reducers {
         &self.reducers
     }
+    fn procedures(&self) -> &Self::Procedures {
+        &self.procedures
+    }
     fn set_reducer_flags(&self) -> &Self::SetReducerFlags {
         &self.set_reducer_flags
     }
@@ -603,6 +1388,9 @@ impl __sdk::DbContext for SubscriptionEventContext {
     fn connection_id(&self) -> __sdk::ConnectionId {
         self.imp.connection_id()
     }
+    fn try_connection_id(&self) -> Option<__sdk::ConnectionId> {
+        self.imp.try_connection_id()
+    }
 }
 
 impl __sdk::SubscriptionEventContext for SubscriptionEventContext {}
@@ -619,6 +1407,8 @@ pub struct ErrorContext {
     ///
     /// This type is currently unstable and may be removed without a major version bump.
     pub set_reducer_flags: SetReducerFlags,
+    /// Access to procedures defined by the module via extension traits implemented for [`RemoteProcedures`].
+    pub procedures: RemoteProcedures,
     /// The event which caused these callbacks to run.
     pub event: Option<__sdk::Error>,
     imp: __sdk::DbContextImpl<RemoteModule>,
@@ -634,6 +1424,7 @@ impl __sdk::AbstractEventContext for ErrorContext {
             db: RemoteTables { imp: imp.clone() },
             reducers: RemoteReducers { imp: imp.clone() },
             set_reducer_flags: SetReducerFlags { imp: imp.clone() },
+            procedures: RemoteProcedures { imp: imp.clone() },
             event,
             imp,
         }
@@ -647,6 +1438,7 @@ impl __sdk::InModule for ErrorContext {
 impl __sdk::DbContext for ErrorContext {
     type DbView = RemoteTables;
     type Reducers = RemoteReducers;
+    type Procedures = RemoteProcedures;
     type SetReducerFlags = SetReducerFlags;
 
     fn db(&self) -> &Self::DbView {
@@ -655,6 +1447,9 @@ impl __sdk::DbContext for ErrorContext {
     fn reducers(&self) -> &Self::Reducers {
         &self.reducers
     }
+    fn procedures(&self) -> &Self::Procedures {
+        &self.procedures
+    }
     fn set_reducer_flags(&self) -> &Self::SetReducerFlags {
         &self.set_reducer_flags
     }
@@ -679,6 +1474,9 @@ impl __sdk::DbContext for ErrorContext {
     fn connection_id(&self) -> __sdk::ConnectionId {
         self.imp.connection_id()
     }
+    fn try_connection_id(&self) -> Option<__sdk::ConnectionId> {
+        self.imp.try_connection_id()
+    }
 }
 
 impl __sdk::ErrorContext for ErrorContext {}
@@ -687,6 +1485,7 @@ impl __sdk::SpacetimeModule for RemoteModule {
     type DbConnection = DbConnection;
     type EventContext = EventContext;
     type ReducerEventContext = ReducerEventContext;
+    type ProcedureEventContext = ProcedureEventContext;
     type SubscriptionEventContext = SubscriptionEventContext;
     type ErrorContext = ErrorContext;
     type Reducer = Reducer;
@@ -696,8 +1495,28 @@ impl __sdk::SpacetimeModule for RemoteModule {
     type DbUpdate = DbUpdate;
     type AppliedDiff<'r> = AppliedDiff<'r>;
     type SubscriptionHandle = SubscriptionHandle;
+    type QueryBuilder = __sdk::QueryBuilder;
 
     fn register_tables(client_cache: &mut __sdk::ClientCache<Self>) {
+        active_window_span_table::register_table(client_cache);
+        consolidate_duplicates_request_table::register_table(client_cache);
+        desktop_window_table::register_table(client_cache);
+        duplicate_window_group_table::register_table(client_cache);
+        explorer_tab_table::register_table(client_cache);
+        folder_score_table::register_table(client_cache);
+        folder_visit_table::register_table(client_cache);
+        machine_status_table::register_table(client_cache);
+        monitor_zone_layout_table::register_table(client_cache);
+        placement_rule_table::register_table(client_cache);
+        schema_info_table::register_table(client_cache);
+        stats_table::register_table(client_cache);
         taskbar_table::register_table(client_cache);
+        taskbar_policy_table::register_table(client_cache);
+        taskbar_policy_report_table::register_table(client_cache);
+        virtual_desktop_table::register_table(client_cache);
+        watch_rule_table::register_table(client_cache);
+        window_move_request_table::register_table(client_cache);
+        window_move_result_table::register_table(client_cache);
+        zone_layout_table::register_table(client_cache);
     }
 }
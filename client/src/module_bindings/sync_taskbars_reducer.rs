@@ -68,6 +68,7 @@ impl sync_taskbars for super::RemoteReducers {
         SyncTaskbarsCallbackId(self.imp.on_reducer(
             "sync_taskbars",
             Box::new(move |ctx: &super::ReducerEventContext| {
+                #[allow(irrefutable_let_patterns)]
                 let super::ReducerEventContext {
                     event:
                         __sdk::ReducerEvent {
@@ -2,6 +2,9 @@
 // WILL NOT BE SAVED. MODIFY TABLES IN YOUR MODULE SOURCE CODE INSTEAD.
 
 #![allow(unused, clippy::all)]
+use super::taskbar_alignment_type::TaskbarAlignment;
+use super::taskbar_combine_buttons_type::TaskbarCombineButtons;
+use super::taskbar_edge_type::TaskbarEdge;
 use super::taskbar_type::Taskbar;
 use spacetimedb_sdk::__codegen::__lib;
 use spacetimedb_sdk::__codegen::__sats;
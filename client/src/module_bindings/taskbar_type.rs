@@ -7,16 +7,27 @@ use spacetimedb_sdk::__codegen::__sats;
 use spacetimedb_sdk::__codegen::__ws;
 use spacetimedb_sdk::__codegen::{self as __sdk};
 
+use super::taskbar_alignment_type::TaskbarAlignment;
+use super::taskbar_combine_buttons_type::TaskbarCombineButtons;
+use super::taskbar_edge_type::TaskbarEdge;
+
 #[derive(__lib::ser::Serialize, __lib::de::Deserialize, Clone, PartialEq, Debug)]
 #[sats(crate = __lib)]
 pub struct Taskbar {
     pub id: u32,
+    pub machine: String,
+    pub index: u32,
     pub is_secondary: bool,
     pub width: u32,
     pub height: u32,
     pub x: i32,
     pub y: i32,
     pub apps: Vec<String>,
+    pub auto_hide: bool,
+    pub edge: TaskbarEdge,
+    pub alignment: TaskbarAlignment,
+    pub locked: bool,
+    pub combine_buttons: TaskbarCombineButtons,
 }
 
 impl __sdk::InModule for Taskbar {
@@ -0,0 +1,628 @@
+use crate::module_bindings::PlacementRegion;
+use crate::module_bindings::PlacementRule;
+use crate::module_bindings::WindowShowState;
+use crate::taskbar_snapshot::Rect;
+use crate::taskbar_snapshot::TaskbarSnapshot;
+use crate::window_move::WindowMover;
+use crate::window_move::WindowPlacement;
+use crate::window_move::WindowState;
+use crate::window_source::WindowInfo;
+use crate::window_source::WindowSource;
+use eyre::Context;
+use eyre::Result;
+use globset::GlobBuilder;
+use globset::GlobMatcher;
+use regex::Regex;
+use serde::Deserialize;
+use serde::Serialize;
+use std::collections::HashSet;
+use std::path::Path;
+
+/// Part of a work area, as fractions of its width and height.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Region {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+/// A `placement_rule` row, or one entry of a rules file given to `place`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RuleSpec {
+    #[serde(default)]
+    pub id: u32,
+    pub name: String,
+    #[serde(default)]
+    pub machine: Option<String>,
+    #[serde(default)]
+    pub path_glob: Option<String>,
+    #[serde(default)]
+    pub process: Option<String>,
+    #[serde(default)]
+    pub title_regex: Option<String>,
+    #[serde(default)]
+    pub target_taskbar_index: u32,
+    #[serde(default)]
+    pub region: Option<Region>,
+    #[serde(default)]
+    pub state: Option<WindowState>,
+    #[serde(default)]
+    pub priority: i32,
+    #[serde(default = "enabled_by_default")]
+    pub enabled: bool,
+}
+
+fn enabled_by_default() -> bool {
+    true
+}
+
+impl RuleSpec {
+    pub fn load(path: &Path) -> Result<Vec<RuleSpec>> {
+        let json = std::fs::read_to_string(path)
+            .wrap_err_with(|| format!("Failed to read {}", path.display()))?;
+        serde_json::from_str(&json)
+            .wrap_err_with(|| format!("Invalid placement rules file {}", path.display()))
+    }
+}
+
+impl From<PlacementRegion> for Region {
+    fn from(value: PlacementRegion) -> Self {
+        Region {
+            x: value.x,
+            y: value.y,
+            width: value.width,
+            height: value.height,
+        }
+    }
+}
+
+impl From<WindowShowState> for WindowState {
+    fn from(value: WindowShowState) -> Self {
+        match value {
+            WindowShowState::Normal => WindowState::Normal,
+            WindowShowState::Minimized => WindowState::Minimized,
+            WindowShowState::Maximized => WindowState::Maximized,
+        }
+    }
+}
+
+impl From<PlacementRule> for RuleSpec {
+    fn from(value: PlacementRule) -> Self {
+        RuleSpec {
+            id: value.id,
+            name: value.name,
+            machine: value.machine,
+            path_glob: value.path_glob,
+            process: value.process,
+            title_regex: value.title_regex,
+            target_taskbar_index: value.target_taskbar_index,
+            region: value.region.map(|x| x.into()),
+            state: value.state.map(|x| x.into()),
+            priority: value.priority,
+            enabled: value.enabled,
+        }
+    }
+}
+
+/// A rule with its glob and regex compiled, ready to match windows against.
+pub struct CompiledRule {
+    pub spec: RuleSpec,
+    path_glob: Option<GlobMatcher>,
+    title_regex: Option<Regex>,
+}
+
+impl CompiledRule {
+    fn compile(spec: RuleSpec) -> Result<Self> {
+        let path_glob = spec
+            .path_glob
+            .as_deref()
+            .map(|glob| {
+                GlobBuilder::new(&normalize_path(glob))
+                    .case_insensitive(true)
+                    .literal_separator(true)
+                    .build()
+                    .map(|x| x.compile_matcher())
+            })
+            .transpose()?;
+        let title_regex = spec.title_regex.as_deref().map(Regex::new).transpose()?;
+        Ok(CompiledRule {
+            spec,
+            path_glob,
+            title_regex,
+        })
+    }
+
+    /// Whether every condition the rule sets holds for `window`.
+    fn matches(&self, window: &WindowInfo) -> bool {
+        let path = match (&self.path_glob, &window.path) {
+            (None, _) => true,
+            (Some(glob), Some(path)) => glob.is_match(normalize_path(path)),
+            (Some(_), None) => false,
+        };
+        let process = match (&self.spec.process, &window.process) {
+            (None, _) => true,
+            (Some(expected), Some(actual)) => expected.eq_ignore_ascii_case(actual),
+            (Some(_), None) => false,
+        };
+        let title = self
+            .title_regex
+            .as_ref()
+            .is_none_or(|x| x.is_match(&window.title));
+        path && process && title
+    }
+}
+
+/// Windows paths use backslashes, which globs treat as escapes.
+fn normalize_path(path: &str) -> String {
+    path.replace('\\', "/")
+}
+
+/// The enabled rules that apply to one machine, highest priority first.
+pub struct RuleSet {
+    rules: Vec<CompiledRule>,
+}
+
+impl RuleSet {
+    /// Compiles the rules for `machine`, skipping with a warning any whose glob or regex is invalid.
+    pub fn compile(specs: impl IntoIterator<Item = RuleSpec>, machine: &str) -> RuleSet {
+        let mut rules = specs
+            .into_iter()
+            .filter(|x| x.enabled)
+            .filter(|x| x.machine.as_deref().is_none_or(|x| x == machine))
+            .filter_map(|spec| {
+                let name = spec.name.clone();
+                CompiledRule::compile(spec)
+                    .inspect_err(|e| tracing::warn!("Skipping placement rule {name}: {e}"))
+                    .ok()
+            })
+            .collect::<Vec<_>>();
+        rules.sort_by_key(|x| (std::cmp::Reverse(x.spec.priority), x.spec.id));
+        RuleSet { rules }
+    }
+
+    pub fn find(&self, window: &WindowInfo) -> Option<&CompiledRule> {
+        self.rules.iter().find(|x| x.matches(window))
+    }
+}
+
+/// Where `window` should go inside `work_area`, centered at its current size when there is no region.
+pub fn placement_in(work_area: Rect, region: Option<Region>, window: Rect) -> Rect {
+    let (width, height, left, top) = match region {
+        Some(region) => {
+            let width = work_area.width() as f32;
+            let height = work_area.height() as f32;
+            (
+                (region.width * width).round() as i32,
+                (region.height * height).round() as i32,
+                work_area.left + (region.x * width).round() as i32,
+                work_area.top + (region.y * height).round() as i32,
+            )
+        }
+        None => {
+            let width = window.width().min(work_area.width()) as i32;
+            let height = window.height().min(work_area.height()) as i32;
+            (
+                width,
+                height,
+                work_area.left + (work_area.width() as i32 - width) / 2,
+                work_area.top + (work_area.height() as i32 - height) / 2,
+            )
+        }
+    };
+    Rect {
+        left,
+        top,
+        right: left + width,
+        bottom: top + height,
+    }
+}
+
+/// Applies the placement rules to windows as they open.
+pub struct PlacementEngine {
+    source: Box<dyn WindowSource>,
+    mover: Box<dyn WindowMover>,
+    dry_run: bool,
+    /// Windows already looked at, `None` until the first cycle
+    seen: Option<HashSet<u64>>,
+}
+
+impl PlacementEngine {
+    pub fn new(source: Box<dyn WindowSource>, mover: Box<dyn WindowMover>, dry_run: bool) -> Self {
+        PlacementEngine {
+            source,
+            mover,
+            dry_run,
+            seen: None,
+        }
+    }
+
+    /// Places the windows that opened since the last cycle, leaving the ones open at startup alone.
+    ///
+    /// Returns the placements made, or that would have been made in a dry run.
+    pub fn run(
+        &mut self,
+        rules: &RuleSet,
+        taskbars: &[TaskbarSnapshot],
+    ) -> Result<Vec<(u64, WindowPlacement)>> {
+        let windows = self.source.windows()?;
+        let current = windows.iter().map(|x| x.window_id).collect::<HashSet<_>>();
+        let Some(seen) = self.seen.replace(current) else {
+            return Ok(Vec::new());
+        };
+        let mut placed = Vec::new();
+        for window in windows.iter().filter(|x| !seen.contains(&x.window_id)) {
+            let Some(rule) = rules.find(window) else {
+                continue;
+            };
+            match self.place(window, &rule.spec, taskbars) {
+                Ok(Some(placement)) => placed.push((window.window_id, placement)),
+                Ok(None) => {}
+                Err(e) => tracing::warn!(
+                    "Failed to place window {:#x} with rule {}: {e}",
+                    window.window_id,
+                    rule.spec.name
+                ),
+            }
+        }
+        Ok(placed)
+    }
+
+    /// Moves `window` where `rule` puts it, `None` if it is already there.
+    fn place(
+        &mut self,
+        window: &WindowInfo,
+        rule: &RuleSpec,
+        taskbars: &[TaskbarSnapshot],
+    ) -> Result<Option<WindowPlacement>> {
+        let target = taskbars
+            .iter()
+            .find(|x| x.index == rule.target_taskbar_index)
+            .ok_or_else(|| eyre::eyre!("No taskbar with index {}", rule.target_taskbar_index))?;
+        let current = self.mover.placement(window.window_id)?;
+        let work_area = self.mover.work_area(target.rect)?;
+        let placement = WindowPlacement {
+            rect: placement_in(work_area, rule.region, current.rect),
+            state: rule.state.unwrap_or(current.state),
+        };
+        if placement == current {
+            tracing::debug!(
+                "Window {:#x} ({}) is already where rule {} puts it",
+                window.window_id,
+                window.title,
+                rule.name
+            );
+            return Ok(None);
+        }
+        if self.dry_run {
+            tracing::info!(
+                "Would place window {:#x} ({}) at {:?} ({:?}) by rule {}",
+                window.window_id,
+                window.title,
+                placement.rect,
+                placement.state,
+                rule.name
+            );
+            return Ok(Some(placement));
+        }
+        tracing::info!(
+            "Placing window {:#x} ({}) at {:?} ({:?}) by rule {}",
+            window.window_id,
+            window.title,
+            placement.rect,
+            placement.state,
+            rule.name
+        );
+        self.mover.set_placement(window.window_id, placement)?;
+        Ok(Some(placement))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::window_move::Monitor;
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+    use std::collections::VecDeque;
+    use std::rc::Rc;
+
+    type Moves = Rc<RefCell<Vec<(u64, WindowPlacement)>>>;
+
+    /// Lists a scripted set of windows per cycle, repeating the last one.
+    struct ScriptedWindows(VecDeque<Vec<WindowInfo>>);
+
+    impl WindowSource for ScriptedWindows {
+        fn windows(&mut self) -> Result<Vec<WindowInfo>> {
+            if self.0.len() > 1 {
+                return Ok(self.0.pop_front().unwrap_or_default());
+            }
+            Ok(self.0.front().cloned().unwrap_or_default())
+        }
+    }
+
+    /// Knows where each window is, treats the area above a taskbar as its work area and
+    /// records the placements it is asked to make.
+    struct FixtureMover {
+        placements: HashMap<u64, WindowPlacement>,
+        moves: Moves,
+    }
+
+    impl WindowMover for FixtureMover {
+        fn placement(&self, window_id: u64) -> Result<WindowPlacement> {
+            self.placements
+                .get(&window_id)
+                .copied()
+                .ok_or_else(|| eyre::eyre!("No window {window_id:#x}"))
+        }
+
+        fn monitor(&self, rect: Rect) -> Result<Monitor> {
+            Ok(Monitor { rect, dpi: 96 })
+        }
+
+        fn work_area(&self, rect: Rect) -> Result<Rect> {
+            Ok(Rect {
+                top: 0,
+                bottom: rect.top,
+                ..rect
+            })
+        }
+
+        fn set_placement(&mut self, window_id: u64, placement: WindowPlacement) -> Result<()> {
+            self.moves.borrow_mut().push((window_id, placement));
+            Ok(())
+        }
+    }
+
+    fn rect(left: i32, top: i32, right: i32, bottom: i32) -> Rect {
+        Rect {
+            left,
+            top,
+            right,
+            bottom,
+        }
+    }
+
+    fn window(window_id: u64, title: &str) -> WindowInfo {
+        WindowInfo {
+            window_id,
+            title: title.to_string(),
+            process: Some("explorer.exe".to_string()),
+            path: None,
+        }
+    }
+
+    fn rule(id: u32, name: &str, title_regex: &str, priority: i32, taskbar: u32) -> RuleSpec {
+        RuleSpec {
+            id,
+            name: name.to_string(),
+            machine: None,
+            path_glob: None,
+            process: None,
+            title_regex: Some(title_regex.to_string()),
+            target_taskbar_index: taskbar,
+            region: Some(Region {
+                x: 0.0,
+                y: 0.0,
+                width: 0.5,
+                height: 1.0,
+            }),
+            state: None,
+            priority,
+            enabled: true,
+        }
+    }
+
+    /// Two 1000x1000 monitors side by side with 40 pixel taskbars along the bottom.
+    fn taskbars() -> Vec<TaskbarSnapshot> {
+        [rect(0, 960, 1000, 1000), rect(1000, 960, 2000, 1000)]
+            .into_iter()
+            .enumerate()
+            .map(|(index, rect)| TaskbarSnapshot {
+                machine: "box".to_string(),
+                index: index as u32,
+                rect,
+                is_secondary: index > 0,
+                apps: Vec::new(),
+                settings: Default::default(),
+            })
+            .collect()
+    }
+
+    fn normal(rect: Rect) -> WindowPlacement {
+        WindowPlacement {
+            rect,
+            state: WindowState::Normal,
+        }
+    }
+
+    /// An engine that sees `at_start` on its first cycle and `later` from then on.
+    fn engine(
+        at_start: Vec<WindowInfo>,
+        later: Vec<WindowInfo>,
+        placements: &[(u64, WindowPlacement)],
+        dry_run: bool,
+    ) -> (PlacementEngine, Moves) {
+        let moves = Moves::default();
+        let mover = FixtureMover {
+            placements: placements.iter().copied().collect(),
+            moves: moves.clone(),
+        };
+        let source = ScriptedWindows(VecDeque::from([at_start, later]));
+        (
+            PlacementEngine::new(Box::new(source), Box::new(mover), dry_run),
+            moves,
+        )
+    }
+
+    fn rules() -> RuleSet {
+        RuleSet::compile(
+            [
+                rule(1, "any window", ".*", 0, 0),
+                rule(2, "reports", "Report", 10, 1),
+                rule(3, "reports too", "Report", 10, 0),
+            ],
+            "box",
+        )
+    }
+
+    /// The rule `place` picks for each window of a desk with a few typical ones open.
+    fn matches(machine: &str) -> Vec<(u64, Option<(String, u32)>)> {
+        let rules = RuleSet::compile(
+            RuleSpec::load(Path::new(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/tests/fixtures/placement/rules.json"
+            )))
+            .unwrap(),
+            machine,
+        );
+        let windows = [
+            (
+                0x1000,
+                "alpha",
+                "Explorer.EXE",
+                Some(r"\\NAS\Projects\alpha"),
+            ),
+            (
+                0x1001,
+                "Downloads",
+                "explorer.exe",
+                Some(r"C:\Users\me\Downloads"),
+            ),
+            (0x1002, "Invoice 42.pdf", "acrobat.exe", None),
+            (0x1003, "notes.txt - Notepad", "notepad.exe", None),
+        ];
+        windows
+            .into_iter()
+            .map(|(window_id, title, process, path)| {
+                let window = WindowInfo {
+                    window_id,
+                    title: title.to_string(),
+                    process: Some(process.to_string()),
+                    path: path.map(String::from),
+                };
+                let rule = rules
+                    .find(&window)
+                    .map(|x| (x.spec.name.clone(), x.spec.target_taskbar_index));
+                (window_id, rule)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn matches_rules_by_priority() {
+        assert_eq!(
+            matches("home-pc"),
+            [
+                (
+                    0x1000,
+                    Some(("projects on the right monitor".to_string(), 1))
+                ),
+                (0x1001, Some(("any explorer window".to_string(), 0))),
+                (0x1002, None),
+                (0x1003, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn applies_machine_specific_rules() {
+        assert_eq!(
+            matches("office-pc")[2],
+            (
+                0x1002,
+                Some(("invoices, only on the office pc".to_string(), 1))
+            )
+        );
+    }
+
+    #[test]
+    fn places_new_windows_by_the_highest_priority_rule() {
+        let somewhere = normal(rect(100, 100, 500, 400));
+        let (mut engine, moves) = engine(
+            vec![window(1, "Old report")],
+            vec![
+                window(1, "Old report"),
+                window(2, "Report 42"),
+                window(3, "Downloads"),
+            ],
+            &[(1, somewhere), (2, somewhere), (3, somewhere)],
+            false,
+        );
+        assert!(engine.run(&rules(), &taskbars()).unwrap().is_empty());
+        let placed = engine.run(&rules(), &taskbars()).unwrap();
+        // Equal priorities go to the lower id, and the window open at startup is left alone
+        let expected = vec![
+            (2, normal(rect(1000, 0, 1500, 960))),
+            (3, normal(rect(0, 0, 500, 960))),
+        ];
+        assert_eq!(placed, expected);
+        assert_eq!(*moves.borrow(), expected);
+        assert!(engine.run(&rules(), &taskbars()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn skips_windows_already_in_place() {
+        let (mut engine, moves) = engine(
+            Vec::new(),
+            vec![window(1, "Downloads"), window(2, "Documents")],
+            &[
+                (1, normal(rect(0, 0, 500, 960))),
+                (
+                    2,
+                    WindowPlacement {
+                        rect: rect(0, 0, 500, 960),
+                        state: WindowState::Minimized,
+                    },
+                ),
+            ],
+            false,
+        );
+        engine.run(&rules(), &taskbars()).unwrap();
+        let placed = engine.run(&rules(), &taskbars()).unwrap();
+        // The rule sets no state, so a minimized window already in its region stays as it is
+        assert!(placed.is_empty());
+        assert!(moves.borrow().is_empty());
+    }
+
+    #[test]
+    fn only_reports_placements_in_a_dry_run() {
+        let (mut engine, moves) = engine(
+            Vec::new(),
+            vec![window(1, "Report 42")],
+            &[(1, normal(rect(100, 100, 500, 400)))],
+            true,
+        );
+        engine.run(&rules(), &taskbars()).unwrap();
+        let placed = engine.run(&rules(), &taskbars()).unwrap();
+        assert_eq!(placed, vec![(1, normal(rect(1000, 0, 1500, 960)))]);
+        assert!(moves.borrow().is_empty());
+    }
+
+    #[test]
+    fn carries_on_past_windows_it_cannot_place() {
+        let rules = RuleSet::compile(
+            [
+                rule(1, "missing taskbar", "Report", 0, 7),
+                rule(2, "any window", ".*", -1, 0),
+            ],
+            "box",
+        );
+        let (mut engine, moves) = engine(
+            Vec::new(),
+            vec![
+                window(1, "Report 42"),
+                window(2, "Gone"),
+                window(3, "Notes"),
+            ],
+            &[
+                (1, normal(rect(100, 100, 500, 400))),
+                (3, normal(rect(100, 100, 500, 400))),
+            ],
+            false,
+        );
+        engine.run(&rules, &taskbars()).unwrap();
+        engine.run(&rules, &taskbars()).unwrap();
+        assert_eq!(*moves.borrow(), vec![(3, normal(rect(0, 0, 500, 960)))]);
+    }
+}
@@ -0,0 +1,218 @@
+use crate::recording::RecordedSnapshot;
+use chrono::DateTime;
+use chrono::Days;
+use chrono::NaiveDate;
+use chrono::Utc;
+use clap::ValueEnum;
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::time::Duration;
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ReportFormat {
+    #[default]
+    Markdown,
+    Html,
+    Csv,
+}
+
+/// How long an app had a taskbar button on one machine on one (UTC) day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Presence {
+    pub machine: String,
+    pub day: NaiveDate,
+    pub app: String,
+    pub present: Duration,
+}
+
+/// Credits `length` starting at `from` to `app`, splitting it at midnight.
+fn credit(
+    totals: &mut BTreeMap<(NaiveDate, String), Duration>,
+    app: &str,
+    from: DateTime<Utc>,
+    length: Duration,
+) {
+    let mut from = from;
+    let mut left = length;
+    while !left.is_zero() {
+        let day = from.date_naive();
+        let midnight = day
+            .checked_add_days(Days::new(1))
+            .and_then(|x| x.and_hms_opt(0, 0, 0))
+            .map(|x| x.and_utc())
+            .unwrap_or(from);
+        let today = (midnight - from).to_std().unwrap_or_default().min(left);
+        *totals.entry((day, app.to_string())).or_default() += today;
+        left -= today;
+        from = midnight;
+    }
+}
+
+/// Adds up how long each app was on a taskbar, per machine and day.
+///
+/// Each sample counts until the next one from the same machine, but for at most `max_gap`, so
+/// time the client was not running is not credited to whatever was open when it stopped.
+pub fn aggregate(snapshots: &[RecordedSnapshot], max_gap: Duration) -> Vec<Presence> {
+    let mut samples = BTreeMap::<String, Vec<(DateTime<Utc>, BTreeSet<&str>)>>::new();
+    for snapshot in snapshots {
+        let mut apps = BTreeMap::<&str, BTreeSet<&str>>::new();
+        for taskbar in &snapshot.taskbars {
+            apps.entry(taskbar.machine.as_str())
+                .or_default()
+                .extend(taskbar.apps.iter().map(|x| x.as_str()));
+        }
+        for (machine, apps) in apps {
+            samples
+                .entry(machine.to_string())
+                .or_default()
+                .push((snapshot.at, apps));
+        }
+    }
+
+    let mut presence = Vec::new();
+    for (machine, mut samples) in samples {
+        samples.sort_by_key(|(at, _)| *at);
+        let mut totals = BTreeMap::new();
+        for pair in samples.windows(2) {
+            let [(from, apps), (to, _)] = pair else {
+                continue;
+            };
+            let length = (*to - *from).to_std().unwrap_or_default().min(max_gap);
+            for app in apps {
+                credit(&mut totals, app, *from, length);
+            }
+        }
+        let mut rows = totals
+            .into_iter()
+            .map(|((day, app), present)| Presence {
+                machine: machine.clone(),
+                day,
+                app,
+                present,
+            })
+            .collect::<Vec<_>>();
+        rows.sort_by(|a, b| {
+            (a.day, std::cmp::Reverse(a.present), &a.app).cmp(&(
+                b.day,
+                std::cmp::Reverse(b.present),
+                &b.app,
+            ))
+        });
+        presence.extend(rows);
+    }
+    presence
+}
+
+fn format_present(present: Duration) -> String {
+    let secs = present.as_secs();
+    format!(
+        "{}h {:02}m {:02}s",
+        secs / 3600,
+        secs % 3600 / 60,
+        secs % 60
+    )
+}
+
+/// The rows of one machine, by day.
+type MachineDays<'a> = (&'a str, Vec<(NaiveDate, Vec<&'a Presence>)>);
+
+/// Rows grouped by machine, then by day, in the order they come in.
+fn grouped(rows: &[Presence]) -> Vec<MachineDays<'_>> {
+    let mut machines: Vec<MachineDays> = Vec::new();
+    for row in rows {
+        if machines.last().is_none_or(|(x, _)| *x != row.machine) {
+            machines.push((&row.machine, Vec::new()));
+        }
+        let Some((_, days)) = machines.last_mut() else {
+            continue;
+        };
+        if days.last().is_none_or(|(x, _)| *x != row.day) {
+            days.push((row.day, Vec::new()));
+        }
+        if let Some((_, day)) = days.last_mut() {
+            day.push(row);
+        }
+    }
+    machines
+}
+
+fn markdown(rows: &[Presence]) -> String {
+    let mut out = String::from("# Taskbar presence\n");
+    for (machine, days) in grouped(rows) {
+        let _ = write!(out, "\n## {machine}\n");
+        for (day, rows) in days {
+            let _ = write!(out, "\n### {day}\n\n| App | Present |\n| --- | ---: |\n");
+            for row in rows {
+                // Pipes would end the cell early
+                let app = row.app.replace('|', "\\|");
+                let _ = writeln!(out, "| {app} | {} |", format_present(row.present));
+            }
+        }
+    }
+    out
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn html(rows: &[Presence]) -> String {
+    let mut out = String::from(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>Taskbar presence</title>\n</head>\n<body>\n<h1>Taskbar presence</h1>\n",
+    );
+    for (machine, days) in grouped(rows) {
+        let _ = writeln!(out, "<h2>{}</h2>", escape_html(machine));
+        for (day, rows) in days {
+            let _ = writeln!(
+                out,
+                "<h3>{day}</h3>\n<table>\n<tr><th>App</th><th>Present</th></tr>"
+            );
+            for row in rows {
+                let _ = writeln!(
+                    out,
+                    "<tr><td>{}</td><td>{}</td></tr>",
+                    escape_html(&row.app),
+                    format_present(row.present)
+                );
+            }
+            out.push_str("</table>\n");
+        }
+    }
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+fn csv(rows: &[Presence]) -> String {
+    let mut out = String::from("machine,day,app,seconds\n");
+    for row in rows {
+        let _ = writeln!(
+            out,
+            "{},{},{},{}",
+            csv_field(&row.machine),
+            row.day,
+            csv_field(&row.app),
+            row.present.as_secs()
+        );
+    }
+    out
+}
+
+pub fn render(rows: &[Presence], format: ReportFormat) -> String {
+    match format {
+        ReportFormat::Markdown => markdown(rows),
+        ReportFormat::Html => html(rows),
+        ReportFormat::Csv => csv(rows),
+    }
+}
@@ -0,0 +1,67 @@
+use crate::taskbar_snapshot::TaskbarSnapshot;
+use chrono::DateTime;
+use chrono::Utc;
+use eyre::Context;
+use eyre::Result;
+use serde::Deserialize;
+use serde::Serialize;
+use std::fs::File;
+use std::fs::OpenOptions;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::BufWriter;
+use std::io::Write;
+use std::path::Path;
+
+/// One line of a recording file.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RecordedSnapshot {
+    pub at: DateTime<Utc>,
+    pub taskbars: Vec<TaskbarSnapshot>,
+}
+
+/// Appends snapshots to a JSON Lines file as they are collected.
+pub struct Recorder {
+    writer: BufWriter<File>,
+}
+
+impl Recorder {
+    pub fn create(path: &Path) -> Result<Self> {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .wrap_err_with(|| format!("Failed to open recording {}", path.display()))?;
+        Ok(Recorder {
+            writer: BufWriter::new(file),
+        })
+    }
+
+    pub fn record(&mut self, taskbars: &[TaskbarSnapshot]) -> Result<()> {
+        let snapshot = RecordedSnapshot {
+            at: Utc::now(),
+            taskbars: taskbars.to_vec(),
+        };
+        serde_json::to_writer(&mut self.writer, &snapshot)?;
+        writeln!(self.writer)?;
+        self.writer.flush()?;
+        Ok(())
+    }
+}
+
+pub fn read_recording(path: &Path) -> Result<Vec<RecordedSnapshot>> {
+    let file = File::open(path)
+        .wrap_err_with(|| format!("Failed to open recording {}", path.display()))?;
+    let mut snapshots = Vec::new();
+    for (i, line) in BufReader::new(file).lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let snapshot = serde_json::from_str(&line).wrap_err_with(|| {
+            format!("Invalid snapshot on line {} of {}", i + 1, path.display())
+        })?;
+        snapshots.push(snapshot);
+    }
+    Ok(snapshots)
+}
@@ -0,0 +1,138 @@
+use crate::module_bindings::DbConnection;
+use crate::module_bindings::ErrorContext;
+use crate::module_bindings::SchemaInfo;
+use crate::module_bindings::SchemaInfoTableAccess;
+use crate::module_bindings::SubscriptionEventContext;
+use eyre::Result;
+use spacetimedb_sdk::DbContext;
+use std::time::Duration;
+
+/// Must match the server's `SCHEMA_VERSION` that `module_bindings` were generated from, which a
+/// test checks.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// How long to wait for the server's `schema_info` before carrying on without it.
+const CHECK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Refuses servers whose schema these bindings do not fit, and warns about those it cannot tell.
+pub fn check_schema(connection: &DbConnection) -> Result<()> {
+    let (applied_tx, applied_rx) = std::sync::mpsc::channel();
+    let failed_tx = applied_tx.clone();
+    connection
+        .subscription_builder()
+        .on_applied(move |ctx: &SubscriptionEventContext| {
+            let _ = applied_tx.send(Ok(ctx.db.schema_info().id().find(&0)));
+        })
+        .on_error(move |_: &ErrorContext, err| {
+            let _ = failed_tx.send(Err(err));
+        })
+        .subscribe(["SELECT * FROM schema_info"]);
+    match applied_rx.recv_timeout(CHECK_TIMEOUT) {
+        Ok(Ok(Some(info))) => compatible(SCHEMA_VERSION, &info),
+        Ok(Ok(None)) => {
+            tracing::warn!(
+                "The server has not recorded its schema version, call `migrate_schema` after publishing"
+            );
+            Ok(())
+        }
+        Ok(Err(error)) => {
+            tracing::warn!(
+                %error,
+                "The server predates schema versioning, republish it and call `migrate_schema`"
+            );
+            Ok(())
+        }
+        Err(_) => {
+            tracing::warn!("The server did not send its schema version within {CHECK_TIMEOUT:?}");
+            Ok(())
+        }
+    }
+}
+
+/// Whether a client built for schema `client` can work with the server `info` describes.
+fn compatible(client: u32, info: &SchemaInfo) -> Result<()> {
+    if client < info.min_client_version {
+        eyre::bail!(
+            "The server is at schema {} and needs clients built for schema {} or newer, \
+             this one is built for {client}",
+            info.version,
+            info.min_client_version
+        );
+    }
+    if client > info.version {
+        eyre::bail!(
+            "This client is built for schema {client} but the server is at {}, \
+             publish the newer module and call `migrate_schema`",
+            info.version
+        );
+    }
+    if client < info.version {
+        tracing::warn!(
+            "The server is at schema {}, ahead of this client's {client}, \
+             update the client to use what changed",
+            info.version
+        );
+    }
+    tracing::info!(
+        version = info.version,
+        module_version = %info.module_version,
+        "Server schema is compatible"
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use spacetimedb_sdk::Timestamp;
+
+    fn server(version: u32, min_client_version: u32) -> SchemaInfo {
+        SchemaInfo {
+            id: 0,
+            version,
+            min_client_version,
+            module_version: "0.1.0".to_string(),
+            migrated_at: Timestamp::UNIX_EPOCH,
+        }
+    }
+
+    #[test]
+    fn matches_the_schema_the_bindings_were_generated_from() {
+        // `Generate-Bindings.ps1` generates `module_bindings` from this module
+        let path =
+            std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../server/src/schema_info.rs");
+        let server = std::fs::read_to_string(path).unwrap();
+        let version = server
+            .lines()
+            .find_map(|x| x.strip_prefix("pub const SCHEMA_VERSION: u32 = "))
+            .and_then(|x| x.strip_suffix(';'))
+            .expect("The server declares its SCHEMA_VERSION");
+        assert_eq!(version.parse(), Ok(SCHEMA_VERSION));
+    }
+
+    #[test]
+    fn accepts_servers_at_the_same_schema() {
+        assert!(compatible(2, &server(2, 1)).is_ok());
+        assert!(compatible(2, &server(2, 2)).is_ok());
+    }
+
+    #[test]
+    fn accepts_servers_ahead_that_still_support_the_client() {
+        assert!(compatible(2, &server(3, 2)).is_ok());
+    }
+
+    #[test]
+    fn refuses_servers_that_need_newer_clients() {
+        let error = compatible(2, &server(4, 3)).unwrap_err().to_string();
+        assert!(
+            error.contains("needs clients built for schema 3 or newer"),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn refuses_servers_behind_the_client() {
+        let error = compatible(3, &server(2, 1)).unwrap_err().to_string();
+        assert!(error.contains("call `migrate_schema`"), "{error}");
+    }
+}
@@ -0,0 +1,53 @@
+#[cfg(unix)]
+use crate::shutdown::handle_signals;
+use crate::shutdown::Ending;
+use crate::shutdown::Shutdown;
+use eyre::Result;
+
+/// Name the client is registered under with the service manager.
+pub const SERVICE_NAME: &str = "explorer-wrangler";
+
+/// What the service runs once the service manager has started it.
+pub type ServiceBody = Box<dyn FnOnce(Shutdown) -> Result<Ending> + Send>;
+
+/// Registers and controls the client with this platform's service manager or task scheduler.
+pub trait ServiceController {
+    /// Registers `service run` with `args` to start with the user's session.
+    fn install(&self, args: &[String]) -> Result<()>;
+    /// Describes what `install` would register, without registering it.
+    fn describe(&self, args: &[String]) -> Result<String>;
+    fn uninstall(&self) -> Result<()>;
+    fn start(&self) -> Result<()>;
+    fn stop(&self) -> Result<()>;
+}
+
+#[cfg(windows)]
+pub fn service_controller() -> Result<Box<dyn ServiceController>> {
+    Ok(Box::new(crate::windows_logon_task::WindowsTaskController))
+}
+
+#[cfg(target_os = "linux")]
+pub fn service_controller() -> Result<Box<dyn ServiceController>> {
+    Ok(Box::new(
+        crate::systemd_service::SystemdServiceController::new()?,
+    ))
+}
+
+#[cfg(not(any(windows, target_os = "linux")))]
+pub fn service_controller() -> Result<Box<dyn ServiceController>> {
+    eyre::bail!("Running as a service is only supported on Windows and on Linux with systemd")
+}
+
+/// Runs `body`, stopping it when `service stop` is run in the same session.
+#[cfg(windows)]
+pub fn run_service(body: ServiceBody) -> Result<Ending> {
+    crate::windows_logon_task::run(body)
+}
+
+/// Runs `body`, stopping it when systemd sends `SIGTERM`.
+#[cfg(unix)]
+pub fn run_service(body: ServiceBody) -> Result<Ending> {
+    let shutdown = Shutdown::default();
+    handle_signals(&shutdown)?;
+    body(shutdown)
+}
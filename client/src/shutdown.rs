@@ -0,0 +1,85 @@
+use eyre::Result;
+#[cfg(unix)]
+use signal_hook::consts::SIGINT;
+#[cfg(unix)]
+use signal_hook::consts::SIGTERM;
+#[cfg(unix)]
+use signal_hook::iterator::Signals;
+use std::sync::Arc;
+use std::sync::Condvar;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Asks the sync loop to stop, waking it from the wait between collections.
+#[derive(Debug, Clone, Default)]
+pub struct Shutdown {
+    requested: Arc<(Mutex<bool>, Condvar)>,
+}
+
+impl Shutdown {
+    pub fn request(&self) {
+        let (requested, changed) = &*self.requested;
+        if let Ok(mut requested) = requested.lock() {
+            *requested = true;
+        }
+        changed.notify_all();
+    }
+
+    pub fn is_requested(&self) -> bool {
+        self.requested.0.lock().map_or(true, |x| *x)
+    }
+
+    /// Sleeps for `timeout` or until shutdown is requested, returning whether it was.
+    pub fn wait(&self, timeout: Duration) -> bool {
+        let (requested, changed) = &*self.requested;
+        let Ok(guard) = requested.lock() else {
+            return true;
+        };
+        changed
+            .wait_timeout_while(guard, timeout, |requested| !*requested)
+            .map_or(true, |(requested, _)| *requested)
+    }
+}
+
+/// How the sync loop ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ending {
+    /// The taskbar source ran out, like at the end of a replay
+    Exhausted,
+    /// Shutdown was requested and the client left the server cleanly
+    Stopped,
+    /// Shutdown was requested but the server did not confirm the client left, in time or at all
+    Unconfirmed,
+}
+
+impl Ending {
+    pub fn exit_code(self) -> u8 {
+        match self {
+            Ending::Exhausted | Ending::Stopped => 0,
+            Ending::Unconfirmed => 3,
+        }
+    }
+}
+
+/// Requests shutdown on Ctrl+C and `SIGTERM`, exiting at once on the second one.
+#[cfg(unix)]
+pub fn handle_signals(shutdown: &Shutdown) -> Result<()> {
+    let mut signals = Signals::new([SIGINT, SIGTERM])?;
+    let shutdown = shutdown.clone();
+    std::thread::spawn(move || {
+        for signal in signals.forever() {
+            if shutdown.is_requested() {
+                std::process::exit(128 + signal);
+            }
+            tracing::info!(signal, "Shutting down, interrupt again to exit at once");
+            shutdown.request();
+        }
+    });
+    Ok(())
+}
+
+/// Requests shutdown on Ctrl+C, Ctrl+Break, the console window closing and the session ending.
+#[cfg(windows)]
+pub fn handle_signals(shutdown: &Shutdown) -> Result<()> {
+    crate::windows_console::handle_console_events(shutdown)
+}
@@ -0,0 +1,332 @@
+use crate::metrics::Metrics;
+use crate::module_bindings::sync_taskbars;
+use crate::module_bindings::DbConnection;
+use crate::module_bindings::ReducerEventContext;
+use crate::module_bindings::Taskbar;
+use crate::taskbar_snapshot::TaskbarSnapshot;
+use eyre::Result;
+use spacetimedb_sdk::DbContext;
+use spacetimedb_sdk::Status;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Instant;
+use std::time::SystemTime;
+
+/// What the server made of one `sync_taskbars` call.
+trait SyncResult {
+    /// Whether this connection made the call, rather than another client.
+    fn is_own(&self) -> bool;
+    fn status(&self) -> &Status;
+    /// Makes the call again, on the connection that made it.
+    fn resend(&self, taskbars: Vec<Taskbar>) -> Result<()>;
+}
+
+impl SyncResult for ReducerEventContext {
+    fn is_own(&self) -> bool {
+        self.event.caller_connection_id == Some(self.connection_id())
+    }
+
+    fn status(&self) -> &Status {
+        &self.event.status
+    }
+
+    fn resend(&self, taskbars: Vec<Taskbar>) -> Result<()> {
+        self.reducers.sync_taskbars(taskbars)?;
+        Ok(())
+    }
+}
+
+/// A `sync_taskbars` call the server has not answered yet.
+struct PendingSync {
+    sent: Instant,
+    attempt: u32,
+}
+
+#[derive(Default)]
+struct SyncState {
+    /// Oldest first, the server answers a connection's calls in the order they were made
+    pending: VecDeque<PendingSync>,
+    last_success: Option<SystemTime>,
+    last_error: Option<String>,
+}
+
+/// Follows this client's `sync_taskbars` calls through to what the server made of them.
+pub struct SyncTracker {
+    state: Mutex<SyncState>,
+    metrics: Arc<Metrics>,
+    /// The `<uri>/<module>` the calls go to, for telling targets apart in logs and metrics
+    target: String,
+    max_retries: u32,
+}
+
+impl SyncTracker {
+    pub fn new(metrics: Arc<Metrics>, target: String, max_retries: u32) -> Arc<Self> {
+        Arc::new(SyncTracker {
+            state: Mutex::default(),
+            metrics,
+            target,
+            max_retries,
+        })
+    }
+
+    /// Registers for the outcome of this connection's `sync_taskbars` calls.
+    pub fn track(self: &Arc<Self>, connection: &DbConnection) {
+        let tracker = self.clone();
+        connection.reducers.on_sync_taskbars(
+            move |ctx: &ReducerEventContext, taskbars: &Vec<Taskbar>| {
+                tracker.on_result(ctx, taskbars)
+            },
+        );
+    }
+
+    /// When the server last accepted this client's taskbars.
+    pub fn last_success(&self) -> Option<SystemTime> {
+        self.state.lock().ok()?.last_success
+    }
+
+    /// Why the server last rejected this client's taskbars, cleared by the next success.
+    pub fn last_error(&self) -> Option<String> {
+        self.state.lock().ok()?.last_error.clone()
+    }
+
+    /// Drops the calls still waiting for an answer, which a new connection will never give.
+    pub fn forget_pending(&self) {
+        if let Ok(mut state) = self.state.lock() {
+            state.pending.clear();
+        }
+    }
+
+    fn push_pending(&self, attempt: u32) {
+        if let Ok(mut state) = self.state.lock() {
+            state.pending.push_back(PendingSync {
+                sent: Instant::now(),
+                attempt,
+            });
+        }
+    }
+
+    /// Sends `taskbars`, whose outcome arrives through the callback `track` registers.
+    pub fn send(&self, connection: &DbConnection, taskbars: Vec<TaskbarSnapshot>) -> Result<()> {
+        let taskbars = tracing::info_span!("convert", taskbars = taskbars.len()).in_scope(|| {
+            // The server assigns ids, matching rows on machine and index instead
+            taskbars
+                .into_iter()
+                .map(|x| x.into_row(0))
+                .collect::<Vec<_>>()
+        });
+        let _span =
+            tracing::info_span!("call_reducer", reducer = "sync_taskbars", database = %self.target)
+                .entered();
+        if let Err(e) = connection.reducers.sync_taskbars(taskbars) {
+            self.metrics
+                .reducer_failures
+                .with_label_values(&[&self.target, "sync_taskbars"])
+                .inc();
+            return Err(e.into());
+        }
+        self.push_pending(0);
+        tracing::debug!("Called sync_taskbars");
+        Ok(())
+    }
+
+    fn on_result(&self, result: &dyn SyncResult, taskbars: &[Taskbar]) {
+        if !result.is_own() {
+            return;
+        }
+        let machine = taskbars.first().map(|x| x.machine.as_str());
+        let _span = tracing::info_span!(
+            "callback",
+            reducer = "sync_taskbars",
+            database = %self.target,
+            machine
+        )
+        .entered();
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+        let call = state.pending.pop_front();
+        let attempt = call.as_ref().map_or(0, |x| x.attempt);
+        let round_trip_ms = call.map(|x| {
+            let lag = x.sent.elapsed();
+            self.metrics
+                .subscription_lag
+                .with_label_values(&[&self.target])
+                .set(lag.as_secs_f64());
+            lag.as_secs_f64() * 1000.0
+        });
+        match result.status() {
+            Status::Committed => {
+                let now = SystemTime::now();
+                state.last_success = Some(now);
+                state.last_error = None;
+                self.metrics.observe_sync_success(&self.target, now);
+                tracing::info!(
+                    status = "committed",
+                    taskbars = taskbars.len(),
+                    round_trip_ms,
+                    "Synced taskbars"
+                );
+            }
+            Status::Failed(error) => {
+                // The same taskbars would be rejected again, so this is not retried
+                state.last_error = Some(error.to_string());
+                self.metrics
+                    .reducer_failures
+                    .with_label_values(&[&self.target, "sync_taskbars"])
+                    .inc();
+                tracing::error!(
+                    status = "failed",
+                    taskbars = taskbars.len(),
+                    round_trip_ms,
+                    %error,
+                    "Server rejected the taskbars"
+                );
+            }
+            Status::OutOfEnergy => {
+                state.last_error = Some("Out of energy".into());
+                self.metrics
+                    .reducer_failures
+                    .with_label_values(&[&self.target, "sync_taskbars"])
+                    .inc();
+                // A newer snapshot on its way supersedes this one
+                let retry = attempt < self.max_retries && state.pending.is_empty();
+                tracing::warn!(
+                    status = "out_of_energy",
+                    taskbars = taskbars.len(),
+                    round_trip_ms,
+                    attempt,
+                    retry,
+                    "Server ran out of energy syncing the taskbars"
+                );
+                if retry {
+                    match result.resend(taskbars.to_vec()) {
+                        Ok(()) => state.pending.push_back(PendingSync {
+                            sent: Instant::now(),
+                            attempt: attempt + 1,
+                        }),
+                        Err(e) => tracing::warn!("Failed to retry sync_taskbars: {e}"),
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    /// An answer to a `sync_taskbars` call, recording the calls made again.
+    struct FakeResult {
+        own: bool,
+        status: Status,
+        resent: RefCell<usize>,
+    }
+
+    impl FakeResult {
+        fn own(status: Status) -> Self {
+            FakeResult {
+                own: true,
+                status,
+                resent: RefCell::new(0),
+            }
+        }
+    }
+
+    impl SyncResult for FakeResult {
+        fn is_own(&self) -> bool {
+            self.own
+        }
+
+        fn status(&self) -> &Status {
+            &self.status
+        }
+
+        fn resend(&self, _taskbars: Vec<Taskbar>) -> Result<()> {
+            *self.resent.borrow_mut() += 1;
+            Ok(())
+        }
+    }
+
+    fn tracker(max_retries: u32) -> Arc<SyncTracker> {
+        SyncTracker::new(
+            Arc::new(Metrics::new().unwrap()),
+            "http://localhost:3000/test".to_string(),
+            max_retries,
+        )
+    }
+
+    fn pending(tracker: &SyncTracker) -> Vec<u32> {
+        let state = tracker.state.lock().unwrap();
+        state.pending.iter().map(|x| x.attempt).collect()
+    }
+
+    #[test]
+    fn ignores_the_calls_of_other_clients() {
+        let tracker = tracker(3);
+        tracker.push_pending(0);
+        let result = FakeResult {
+            own: false,
+            ..FakeResult::own(Status::Committed)
+        };
+        tracker.on_result(&result, &[]);
+        assert_eq!(pending(&tracker), [0]);
+        assert_eq!(tracker.last_success(), None);
+    }
+
+    #[test]
+    fn answers_the_oldest_call_first() {
+        let tracker = tracker(3);
+        tracker.push_pending(3);
+        tracker.push_pending(0);
+        // The first answer is for the call out of retries, and a newer one is still on its way
+        let result = FakeResult::own(Status::OutOfEnergy);
+        tracker.on_result(&result, &[]);
+        assert_eq!((pending(&tracker), *result.resent.borrow()), (vec![0], 0));
+        // The last call is resent, as nothing newer supersedes it
+        tracker.on_result(&result, &[]);
+        assert_eq!((pending(&tracker), *result.resent.borrow()), (vec![1], 1));
+    }
+
+    #[test]
+    fn resends_out_of_energy_calls_up_to_the_retry_limit() {
+        let tracker = tracker(2);
+        tracker.push_pending(0);
+        let result = FakeResult::own(Status::OutOfEnergy);
+        // The server answers each call made, the first and those resent
+        while !pending(&tracker).is_empty() {
+            tracker.on_result(&result, &[]);
+        }
+        assert_eq!(*result.resent.borrow(), 2);
+        assert_eq!(tracker.last_error().as_deref(), Some("Out of energy"));
+    }
+
+    #[test]
+    fn does_not_resend_rejected_calls() {
+        let tracker = tracker(3);
+        tracker.push_pending(0);
+        let result = FakeResult::own(Status::Failed("machine name must not be empty".into()));
+        tracker.on_result(&result, &[]);
+        assert_eq!(*result.resent.borrow(), 0);
+        assert_eq!(
+            tracker.last_error().as_deref(),
+            Some("machine name must not be empty")
+        );
+        assert_eq!(tracker.last_success(), None);
+    }
+
+    #[test]
+    fn clears_the_last_error_on_success() {
+        let tracker = tracker(3);
+        tracker.push_pending(0);
+        tracker.push_pending(0);
+        tracker.on_result(&FakeResult::own(Status::Failed("rejected".into())), &[]);
+        let before = SystemTime::now();
+        tracker.on_result(&FakeResult::own(Status::Committed), &[]);
+        assert_eq!(tracker.last_error(), None);
+        assert!(tracker.last_success().is_some_and(|x| x >= before));
+        assert!(pending(&tracker).is_empty());
+    }
+}
@@ -0,0 +1,127 @@
+use crate::service::ServiceController;
+use crate::service::SERVICE_NAME;
+use eyre::Context;
+use eyre::Result;
+use std::fs::OpenOptions;
+use std::fs::Permissions;
+use std::io::Write;
+use std::os::unix::fs::OpenOptionsExt;
+use std::os::unix::fs::PermissionsExt;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// Runs the client as a systemd user service, started with the graphical session.
+pub struct SystemdServiceController {
+    unit_path: PathBuf,
+}
+
+impl SystemdServiceController {
+    pub fn new() -> Result<Self> {
+        let config = match std::env::var_os("XDG_CONFIG_HOME") {
+            Some(config) => PathBuf::from(config),
+            None => PathBuf::from(
+                std::env::var_os("HOME").ok_or_else(|| eyre::eyre!("HOME is not set"))?,
+            )
+            .join(".config"),
+        };
+        Ok(SystemdServiceController {
+            unit_path: config
+                .join("systemd/user")
+                .join(format!("{SERVICE_NAME}.service")),
+        })
+    }
+}
+
+/// Quotes an argument for `ExecStart`, where `%` starts a specifier and `$` a variable.
+fn quote(arg: &str) -> String {
+    let escaped = arg
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('%', "%%")
+        .replace('$', "$$");
+    format!("\"{escaped}\"")
+}
+
+fn unit(args: &[String]) -> Result<String> {
+    let executable = std::env::current_exe()?;
+    let command = std::iter::once(executable.to_string_lossy().into_owned())
+        .chain(["service".into(), "run".into()])
+        .chain(args.iter().cloned())
+        .map(|x| quote(&x))
+        .collect::<Vec<_>>()
+        .join(" ");
+    Ok(format!(
+        "[Unit]
+Description=Explorer Wrangler taskbar sync
+PartOf=graphical-session.target
+After=graphical-session.target
+
+[Service]
+ExecStart={command}
+Restart=on-failure
+TimeoutStopSec=15
+
+[Install]
+WantedBy=graphical-session.target
+"
+    ))
+}
+
+fn systemctl(args: &[&str]) -> Result<()> {
+    let status = Command::new("systemctl")
+        .arg("--user")
+        .args(args)
+        .status()
+        .wrap_err("Failed to run systemctl")?;
+    eyre::ensure!(
+        status.success(),
+        "systemctl --user {} failed with {status}",
+        args.join(" ")
+    );
+    Ok(())
+}
+
+impl ServiceController for SystemdServiceController {
+    fn install(&self, args: &[String]) -> Result<()> {
+        if let Some(parent) = self.unit_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let unit = unit(args)?;
+        let failed = || format!("Failed to write {}", self.unit_path.display());
+        // Only the user has any business reading how their client is started
+        let mut file = OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .mode(0o600)
+            .open(&self.unit_path)
+            .wrap_err_with(failed)?;
+        // `mode` only applies to new files, and an older install left the unit readable by all
+        file.set_permissions(Permissions::from_mode(0o600))
+            .wrap_err_with(failed)?;
+        file.write_all(unit.as_bytes()).wrap_err_with(failed)?;
+        systemctl(&["daemon-reload"])?;
+        systemctl(&["enable", SERVICE_NAME])?;
+        tracing::info!("Installed {}", self.unit_path.display());
+        Ok(())
+    }
+
+    fn describe(&self, args: &[String]) -> Result<String> {
+        Ok(format!("# {}\n{}", self.unit_path.display(), unit(args)?))
+    }
+
+    fn uninstall(&self) -> Result<()> {
+        systemctl(&["disable", "--now", SERVICE_NAME])?;
+        std::fs::remove_file(&self.unit_path)
+            .wrap_err_with(|| format!("Failed to remove {}", self.unit_path.display()))?;
+        systemctl(&["daemon-reload"])
+    }
+
+    fn start(&self) -> Result<()> {
+        systemctl(&["start", SERVICE_NAME])
+    }
+
+    fn stop(&self) -> Result<()> {
+        systemctl(&["stop", SERVICE_NAME])
+    }
+}
@@ -0,0 +1,47 @@
+use crate::taskbar_snapshot::TaskbarSnapshot;
+use clap::ValueEnum;
+use eyre::Result;
+
+/// Reads the current taskbars of the local desktop.
+pub trait TaskbarCollector {
+    fn collect(&mut self) -> Result<Vec<TaskbarSnapshot>>;
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CollectorKind {
+    /// `Shell_TrayWnd` taskbars of the Windows shell
+    Windows,
+    /// EWMH dock windows and client list of an X11 desktop
+    X11,
+}
+
+impl Default for CollectorKind {
+    fn default() -> Self {
+        if cfg!(windows) {
+            CollectorKind::Windows
+        } else {
+            CollectorKind::X11
+        }
+    }
+}
+
+pub fn collector(kind: CollectorKind) -> Result<Box<dyn TaskbarCollector>> {
+    match kind {
+        #[cfg(windows)]
+        CollectorKind::Windows => Ok(Box::new(WindowsCollector)),
+        #[cfg(unix)]
+        CollectorKind::X11 => Ok(Box::new(crate::x11_taskbars::X11Collector::connect()?)),
+        kind => eyre::bail!("The {kind:?} collector is not supported on this platform"),
+    }
+}
+
+#[cfg(windows)]
+pub struct WindowsCollector;
+
+#[cfg(windows)]
+impl TaskbarCollector for WindowsCollector {
+    fn collect(&mut self) -> Result<Vec<TaskbarSnapshot>> {
+        let taskbars = crate::get_taskbars::get_taskbars()?;
+        Ok(taskbars.into_iter().map(|x| x.into()).collect())
+    }
+}
@@ -0,0 +1,380 @@
+use crate::module_bindings::report_taskbar_policy;
+use crate::module_bindings::DbConnection;
+use crate::module_bindings::DriftOutcome;
+use crate::module_bindings::TaskbarPolicy;
+use crate::module_bindings::TaskbarPolicyTableAccess;
+use crate::module_bindings::TaskbarSettingDrift;
+use crate::taskbar_snapshot::Alignment;
+use crate::taskbar_snapshot::CombineButtons;
+use crate::taskbar_snapshot::Edge;
+use crate::taskbar_snapshot::TaskbarSettings;
+use crate::taskbar_snapshot::TaskbarSnapshot;
+use eyre::Result;
+use spacetimedb_sdk::Table;
+use std::collections::HashMap;
+
+/// A single setting to bring in line with policy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SettingChange {
+    AutoHide(bool),
+    Edge(Edge),
+    Alignment(Alignment),
+    CombineButtons(CombineButtons),
+}
+
+impl SettingChange {
+    pub fn name(&self) -> &'static str {
+        match self {
+            SettingChange::AutoHide(_) => "auto_hide",
+            SettingChange::Edge(_) => "edge",
+            SettingChange::Alignment(_) => "alignment",
+            SettingChange::CombineButtons(_) => "combine_buttons",
+        }
+    }
+
+    fn value(&self) -> String {
+        match self {
+            SettingChange::AutoHide(x) => format!("{x:?}"),
+            SettingChange::Edge(x) => format!("{x:?}"),
+            SettingChange::Alignment(x) => format!("{x:?}"),
+            SettingChange::CombineButtons(x) => format!("{x:?}"),
+        }
+    }
+
+    /// The value `settings` currently has for the setting this change targets.
+    fn current(&self, settings: &TaskbarSettings) -> String {
+        match self {
+            SettingChange::AutoHide(_) => format!("{:?}", settings.auto_hide),
+            SettingChange::Edge(_) => format!("{:?}", settings.edge),
+            SettingChange::Alignment(_) => format!("{:?}", settings.alignment),
+            SettingChange::CombineButtons(_) => format!("{:?}", settings.combine_buttons),
+        }
+    }
+}
+
+/// Applies taskbar setting changes to the local machine.
+pub trait TaskbarSettingsWriter {
+    fn apply(&mut self, change: SettingChange) -> Result<()>;
+
+    /// Whether `apply` only pretends, in which case drift is reported as not applied.
+    fn is_dry_run(&self) -> bool {
+        false
+    }
+}
+
+/// Only logs what would change, for trying out a policy before enforcing it.
+pub struct DryRunSettingsWriter;
+
+impl TaskbarSettingsWriter for DryRunSettingsWriter {
+    fn apply(&mut self, change: SettingChange) -> Result<()> {
+        tracing::info!("Would set taskbar {} to {}", change.name(), change.value());
+        Ok(())
+    }
+
+    fn is_dry_run(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(windows)]
+pub fn live_settings_writer() -> Result<Box<dyn TaskbarSettingsWriter>> {
+    Ok(Box::new(crate::taskbar_settings::WindowsSettingsWriter))
+}
+
+#[cfg(not(windows))]
+pub fn live_settings_writer() -> Result<Box<dyn TaskbarSettingsWriter>> {
+    eyre::bail!("Applying taskbar settings is only supported on Windows, use `--dry-run` instead")
+}
+
+/// Policy with the machine's own policy layered over the global one.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DesiredSettings {
+    pub auto_hide: Option<bool>,
+    pub edge: Option<Edge>,
+    pub alignment: Option<Alignment>,
+    pub combine_buttons: Option<CombineButtons>,
+}
+
+impl DesiredSettings {
+    pub fn for_machine(policies: impl IntoIterator<Item = TaskbarPolicy>, machine: &str) -> Self {
+        let mut global = DesiredSettings::default();
+        let mut local = DesiredSettings::default();
+        for policy in policies {
+            let target = match policy.machine.as_deref() {
+                None => &mut global,
+                Some(x) if x == machine => &mut local,
+                Some(_) => continue,
+            };
+            *target = DesiredSettings {
+                auto_hide: policy.auto_hide,
+                edge: policy.edge.map(|x| x.into()),
+                alignment: policy.alignment.map(|x| x.into()),
+                combine_buttons: policy.combine_buttons.map(|x| x.into()),
+            };
+        }
+        DesiredSettings {
+            auto_hide: local.auto_hide.or(global.auto_hide),
+            edge: local.edge.or(global.edge),
+            alignment: local.alignment.or(global.alignment),
+            combine_buttons: local.combine_buttons.or(global.combine_buttons),
+        }
+    }
+
+    /// The changes needed to bring `actual` in line with this policy.
+    pub fn changes(&self, actual: &TaskbarSettings) -> Vec<SettingChange> {
+        let mut changes = Vec::new();
+        if let Some(x) = self.auto_hide.filter(|x| *x != actual.auto_hide) {
+            changes.push(SettingChange::AutoHide(x));
+        }
+        if let Some(x) = self.edge.filter(|x| *x != actual.edge) {
+            changes.push(SettingChange::Edge(x));
+        }
+        if let Some(x) = self.alignment.filter(|x| *x != actual.alignment) {
+            changes.push(SettingChange::Alignment(x));
+        }
+        if let Some(x) = self
+            .combine_buttons
+            .filter(|x| *x != actual.combine_buttons)
+        {
+            changes.push(SettingChange::CombineButtons(x));
+        }
+        changes
+    }
+}
+
+/// Compares each collected snapshot against policy, applies the differences and reports drift to the server.
+pub struct PolicyReconciler {
+    writer: Box<dyn TaskbarSettingsWriter>,
+    /// What applying each change gave, kept until the observed settings change so a setting that
+    /// does not stick is not rewritten on every pass
+    applied: HashMap<SettingChange, DriftOutcome>,
+    observed: Option<TaskbarSettings>,
+    last_report: Option<Vec<TaskbarSettingDrift>>,
+}
+
+impl PolicyReconciler {
+    pub fn new(writer: Box<dyn TaskbarSettingsWriter>) -> Self {
+        PolicyReconciler {
+            writer,
+            applied: HashMap::new(),
+            observed: None,
+            last_report: None,
+        }
+    }
+
+    pub fn reconcile(
+        &mut self,
+        connection: &DbConnection,
+        taskbars: &[TaskbarSnapshot],
+    ) -> Result<()> {
+        // Only the primary taskbar's edge can be configured, the other settings are machine-wide
+        let Some(primary) = taskbars
+            .iter()
+            .find(|x| !x.is_secondary)
+            .or(taskbars.first())
+        else {
+            return Ok(());
+        };
+        let desired =
+            DesiredSettings::for_machine(connection.db.taskbar_policy().iter(), &primary.machine);
+        let drift = self.drift(&desired, &primary.settings);
+        if self.last_report.as_ref() != Some(&drift) {
+            connection
+                .reducers
+                .report_taskbar_policy(primary.machine.clone(), drift.clone())?;
+            self.last_report = Some(drift);
+        }
+        Ok(())
+    }
+
+    /// Applies the changes `actual` needs that were not already tried against these settings.
+    fn drift(
+        &mut self,
+        desired: &DesiredSettings,
+        actual: &TaskbarSettings,
+    ) -> Vec<TaskbarSettingDrift> {
+        if self.observed.as_ref() != Some(actual) {
+            self.applied.clear();
+            self.observed = Some(*actual);
+        }
+        desired
+            .changes(actual)
+            .into_iter()
+            .map(|change| {
+                let writer = &mut self.writer;
+                let outcome = self
+                    .applied
+                    .entry(change)
+                    .or_insert_with(|| match writer.apply(change) {
+                        Ok(()) if writer.is_dry_run() => DriftOutcome::DryRun,
+                        Ok(()) => DriftOutcome::Applied,
+                        Err(e) => {
+                            tracing::warn!("Failed to set taskbar {}: {e}", change.name());
+                            DriftOutcome::Failed(e.to_string())
+                        }
+                    })
+                    .clone();
+                TaskbarSettingDrift {
+                    setting: change.name().to_string(),
+                    expected: change.value(),
+                    actual: change.current(actual),
+                    outcome,
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    type Applied = Rc<RefCell<Vec<SettingChange>>>;
+
+    /// Records the changes it is asked to apply, failing those in `failing`.
+    struct FixtureWriter {
+        applied: Applied,
+        failing: Vec<SettingChange>,
+    }
+
+    impl TaskbarSettingsWriter for FixtureWriter {
+        fn apply(&mut self, change: SettingChange) -> Result<()> {
+            self.applied.borrow_mut().push(change);
+            if self.failing.contains(&change) {
+                eyre::bail!("access denied");
+            }
+            Ok(())
+        }
+    }
+
+    fn reconciler(failing: Vec<SettingChange>) -> (PolicyReconciler, Applied) {
+        let applied = Applied::default();
+        let writer = FixtureWriter {
+            applied: applied.clone(),
+            failing,
+        };
+        (PolicyReconciler::new(Box::new(writer)), applied)
+    }
+
+    #[test]
+    fn changes_only_lists_settings_that_differ() {
+        let desired = DesiredSettings {
+            auto_hide: Some(true),
+            edge: Some(Edge::Bottom),
+            alignment: Some(Alignment::Center),
+            combine_buttons: None,
+        };
+        let actual = TaskbarSettings {
+            auto_hide: false,
+            edge: Edge::Bottom,
+            alignment: Alignment::Left,
+            locked: true,
+            combine_buttons: CombineButtons::Never,
+        };
+        assert_eq!(
+            desired.changes(&actual),
+            vec![
+                SettingChange::AutoHide(true),
+                SettingChange::Alignment(Alignment::Center)
+            ]
+        );
+    }
+
+    #[test]
+    fn changes_are_empty_without_policy() {
+        assert!(DesiredSettings::default()
+            .changes(&TaskbarSettings::default())
+            .is_empty());
+    }
+
+    #[test]
+    fn applies_each_change_once_while_the_settings_stay_the_same() {
+        let (mut reconciler, applied) = reconciler(Vec::new());
+        let desired = DesiredSettings {
+            auto_hide: Some(true),
+            ..Default::default()
+        };
+        let actual = TaskbarSettings::default();
+        let first = reconciler.drift(&desired, &actual);
+        let second = reconciler.drift(&desired, &actual);
+        assert_eq!(*applied.borrow(), vec![SettingChange::AutoHide(true)]);
+        assert_eq!(first, second);
+        assert_eq!(first[0].outcome, DriftOutcome::Applied);
+        assert_eq!(first[0].actual, "false");
+    }
+
+    #[test]
+    fn retries_once_the_observed_settings_change() {
+        let (mut reconciler, applied) = reconciler(Vec::new());
+        let desired = DesiredSettings {
+            auto_hide: Some(true),
+            edge: Some(Edge::Top),
+            ..Default::default()
+        };
+        reconciler.drift(&desired, &TaskbarSettings::default());
+        let moved = TaskbarSettings {
+            edge: Edge::Left,
+            ..Default::default()
+        };
+        reconciler.drift(&desired, &moved);
+        assert_eq!(
+            *applied.borrow(),
+            vec![
+                SettingChange::AutoHide(true),
+                SettingChange::Edge(Edge::Top),
+                SettingChange::AutoHide(true),
+                SettingChange::Edge(Edge::Top),
+            ]
+        );
+    }
+
+    #[test]
+    fn applies_a_changed_policy_straight_away() {
+        let (mut reconciler, applied) = reconciler(Vec::new());
+        let actual = TaskbarSettings::default();
+        let mut desired = DesiredSettings {
+            edge: Some(Edge::Top),
+            ..Default::default()
+        };
+        reconciler.drift(&desired, &actual);
+        desired.edge = Some(Edge::Left);
+        reconciler.drift(&desired, &actual);
+        assert_eq!(
+            *applied.borrow(),
+            vec![
+                SettingChange::Edge(Edge::Top),
+                SettingChange::Edge(Edge::Left)
+            ]
+        );
+    }
+
+    #[test]
+    fn keeps_reporting_failures_without_retrying() {
+        let (mut reconciler, applied) = reconciler(vec![SettingChange::AutoHide(true)]);
+        let desired = DesiredSettings {
+            auto_hide: Some(true),
+            ..Default::default()
+        };
+        let actual = TaskbarSettings::default();
+        reconciler.drift(&desired, &actual);
+        let drift = reconciler.drift(&desired, &actual);
+        assert_eq!(applied.borrow().len(), 1);
+        assert_eq!(
+            drift[0].outcome,
+            DriftOutcome::Failed("access denied".to_string())
+        );
+    }
+
+    #[test]
+    fn reports_dry_runs_as_not_applied() {
+        let mut reconciler = PolicyReconciler::new(Box::new(DryRunSettingsWriter));
+        let desired = DesiredSettings {
+            alignment: Some(Alignment::Center),
+            ..Default::default()
+        };
+        let drift = reconciler.drift(&desired, &TaskbarSettings::default());
+        assert_eq!(drift[0].outcome, DriftOutcome::DryRun);
+    }
+}
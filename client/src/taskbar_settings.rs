@@ -0,0 +1,177 @@
+use crate::taskbar_policy::SettingChange;
+use crate::taskbar_policy::TaskbarSettingsWriter;
+use crate::taskbar_snapshot::Alignment;
+use crate::taskbar_snapshot::CombineButtons;
+use crate::taskbar_snapshot::Edge;
+use crate::taskbar_snapshot::Rect;
+use crate::taskbar_snapshot::TaskbarSettings;
+use crate::windows_registry::read_binary;
+use crate::windows_registry::read_dword;
+use crate::windows_registry::read_string;
+use crate::windows_registry::write_binary;
+use crate::windows_registry::write_dword;
+use eyre::OptionExt;
+use eyre::Result;
+use windows::core::w;
+use windows::core::PCWSTR;
+use windows::Win32::Foundation::HWND;
+use windows::Win32::Foundation::LPARAM;
+use windows::Win32::Foundation::RECT;
+use windows::Win32::Foundation::WPARAM;
+use windows::Win32::Graphics::Gdi::GetMonitorInfoW;
+use windows::Win32::Graphics::Gdi::MonitorFromWindow;
+use windows::Win32::Graphics::Gdi::MONITORINFO;
+use windows::Win32::Graphics::Gdi::MONITOR_DEFAULTTONEAREST;
+use windows::Win32::System::Registry::HKEY_CURRENT_USER;
+use windows::Win32::System::Registry::HKEY_LOCAL_MACHINE;
+use windows::Win32::UI::Shell::SHAppBarMessage;
+use windows::Win32::UI::Shell::ABM_GETSTATE;
+use windows::Win32::UI::Shell::ABM_SETSTATE;
+use windows::Win32::UI::Shell::ABS_ALWAYSONTOP;
+use windows::Win32::UI::Shell::ABS_AUTOHIDE;
+use windows::Win32::UI::Shell::APPBARDATA;
+use windows::Win32::UI::WindowsAndMessaging::SendNotifyMessageW;
+use windows::Win32::UI::WindowsAndMessaging::HWND_BROADCAST;
+use windows::Win32::UI::WindowsAndMessaging::WM_SETTINGCHANGE;
+
+const EXPLORER_ADVANCED: PCWSTR =
+    w!("Software\\Microsoft\\Windows\\CurrentVersion\\Explorer\\Advanced");
+const STUCK_RECTS: PCWSTR =
+    w!("Software\\Microsoft\\Windows\\CurrentVersion\\Explorer\\StuckRects3");
+
+/// Offset of the docked edge inside the `StuckRects3` settings blob.
+const STUCK_RECTS_EDGE: usize = 12;
+
+/// First build number of Windows 11, where taskbar icons are centered unless `TaskbarAl` says otherwise.
+const WINDOWS_11_BUILD: u32 = 22000;
+
+/// Reads the configuration of the taskbar window `hwnd` occupying `rect`.
+pub fn read_taskbar_settings(hwnd: HWND, rect: RECT) -> Result<TaskbarSettings> {
+    Ok(TaskbarSettings {
+        auto_hide: is_auto_hide(),
+        edge: Edge::from_rects(rect.into(), monitor_rect(hwnd)?),
+        alignment: read_alignment()?,
+        locked: read_dword(HKEY_CURRENT_USER, EXPLORER_ADVANCED, w!("TaskbarSizeMove"))?
+            .is_none_or(|x| x == 0),
+        combine_buttons: match read_dword(
+            HKEY_CURRENT_USER,
+            EXPLORER_ADVANCED,
+            w!("TaskbarGlomLevel"),
+        )? {
+            Some(1) => CombineButtons::WhenFull,
+            Some(2) => CombineButtons::Never,
+            _ => CombineButtons::Always,
+        },
+    })
+}
+
+/// Writes taskbar settings through the shell and the registry values Explorer reads them from.
+pub struct WindowsSettingsWriter;
+
+impl TaskbarSettingsWriter for WindowsSettingsWriter {
+    fn apply(&mut self, change: SettingChange) -> Result<()> {
+        match change {
+            SettingChange::AutoHide(auto_hide) => {
+                let mut data = APPBARDATA {
+                    cbSize: std::mem::size_of::<APPBARDATA>() as u32,
+                    lParam: LPARAM(if auto_hide {
+                        ABS_AUTOHIDE
+                    } else {
+                        ABS_ALWAYSONTOP
+                    } as isize),
+                    ..Default::default()
+                };
+                unsafe { SHAppBarMessage(ABM_SETSTATE, &mut data) };
+            }
+            SettingChange::Edge(edge) => {
+                let mut settings = read_binary(HKEY_CURRENT_USER, STUCK_RECTS, w!("Settings"))?
+                    .ok_or_eyre("StuckRects3 settings not found")?;
+                eyre::ensure!(
+                    settings.len() > STUCK_RECTS_EDGE,
+                    "Unexpected StuckRects3 layout ({} bytes)",
+                    settings.len()
+                );
+                settings[STUCK_RECTS_EDGE] = match edge {
+                    Edge::Left => 0,
+                    Edge::Top => 1,
+                    Edge::Right => 2,
+                    Edge::Bottom => 3,
+                };
+                write_binary(HKEY_CURRENT_USER, STUCK_RECTS, w!("Settings"), &settings)?;
+                tracing::warn!("Taskbar edge changes take effect once Explorer restarts");
+            }
+            SettingChange::Alignment(alignment) => {
+                let value = match alignment {
+                    Alignment::Left => 0,
+                    Alignment::Center => 1,
+                };
+                write_dword(HKEY_CURRENT_USER, EXPLORER_ADVANCED, w!("TaskbarAl"), value)?;
+            }
+            SettingChange::CombineButtons(combine_buttons) => {
+                let value = match combine_buttons {
+                    CombineButtons::Always => 0,
+                    CombineButtons::WhenFull => 1,
+                    CombineButtons::Never => 2,
+                };
+                write_dword(
+                    HKEY_CURRENT_USER,
+                    EXPLORER_ADVANCED,
+                    w!("TaskbarGlomLevel"),
+                    value,
+                )?;
+            }
+        }
+        // Ask Explorer to pick up the registry changes
+        unsafe {
+            SendNotifyMessageW(
+                HWND_BROADCAST,
+                WM_SETTINGCHANGE,
+                WPARAM(0),
+                LPARAM(w!("TraySettings").as_ptr() as isize),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Auto-hide is a single setting shared by every taskbar on the machine.
+fn is_auto_hide() -> bool {
+    let mut data = APPBARDATA {
+        cbSize: std::mem::size_of::<APPBARDATA>() as u32,
+        ..Default::default()
+    };
+    let state = unsafe { SHAppBarMessage(ABM_GETSTATE, &mut data) } as u32;
+    state & ABS_AUTOHIDE != 0
+}
+
+fn monitor_rect(hwnd: HWND) -> Result<Rect> {
+    let mut info = MONITORINFO {
+        cbSize: std::mem::size_of::<MONITORINFO>() as u32,
+        ..Default::default()
+    };
+    unsafe {
+        let monitor = MonitorFromWindow(hwnd, MONITOR_DEFAULTTONEAREST);
+        GetMonitorInfoW(monitor, &mut info).ok()?;
+    }
+    Ok(info.rcMonitor.into())
+}
+
+fn read_alignment() -> Result<Alignment> {
+    let alignment = match read_dword(HKEY_CURRENT_USER, EXPLORER_ADVANCED, w!("TaskbarAl"))? {
+        Some(0) => Alignment::Left,
+        Some(_) => Alignment::Center,
+        None if windows_build()? >= WINDOWS_11_BUILD => Alignment::Center,
+        None => Alignment::Left,
+    };
+    Ok(alignment)
+}
+
+fn windows_build() -> Result<u32> {
+    let build = read_string(
+        HKEY_LOCAL_MACHINE,
+        w!("SOFTWARE\\Microsoft\\Windows NT\\CurrentVersion"),
+        w!("CurrentBuildNumber"),
+    )?
+    .ok_or_eyre("Windows build number not found")?;
+    Ok(build.parse()?)
+}
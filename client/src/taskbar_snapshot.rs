@@ -0,0 +1,349 @@
+use crate::module_bindings::Taskbar;
+use crate::module_bindings::TaskbarAlignment;
+use crate::module_bindings::TaskbarCombineButtons;
+use crate::module_bindings::TaskbarEdge;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Screen rectangle in physical pixels, edges inclusive of `left`/`top` and exclusive of `right`/`bottom`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Rect {
+    pub left: i32,
+    pub top: i32,
+    pub right: i32,
+    pub bottom: i32,
+}
+
+impl Rect {
+    /// 0 for inverted rects, which minimized and off-screen windows report.
+    pub fn width(&self) -> u32 {
+        u32::try_from(i64::from(self.right) - i64::from(self.left)).unwrap_or(0)
+    }
+
+    /// 0 for inverted rects, which minimized and off-screen windows report.
+    pub fn height(&self) -> u32 {
+        u32::try_from(i64::from(self.bottom) - i64::from(self.top)).unwrap_or(0)
+    }
+}
+
+/// Parses `left,top,right,bottom`.
+impl std::str::FromStr for Rect {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let edges = s
+            .split(',')
+            .map(|x| x.trim().parse::<i32>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| e.to_string())?;
+        let [left, top, right, bottom] = edges[..] else {
+            return Err(format!("Expected left,top,right,bottom but got {s:?}"));
+        };
+        if right < left || bottom < top {
+            return Err(format!("{s:?} has a negative size"));
+        }
+        Ok(Rect {
+            left,
+            top,
+            right,
+            bottom,
+        })
+    }
+}
+
+#[cfg(windows)]
+impl From<windows::Win32::Foundation::RECT> for Rect {
+    fn from(value: windows::Win32::Foundation::RECT) -> Self {
+        Rect {
+            left: value.left,
+            top: value.top,
+            right: value.right,
+            bottom: value.bottom,
+        }
+    }
+}
+
+#[cfg(windows)]
+impl From<Rect> for windows::Win32::Foundation::RECT {
+    fn from(value: Rect) -> Self {
+        windows::Win32::Foundation::RECT {
+            left: value.left,
+            top: value.top,
+            right: value.right,
+            bottom: value.bottom,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum Edge {
+    Left,
+    Top,
+    Right,
+    #[default]
+    Bottom,
+}
+
+impl Edge {
+    /// Works out which monitor edge a taskbar is docked to from its rect and the monitor's rect.
+    pub fn from_rects(taskbar: Rect, monitor: Rect) -> Edge {
+        if taskbar.width() >= taskbar.height() {
+            if taskbar.top - monitor.top <= monitor.bottom - taskbar.bottom {
+                Edge::Top
+            } else {
+                Edge::Bottom
+            }
+        } else if taskbar.left - monitor.left <= monitor.right - taskbar.right {
+            Edge::Left
+        } else {
+            Edge::Right
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum Alignment {
+    #[default]
+    Left,
+    Center,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum CombineButtons {
+    #[default]
+    Always,
+    WhenFull,
+    Never,
+}
+
+/// Taskbar configuration, as opposed to its geometry and contents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TaskbarSettings {
+    pub auto_hide: bool,
+    pub edge: Edge,
+    pub alignment: Alignment,
+    pub locked: bool,
+    pub combine_buttons: CombineButtons,
+}
+
+/// Name this machine reports its taskbars under.
+pub fn local_machine_name() -> String {
+    gethostname::gethostname().to_string_lossy().into_owned()
+}
+
+/// Platform-neutral taskbar state, used for recordings, file output and fixtures.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskbarSnapshot {
+    #[serde(default)]
+    pub machine: String,
+    #[serde(alias = "id")]
+    pub index: u32,
+    pub rect: Rect,
+    pub is_secondary: bool,
+    pub apps: Vec<String>,
+    #[serde(default)]
+    pub settings: TaskbarSettings,
+}
+
+impl TaskbarSnapshot {
+    /// The `taskbar` row for this snapshot, which snapshots carry no id for.
+    pub fn into_row(self, id: u32) -> Taskbar {
+        Taskbar {
+            id,
+            machine: self.machine,
+            index: self.index,
+            is_secondary: self.is_secondary,
+            x: self.rect.left,
+            y: self.rect.top,
+            width: self.rect.width(),
+            height: self.rect.height(),
+            apps: self.apps,
+            auto_hide: self.settings.auto_hide,
+            edge: self.settings.edge.into(),
+            alignment: self.settings.alignment.into(),
+            locked: self.settings.locked,
+            combine_buttons: self.settings.combine_buttons.into(),
+        }
+    }
+}
+
+impl From<Taskbar> for TaskbarSnapshot {
+    fn from(value: Taskbar) -> Self {
+        TaskbarSnapshot {
+            machine: value.machine,
+            index: value.index,
+            rect: Rect {
+                left: value.x,
+                top: value.y,
+                right: value.x.saturating_add_unsigned(value.width),
+                bottom: value.y.saturating_add_unsigned(value.height),
+            },
+            is_secondary: value.is_secondary,
+            apps: value.apps,
+            settings: TaskbarSettings {
+                auto_hide: value.auto_hide,
+                edge: value.edge.into(),
+                alignment: value.alignment.into(),
+                locked: value.locked,
+                combine_buttons: value.combine_buttons.into(),
+            },
+        }
+    }
+}
+
+impl From<Edge> for TaskbarEdge {
+    fn from(value: Edge) -> Self {
+        match value {
+            Edge::Left => TaskbarEdge::Left,
+            Edge::Top => TaskbarEdge::Top,
+            Edge::Right => TaskbarEdge::Right,
+            Edge::Bottom => TaskbarEdge::Bottom,
+        }
+    }
+}
+
+impl From<TaskbarEdge> for Edge {
+    fn from(value: TaskbarEdge) -> Self {
+        match value {
+            TaskbarEdge::Left => Edge::Left,
+            TaskbarEdge::Top => Edge::Top,
+            TaskbarEdge::Right => Edge::Right,
+            TaskbarEdge::Bottom => Edge::Bottom,
+        }
+    }
+}
+
+impl From<Alignment> for TaskbarAlignment {
+    fn from(value: Alignment) -> Self {
+        match value {
+            Alignment::Left => TaskbarAlignment::Left,
+            Alignment::Center => TaskbarAlignment::Center,
+        }
+    }
+}
+
+impl From<TaskbarAlignment> for Alignment {
+    fn from(value: TaskbarAlignment) -> Self {
+        match value {
+            TaskbarAlignment::Left => Alignment::Left,
+            TaskbarAlignment::Center => Alignment::Center,
+        }
+    }
+}
+
+impl From<CombineButtons> for TaskbarCombineButtons {
+    fn from(value: CombineButtons) -> Self {
+        match value {
+            CombineButtons::Always => TaskbarCombineButtons::Always,
+            CombineButtons::WhenFull => TaskbarCombineButtons::WhenFull,
+            CombineButtons::Never => TaskbarCombineButtons::Never,
+        }
+    }
+}
+
+impl From<TaskbarCombineButtons> for CombineButtons {
+    fn from(value: TaskbarCombineButtons) -> Self {
+        match value {
+            TaskbarCombineButtons::Always => CombineButtons::Always,
+            TaskbarCombineButtons::WhenFull => CombineButtons::WhenFull,
+            TaskbarCombineButtons::Never => CombineButtons::Never,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(rect: Rect, settings: TaskbarSettings) -> TaskbarSnapshot {
+        TaskbarSnapshot {
+            machine: "office-pc".to_string(),
+            index: 1,
+            rect,
+            is_secondary: true,
+            apps: vec!["Terminal".to_string(), "Files — Downloads".to_string()],
+            settings,
+        }
+    }
+
+    fn snapshots() -> Vec<TaskbarSnapshot> {
+        let rects = [
+            Rect {
+                left: 0,
+                top: 1040,
+                right: 1920,
+                bottom: 1080,
+            },
+            // Monitors left of and above the primary have negative coordinates
+            Rect {
+                left: -2560,
+                top: -1440,
+                right: -2512,
+                bottom: 0,
+            },
+            Rect {
+                left: i32::MIN,
+                top: i32::MIN,
+                right: i32::MAX,
+                bottom: i32::MAX,
+            },
+        ];
+        let settings = [
+            TaskbarSettings::default(),
+            TaskbarSettings {
+                auto_hide: true,
+                edge: Edge::Left,
+                alignment: Alignment::Left,
+                locked: true,
+                combine_buttons: CombineButtons::Never,
+            },
+            TaskbarSettings {
+                edge: Edge::Top,
+                combine_buttons: CombineButtons::WhenFull,
+                ..Default::default()
+            },
+            TaskbarSettings {
+                edge: Edge::Right,
+                combine_buttons: CombineButtons::Always,
+                ..Default::default()
+            },
+        ];
+        rects
+            .into_iter()
+            .flat_map(|rect| settings.map(|x| snapshot(rect, x)))
+            .collect()
+    }
+
+    #[test]
+    fn round_trips_through_taskbar_rows() {
+        for snapshot in snapshots() {
+            let row = snapshot.clone().into_row(7);
+            assert_eq!(row.id, 7);
+            assert_eq!(TaskbarSnapshot::from(row), snapshot);
+        }
+    }
+
+    #[test]
+    fn inverted_rects_become_empty_rows() {
+        let rect = Rect {
+            left: 100,
+            top: 100,
+            right: 50,
+            bottom: 40,
+        };
+        let row = snapshot(rect, TaskbarSettings::default()).into_row(0);
+        assert_eq!((row.x, row.y, row.width, row.height), (100, 100, 0, 0));
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn round_trips_through_windows_taskbars() {
+        use crate::windows_taskbar::WindowsTaskbar;
+
+        for snapshot in snapshots() {
+            let taskbar = WindowsTaskbar::from(snapshot.clone());
+            assert_eq!(TaskbarSnapshot::from(taskbar), snapshot);
+        }
+    }
+}
@@ -0,0 +1,180 @@
+use crate::recording::read_recording;
+use crate::recording::RecordedSnapshot;
+use crate::shutdown::Shutdown;
+use crate::taskbar_collector::collector;
+use crate::taskbar_collector::CollectorKind;
+use crate::taskbar_collector::TaskbarCollector;
+use crate::taskbar_snapshot::TaskbarSnapshot;
+use chrono::DateTime;
+use chrono::Utc;
+use eyre::Result;
+use std::path::Path;
+use std::time::Duration;
+
+/// Produces the taskbar snapshots that get pushed to the server.
+pub trait TaskbarSource {
+    /// Blocks until the next snapshot is due, returning `None` once the source is exhausted or
+    /// shutdown was requested.
+    fn next_snapshot(&mut self) -> Result<Option<Vec<TaskbarSnapshot>>>;
+}
+
+/// Collects from the local desktop every `interval`.
+pub struct LiveTaskbarSource {
+    collector: Box<dyn TaskbarCollector>,
+    interval: Duration,
+    started: bool,
+    shutdown: Shutdown,
+}
+
+impl TaskbarSource for LiveTaskbarSource {
+    fn next_snapshot(&mut self) -> Result<Option<Vec<TaskbarSnapshot>>> {
+        if self.started && self.shutdown.wait(self.interval) {
+            return Ok(None);
+        }
+        self.started = true;
+        let _span = tracing::info_span!("collect").entered();
+        let taskbars = self.collector.collect()?;
+        tracing::debug!(taskbars = taskbars.len(), "Collected taskbars");
+        Ok(Some(taskbars))
+    }
+}
+
+pub fn live_source(
+    interval: Duration,
+    kind: CollectorKind,
+    shutdown: Shutdown,
+) -> Result<Box<dyn TaskbarSource>> {
+    Ok(Box::new(LiveTaskbarSource {
+        collector: collector(kind)?,
+        interval,
+        started: false,
+        shutdown,
+    }))
+}
+
+/// Slowest replay speed accepted, which already stretches a minute of recording to 16 hours.
+const MIN_SPEED: f64 = 0.001;
+
+/// How long to sleep between snapshots recorded `gap` apart when replaying at `speed`.
+fn replay_delay(gap: Duration, speed: f64) -> Duration {
+    Duration::try_from_secs_f64(gap.as_secs_f64() / speed).unwrap_or(Duration::MAX)
+}
+
+/// Plays a recording back, sleeping between snapshots as long as the original session did.
+pub struct ReplayTaskbarSource {
+    snapshots: std::vec::IntoIter<RecordedSnapshot>,
+    speed: f64,
+    previous_at: Option<DateTime<Utc>>,
+    shutdown: Shutdown,
+}
+
+impl ReplayTaskbarSource {
+    pub fn open(path: &Path, speed: f64, shutdown: Shutdown) -> Result<Self> {
+        eyre::ensure!(
+            speed.is_finite() && speed >= MIN_SPEED,
+            "Replay speed must be a number from {MIN_SPEED}, got {speed}"
+        );
+        let snapshots = read_recording(path)?;
+        tracing::info!(
+            "Replaying {} snapshots from {} at {speed}x",
+            snapshots.len(),
+            path.display()
+        );
+        Ok(ReplayTaskbarSource {
+            snapshots: snapshots.into_iter(),
+            speed,
+            previous_at: None,
+            shutdown,
+        })
+    }
+}
+
+impl TaskbarSource for ReplayTaskbarSource {
+    fn next_snapshot(&mut self) -> Result<Option<Vec<TaskbarSnapshot>>> {
+        let Some(snapshot) = self.snapshots.next() else {
+            return Ok(None);
+        };
+        if let Some(previous_at) = self.previous_at {
+            let gap = (snapshot.at - previous_at).to_std().unwrap_or_default();
+            if self.shutdown.wait(replay_delay(gap, self.speed)) {
+                return Ok(None);
+            }
+        }
+        self.previous_at = Some(snapshot.at);
+        Ok(Some(snapshot.taskbars))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::recording::Recorder;
+    use crate::taskbar_snapshot::Rect;
+    use crate::taskbar_snapshot::TaskbarSettings;
+
+    fn taskbar(index: u32, apps: &[&str]) -> TaskbarSnapshot {
+        TaskbarSnapshot {
+            machine: "office-pc".to_string(),
+            index,
+            rect: Rect {
+                left: 0,
+                top: 1040,
+                right: 1920,
+                bottom: 1080,
+            },
+            is_secondary: index > 0,
+            apps: apps.iter().map(|x| x.to_string()).collect(),
+            settings: TaskbarSettings::default(),
+        }
+    }
+
+    #[test]
+    fn replays_what_was_recorded() {
+        let path = std::env::temp_dir().join(format!("recording-{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let snapshots = [
+            vec![taskbar(0, &["Terminal"])],
+            vec![taskbar(0, &["Terminal", "Files"]), taskbar(1, &[])],
+            Vec::new(),
+        ];
+        let mut recorder = Recorder::create(&path).unwrap();
+        for snapshot in &snapshots {
+            recorder.record(snapshot).unwrap();
+        }
+        drop(recorder);
+
+        let recorded = read_recording(&path).unwrap();
+        assert_eq!(recorded.len(), 3);
+        assert!(recorded.windows(2).all(|x| x[0].at <= x[1].at));
+        let mut replay = ReplayTaskbarSource::open(&path, 1000.0, Shutdown::default()).unwrap();
+        let mut replayed = Vec::new();
+        while let Some(snapshot) = replay.next_snapshot().unwrap() {
+            replayed.push(snapshot);
+        }
+        assert_eq!(replayed, snapshots);
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn refuses_speeds_it_cannot_sleep_for() {
+        for speed in [0.0, -1.0, 1e-300, f64::NAN, f64::INFINITY] {
+            assert!(
+                ReplayTaskbarSource::open(Path::new("unused.jsonl"), speed, Shutdown::default())
+                    .is_err(),
+                "{speed}"
+            );
+        }
+    }
+
+    #[test]
+    fn scales_gaps_without_overflowing() {
+        assert_eq!(
+            replay_delay(Duration::from_secs(10), 2.0),
+            Duration::from_secs(5)
+        );
+        assert_eq!(
+            replay_delay(Duration::from_secs(u64::MAX / 2), MIN_SPEED),
+            Duration::MAX
+        );
+    }
+}
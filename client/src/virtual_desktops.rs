@@ -0,0 +1,147 @@
+use crate::module_bindings::sync_virtual_desktops;
+use crate::module_bindings::DbConnection;
+use crate::module_bindings::DesktopWindow;
+use crate::module_bindings::VirtualDesktop;
+use eyre::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DesktopSnapshot {
+    pub guid: String,
+    pub name: String,
+    pub is_current: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WindowSnapshot {
+    pub window_id: u64,
+    pub title: String,
+    /// `None` when the window is pinned to every desktop or its desktop is unknown
+    pub desktop_guid: Option<String>,
+}
+
+/// The virtual desktops of a machine and which of them each top-level window is on.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VirtualDesktopSnapshot {
+    pub desktops: Vec<DesktopSnapshot>,
+    pub windows: Vec<WindowSnapshot>,
+}
+
+pub trait VirtualDesktopCollector {
+    fn collect(&mut self) -> Result<VirtualDesktopSnapshot>;
+}
+
+#[cfg(windows)]
+pub fn live_desktop_collector() -> Result<Box<dyn VirtualDesktopCollector>> {
+    Ok(Box::new(
+        crate::windows_virtual_desktops::WindowsDesktopCollector::new()?,
+    ))
+}
+
+#[cfg(not(windows))]
+pub fn live_desktop_collector() -> Result<Box<dyn VirtualDesktopCollector>> {
+    eyre::bail!("Virtual desktops are only supported on Windows")
+}
+
+pub fn sync_desktops(
+    connection: &DbConnection,
+    machine: &str,
+    snapshot: VirtualDesktopSnapshot,
+) -> Result<()> {
+    let (desktops, windows) = desktop_rows(machine, snapshot);
+    connection
+        .reducers
+        .sync_virtual_desktops(machine.to_string(), desktops, windows)?;
+    Ok(())
+}
+
+/// The rows `sync_virtual_desktops` replaces the machine's with, ids assigned by the server.
+fn desktop_rows(
+    machine: &str,
+    snapshot: VirtualDesktopSnapshot,
+) -> (Vec<VirtualDesktop>, Vec<DesktopWindow>) {
+    let desktops = snapshot
+        .desktops
+        .into_iter()
+        .map(|x| VirtualDesktop {
+            id: 0,
+            machine: machine.to_string(),
+            guid: x.guid,
+            name: x.name,
+            is_current: x.is_current,
+        })
+        .collect();
+    let windows = snapshot
+        .windows
+        .into_iter()
+        .map(|x| DesktopWindow {
+            id: 0,
+            machine: machine.to_string(),
+            window_id: x.window_id,
+            title: x.title,
+            desktop_guid: x.desktop_guid,
+        })
+        .collect();
+    (desktops, windows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn syncs_the_snapshot_as_the_machines_rows() {
+        let snapshot = VirtualDesktopSnapshot {
+            desktops: vec![
+                DesktopSnapshot {
+                    guid: "{0A1B}".to_string(),
+                    name: "Work".to_string(),
+                    is_current: true,
+                },
+                DesktopSnapshot {
+                    guid: "{2C3D}".to_string(),
+                    name: "Games".to_string(),
+                    is_current: false,
+                },
+            ],
+            windows: vec![
+                WindowSnapshot {
+                    window_id: 4096,
+                    title: "Terminal".to_string(),
+                    desktop_guid: Some("{0A1B}".to_string()),
+                },
+                WindowSnapshot {
+                    window_id: 8192,
+                    title: "Clock".to_string(),
+                    desktop_guid: None,
+                },
+            ],
+        };
+
+        let (desktops, windows) = desktop_rows("office", snapshot);
+        assert_eq!(
+            desktops
+                .iter()
+                .map(|x| (
+                    x.machine.as_str(),
+                    x.guid.as_str(),
+                    x.name.as_str(),
+                    x.is_current
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                ("office", "{0A1B}", "Work", true),
+                ("office", "{2C3D}", "Games", false)
+            ]
+        );
+        assert_eq!(
+            windows
+                .iter()
+                .map(|x| (x.machine.as_str(), x.window_id, x.desktop_guid.as_deref()))
+                .collect::<Vec<_>>(),
+            vec![("office", 4096, Some("{0A1B}")), ("office", 8192, None)]
+        );
+        assert!(desktops.iter().all(|x| x.id == 0) && windows.iter().all(|x| x.id == 0));
+    }
+}
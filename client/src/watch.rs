@@ -0,0 +1,335 @@
+use crate::module_bindings::DbConnection;
+use crate::module_bindings::EventContext;
+use crate::module_bindings::Taskbar;
+use crate::module_bindings::TaskbarTableAccess;
+use crate::module_bindings::WatchEvent;
+use crate::module_bindings::WatchRule;
+use crate::module_bindings::WatchRuleTableAccess;
+use crate::recording::RecordedSnapshot;
+use chrono::DateTime;
+use chrono::Utc;
+use clap::ValueEnum;
+use eyre::Context;
+use eyre::Result;
+use regex::Regex;
+use serde::Deserialize;
+use serde::Serialize;
+use spacetimedb_sdk::Event;
+use spacetimedb_sdk::Table;
+use spacetimedb_sdk::TableWithPrimaryKey;
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+use std::sync::mpsc::Sender;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotifierKind {
+    Stdout,
+    Desktop,
+    Webhook,
+}
+
+/// A watched app appeared on or disappeared from the taskbars of a machine.
+#[derive(Debug, Clone, Serialize)]
+pub struct Alert {
+    pub rule: String,
+    pub machine: String,
+    pub app: String,
+    pub appeared: bool,
+    pub at: DateTime<Utc>,
+}
+
+impl fmt::Display for Alert {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let verb = if self.appeared {
+            "appeared on"
+        } else {
+            "disappeared from"
+        };
+        write!(f, "{} {verb} {} ({})", self.app, self.machine, self.rule)
+    }
+}
+
+pub trait Notifier: Send {
+    fn notify(&mut self, alert: &Alert) -> Result<()>;
+}
+
+pub struct StdoutNotifier;
+
+impl Notifier for StdoutNotifier {
+    fn notify(&mut self, alert: &Alert) -> Result<()> {
+        println!("{} {alert}", alert.at.format("%Y-%m-%d %H:%M:%S"));
+        Ok(())
+    }
+}
+
+pub struct DesktopNotifier;
+
+impl Notifier for DesktopNotifier {
+    fn notify(&mut self, alert: &Alert) -> Result<()> {
+        notify_rust::Notification::new()
+            .summary(&alert.rule)
+            .body(&alert.to_string())
+            .show()?;
+        Ok(())
+    }
+}
+
+/// Posts each alert as JSON to a URL.
+pub struct WebhookNotifier {
+    pub url: String,
+}
+
+impl Notifier for WebhookNotifier {
+    fn notify(&mut self, alert: &Alert) -> Result<()> {
+        ureq::post(&self.url).send_json(alert)?;
+        Ok(())
+    }
+}
+
+pub fn notifier(kind: NotifierKind, webhook_url: Option<&str>) -> Result<Box<dyn Notifier>> {
+    Ok(match kind {
+        NotifierKind::Stdout => Box::new(StdoutNotifier),
+        NotifierKind::Desktop => Box::new(DesktopNotifier),
+        NotifierKind::Webhook => Box::new(WebhookNotifier {
+            url: webhook_url
+                .ok_or_else(|| eyre::eyre!("The webhook notifier needs `--webhook-url`"))?
+                .to_string(),
+        }),
+    })
+}
+
+/// Whether a watch rule fires on apps appearing or disappearing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WatchEventKind {
+    Appear,
+    Disappear,
+}
+
+impl From<WatchEvent> for WatchEventKind {
+    fn from(value: WatchEvent) -> Self {
+        match value {
+            WatchEvent::Appear => WatchEventKind::Appear,
+            WatchEvent::Disappear => WatchEventKind::Disappear,
+        }
+    }
+}
+
+/// A `watch_rule` row, or one entry of a rules file given to `notify --replay`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WatchRuleSpec {
+    pub name: String,
+    pub title_regex: String,
+    #[serde(default)]
+    pub machine: Option<String>,
+    pub event: WatchEventKind,
+    #[serde(default = "enabled_by_default")]
+    pub enabled: bool,
+}
+
+fn enabled_by_default() -> bool {
+    true
+}
+
+impl WatchRuleSpec {
+    pub fn load(path: &Path) -> Result<Vec<WatchRuleSpec>> {
+        let json = std::fs::read_to_string(path)
+            .wrap_err_with(|| format!("Failed to read {}", path.display()))?;
+        serde_json::from_str(&json)
+            .wrap_err_with(|| format!("Invalid watch rules file {}", path.display()))
+    }
+}
+
+impl From<WatchRule> for WatchRuleSpec {
+    fn from(value: WatchRule) -> Self {
+        WatchRuleSpec {
+            name: value.name,
+            title_regex: value.title_regex,
+            machine: value.machine,
+            event: value.event.into(),
+            enabled: value.enabled,
+        }
+    }
+}
+
+pub struct CompiledWatchRule {
+    rule: WatchRuleSpec,
+    title: Regex,
+}
+
+/// The enabled watch rules, skipping with a warning any whose regex is invalid.
+pub fn compile(rules: impl IntoIterator<Item = WatchRuleSpec>) -> Vec<CompiledWatchRule> {
+    rules
+        .into_iter()
+        .filter(|x| x.enabled)
+        .filter_map(|rule| match Regex::new(&rule.title_regex) {
+            Ok(title) => Some(CompiledWatchRule { rule, title }),
+            Err(e) => {
+                tracing::warn!("Skipping watch rule {}: {e}", rule.name);
+                None
+            }
+        })
+        .collect()
+}
+
+/// The alerts `rules` raise for the apps of `machine` going from `before` to `after`.
+fn evaluate(
+    rules: &[CompiledWatchRule],
+    machine: &str,
+    before: &BTreeSet<String>,
+    after: &BTreeSet<String>,
+    at: DateTime<Utc>,
+) -> Vec<Alert> {
+    let mut alerts = Vec::new();
+    for compiled in rules {
+        let rule = &compiled.rule;
+        if rule.machine.as_deref().is_some_and(|x| x != machine) {
+            continue;
+        }
+        let (appeared, changed) = match rule.event {
+            WatchEventKind::Appear => (true, after.difference(before)),
+            WatchEventKind::Disappear => (false, before.difference(after)),
+        };
+        alerts.extend(
+            changed
+                .filter(|app| compiled.title.is_match(app))
+                .map(|app| Alert {
+                    rule: rule.name.clone(),
+                    machine: machine.to_string(),
+                    app: app.clone(),
+                    appeared,
+                    at,
+                }),
+        );
+    }
+    alerts
+}
+
+/// Follows the apps of each machine across all its taskbars, so one moving to another monitor
+/// or opening a second window is not reported again.
+#[derive(Default)]
+pub struct Watcher {
+    known: HashMap<String, BTreeSet<String>>,
+}
+
+impl Watcher {
+    /// The alerts for `machine` now showing `current`, compared to `seed` on first sight.
+    pub fn observe(
+        &mut self,
+        rules: &[CompiledWatchRule],
+        machine: &str,
+        seed: BTreeSet<String>,
+        current: BTreeSet<String>,
+        at: DateTime<Utc>,
+    ) -> Vec<Alert> {
+        let previous = self
+            .known
+            .insert(machine.to_string(), current.clone())
+            .unwrap_or(seed);
+        evaluate(rules, machine, &previous, &current, at)
+    }
+}
+
+/// The alerts `rules` raise over a recording, the first snapshot of each machine being taken as is.
+pub fn replay_alerts(rules: &[CompiledWatchRule], snapshots: &[RecordedSnapshot]) -> Vec<Alert> {
+    let mut watcher = Watcher::default();
+    let mut alerts = Vec::new();
+    for snapshot in snapshots {
+        let mut machines = BTreeMap::<&str, BTreeSet<String>>::new();
+        for taskbar in &snapshot.taskbars {
+            machines
+                .entry(&taskbar.machine)
+                .or_default()
+                .extend(taskbar.apps.iter().cloned());
+        }
+        for (machine, apps) in machines {
+            alerts.extend(watcher.observe(rules, machine, apps.clone(), apps, snapshot.at));
+        }
+    }
+    alerts
+}
+
+/// Sends each alert to every notifier on a thread of its own, so a slow webhook does not hold up
+/// the connection's callbacks. The thread ends once the sender is dropped.
+fn spawn_notifiers(mut notifiers: Vec<Box<dyn Notifier>>) -> Sender<Alert> {
+    let (alerts, received) = std::sync::mpsc::channel::<Alert>();
+    std::thread::spawn(move || {
+        for alert in received {
+            for notifier in notifiers.iter_mut() {
+                if let Err(e) = notifier.notify(&alert) {
+                    tracing::warn!("Failed to send alert \"{alert}\": {e}");
+                }
+            }
+        }
+    });
+    alerts
+}
+
+/// Raises alerts as the taskbars of any machine change, appear or go away.
+///
+/// Apps are compared across all taskbars of a machine, so one moving to another monitor is not
+/// reported as disappearing from one and appearing on the other.
+pub fn watch_taskbars(connection: &DbConnection, notifiers: Vec<Box<dyn Notifier>>) {
+    let alerts = spawn_notifiers(notifiers);
+    // Compiled on the first update after the rules change rather than on every update
+    let rules = Arc::new(Mutex::new(None::<Vec<CompiledWatchRule>>));
+    let invalidate = |rules: &Arc<Mutex<Option<Vec<CompiledWatchRule>>>>| {
+        let rules = rules.clone();
+        move || {
+            if let Ok(mut rules) = rules.lock() {
+                *rules = None;
+            }
+        }
+    };
+    let on_insert = invalidate(&rules);
+    let on_delete = invalidate(&rules);
+    let on_update = invalidate(&rules);
+    let watch_rules = connection.db.watch_rule();
+    watch_rules.on_insert(move |_: &EventContext, _: &WatchRule| on_insert());
+    watch_rules.on_delete(move |_: &EventContext, _: &WatchRule| on_delete());
+    watch_rules.on_update(move |_: &EventContext, _: &WatchRule, _: &WatchRule| on_update());
+
+    let watcher = Mutex::new(Watcher::default());
+    // Rows the subscription loads are what was there before the client, not changes
+    let raise =
+        move |ctx: &EventContext, machine: &str, id: u32, before: &[String], after: &[String]| {
+            if !matches!(ctx.event, Event::Reducer(_) | Event::UnknownTransaction) {
+                return;
+            }
+            let others = ctx
+                .db
+                .taskbar()
+                .iter()
+                .filter(|x| x.machine == machine && x.id != id)
+                .flat_map(|x| x.apps)
+                .collect::<BTreeSet<_>>();
+            let previous = others.iter().chain(before).cloned().collect();
+            let current = others.iter().chain(after).cloned().collect();
+            let (Ok(mut rules), Ok(mut watcher)) = (rules.lock(), watcher.lock()) else {
+                return;
+            };
+            let rules = rules.get_or_insert_with(|| {
+                compile(ctx.db.watch_rule().iter().map(WatchRuleSpec::from))
+            });
+            for alert in watcher.observe(rules, machine, previous, current, Utc::now()) {
+                let _ = alerts.send(alert);
+            }
+        };
+    let raise = Arc::new(raise);
+    let taskbars = connection.db.taskbar();
+    let on_insert = raise.clone();
+    taskbars.on_insert(move |ctx: &EventContext, new: &Taskbar| {
+        on_insert(ctx, &new.machine, new.id, &[], &new.apps)
+    });
+    let on_delete = raise.clone();
+    taskbars.on_delete(move |ctx: &EventContext, old: &Taskbar| {
+        on_delete(ctx, &old.machine, old.id, &old.apps, &[])
+    });
+    taskbars.on_update(move |ctx: &EventContext, old: &Taskbar, new: &Taskbar| {
+        raise(ctx, &new.machine, new.id, &old.apps, &new.apps)
+    });
+}
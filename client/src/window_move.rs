@@ -0,0 +1,210 @@
+use crate::module_bindings::report_window_move;
+use crate::module_bindings::DbConnection;
+use crate::module_bindings::ErrorContext;
+use crate::module_bindings::EventContext;
+use crate::module_bindings::TaskbarTableAccess;
+use crate::module_bindings::WindowMoveRequest;
+use crate::module_bindings::WindowMoveRequestTableAccess;
+use crate::taskbar_snapshot::Rect;
+use crate::taskbar_snapshot::TaskbarSnapshot;
+use eyre::OptionExt;
+use eyre::Result;
+use serde::Deserialize;
+use serde::Serialize;
+use spacetimedb_sdk::DbContext;
+use spacetimedb_sdk::Table;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WindowState {
+    Normal,
+    Minimized,
+    Maximized,
+}
+
+/// Where a window sits when restored, and how it is currently shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WindowPlacement {
+    pub rect: Rect,
+    pub state: WindowState,
+}
+
+/// Bounds of a monitor in physical pixels and its scaling, 96 DPI being 100%.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Monitor {
+    pub rect: Rect,
+    pub dpi: u32,
+}
+
+/// Reads and changes the placement of native windows on this machine.
+pub trait WindowMover {
+    fn placement(&self, window_id: u64) -> Result<WindowPlacement>;
+    /// The monitor that `rect` is mostly on.
+    fn monitor(&self, rect: Rect) -> Result<Monitor>;
+    /// Work area (the monitor minus its taskbars) of the monitor that `rect` is mostly on.
+    fn work_area(&self, rect: Rect) -> Result<Rect>;
+    fn set_placement(&mut self, window_id: u64, placement: WindowPlacement) -> Result<()>;
+}
+
+#[cfg(windows)]
+pub fn live_window_mover() -> Result<Box<dyn WindowMover + Send>> {
+    Ok(Box::new(crate::windows_window_mover::WindowsWindowMover))
+}
+
+#[cfg(not(windows))]
+pub fn live_window_mover() -> Result<Box<dyn WindowMover + Send>> {
+    eyre::bail!("Moving windows is only supported on Windows")
+}
+
+/// Maps `window` from the `from` work area onto the `to` work area, keeping its relative position and size.
+///
+/// Fails when either work area is empty, as there is nothing to map from or fit into.
+pub fn relocate(window: Rect, from: Rect, to: Rect) -> Result<Rect> {
+    eyre::ensure!(
+        from.width() > 0 && from.height() > 0,
+        "The window's work area {from:?} is empty"
+    );
+    eyre::ensure!(
+        to.width() > 0 && to.height() > 0,
+        "The target work area {to:?} is empty"
+    );
+    let scale_x = to.width() as f64 / from.width() as f64;
+    let scale_y = to.height() as f64 / from.height() as f64;
+    let width = ((window.width() as f64 * scale_x).round() as i32).min(to.width() as i32);
+    let height = ((window.height() as f64 * scale_y).round() as i32).min(to.height() as i32);
+    let left = to.left + ((window.left - from.left) as f64 * scale_x).round() as i32;
+    let top = to.top + ((window.top - from.top) as f64 * scale_y).round() as i32;
+    // Keep the whole window inside the target work area
+    let left = left.clamp(to.left, to.right - width);
+    let top = top.clamp(to.top, to.bottom - height);
+    Ok(Rect {
+        left,
+        top,
+        right: left + width,
+        bottom: top + height,
+    })
+}
+
+fn move_window(mover: &mut dyn WindowMover, window_id: u64, target: TaskbarSnapshot) -> Result<()> {
+    let placement = mover.placement(window_id)?;
+    let from = mover.work_area(placement.rect)?;
+    let to = mover.work_area(target.rect)?;
+    let rect = relocate(placement.rect, from, to)?;
+    tracing::info!(
+        "Moving window {window_id:#x} from {:?} to {:?} ({:?})",
+        placement.rect,
+        rect,
+        placement.state
+    );
+    mover.set_placement(
+        window_id,
+        WindowPlacement {
+            rect,
+            state: placement.state,
+        },
+    )
+}
+
+/// Carries out the `window_move_request`s queued for `machine` and reports each outcome to the server.
+///
+/// `mover` is shared so the connection that replaces a dropped one can use it too.
+pub fn handle_window_moves(
+    connection: &DbConnection,
+    machine: &str,
+    mover: Arc<Mutex<Box<dyn WindowMover + Send>>>,
+) {
+    let owned_machine = machine.to_string();
+    connection.db.window_move_request().on_insert(
+        move |ctx: &EventContext, request: &WindowMoveRequest| {
+            if request.machine != owned_machine {
+                return;
+            }
+            let Ok(mut mover) = mover.lock() else {
+                tracing::error!("Window mover lock poisoned");
+                return;
+            };
+            let result = ctx
+                .db
+                .taskbar()
+                .id()
+                .find(&request.target_taskbar_id)
+                .ok_or_eyre("Target taskbar is no longer known")
+                .and_then(|target| move_window(mover.as_mut(), request.window_id, target.into()));
+            let error = result.err().map(|e| {
+                tracing::warn!("Failed to move window {:#x}: {e}", request.window_id);
+                e.to_string()
+            });
+            if let Err(e) = ctx.reducers.report_window_move(request.id, error) {
+                tracing::error!("Failed to report window move {}: {e}", request.id);
+            }
+        },
+    );
+    connection
+        .subscription_builder()
+        .on_error(|_ctx: &ErrorContext, err| {
+            tracing::error!("Window move subscription error: {:?}", err)
+        })
+        .subscribe([format!(
+            "SELECT * FROM window_move_request WHERE machine = '{}'",
+            machine.replace('\'', "''")
+        )]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rect(left: i32, top: i32, right: i32, bottom: i32) -> Rect {
+        Rect {
+            left,
+            top,
+            right,
+            bottom,
+        }
+    }
+
+    #[test]
+    fn keeps_the_window_where_it_was_on_a_same_size_work_area() {
+        let from = rect(0, 0, 1920, 1040);
+        let to = rect(1920, 0, 3840, 1040);
+        assert_eq!(
+            relocate(rect(100, 50, 900, 650), from, to).unwrap(),
+            rect(2020, 50, 2820, 650)
+        );
+    }
+
+    #[test]
+    fn scales_the_window_to_a_different_size_work_area() {
+        let from = rect(0, 0, 1920, 1080);
+        let to = rect(-1280, 0, 0, 720);
+        assert_eq!(
+            relocate(rect(960, 540, 1440, 810), from, to).unwrap(),
+            rect(-640, 360, -320, 540)
+        );
+    }
+
+    #[test]
+    fn pulls_a_partly_off_screen_window_inside_the_work_area() {
+        let from = rect(0, 0, 1920, 1040);
+        let to = rect(1920, 0, 3840, 1040);
+        assert_eq!(
+            relocate(rect(1700, -100, 2300, 400), from, to).unwrap(),
+            rect(3240, 0, 3840, 500)
+        );
+        // Larger than the target, so it fills it
+        assert_eq!(
+            relocate(rect(-50, -50, 2000, 1100), from, rect(0, 0, 1280, 720)).unwrap(),
+            rect(0, 0, 1280, 720)
+        );
+    }
+
+    #[test]
+    fn refuses_empty_work_areas() {
+        let window = rect(100, 100, 500, 400);
+        let work_area = rect(0, 0, 1920, 1040);
+        assert!(relocate(window, work_area, rect(0, 0, 0, 0)).is_err());
+        assert!(relocate(window, work_area, rect(1920, 0, 0, 1040)).is_err());
+        assert!(relocate(window, rect(0, 0, 1920, 0), work_area).is_err());
+    }
+}
@@ -0,0 +1,33 @@
+use eyre::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A top-level window as seen by the placement rules.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WindowInfo {
+    pub window_id: u64,
+    pub title: String,
+    /// Executable file name, like `explorer.exe`
+    #[serde(default)]
+    pub process: Option<String>,
+    /// Folder shown by an Explorer window
+    #[serde(default)]
+    pub path: Option<String>,
+}
+
+/// Lists the top-level windows currently open on this machine.
+pub trait WindowSource {
+    fn windows(&mut self) -> Result<Vec<WindowInfo>>;
+}
+
+#[cfg(windows)]
+pub fn live_window_source() -> Result<Box<dyn WindowSource>> {
+    Ok(Box::new(
+        crate::windows_window_source::WindowsWindowSource::new()?,
+    ))
+}
+
+#[cfg(not(windows))]
+pub fn live_window_source() -> Result<Box<dyn WindowSource>> {
+    eyre::bail!("Listing windows is only supported on Windows")
+}
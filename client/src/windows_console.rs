@@ -0,0 +1,46 @@
+use crate::shutdown::Shutdown;
+use eyre::Result;
+use std::sync::OnceLock;
+use std::time::Duration;
+use windows::Win32::Foundation::BOOL;
+use windows::Win32::System::Console::SetConsoleCtrlHandler;
+use windows::Win32::System::Console::CTRL_BREAK_EVENT;
+use windows::Win32::System::Console::CTRL_CLOSE_EVENT;
+use windows::Win32::System::Console::CTRL_C_EVENT;
+use windows::Win32::System::Console::CTRL_LOGOFF_EVENT;
+use windows::Win32::System::Console::CTRL_SHUTDOWN_EVENT;
+
+/// Console handlers take no context, so the one to request lives here.
+static SHUTDOWN: OnceLock<Shutdown> = OnceLock::new();
+
+unsafe extern "system" fn on_console_event(event: u32) -> BOOL {
+    let Some(shutdown) = SHUTDOWN.get() else {
+        return false.into();
+    };
+    match event {
+        CTRL_C_EVENT | CTRL_BREAK_EVENT => {
+            if shutdown.is_requested() {
+                std::process::exit(130);
+            }
+            tracing::info!("Shutting down, press Ctrl+C again to exit at once");
+            shutdown.request();
+            true.into()
+        }
+        CTRL_CLOSE_EVENT | CTRL_LOGOFF_EVENT | CTRL_SHUTDOWN_EVENT => {
+            shutdown.request();
+            // The process is ended when this returns, so give the sync loop the few seconds
+            // Windows allows to leave
+            std::thread::sleep(Duration::from_secs(5));
+            true.into()
+        }
+        _ => false.into(),
+    }
+}
+
+pub fn handle_console_events(shutdown: &Shutdown) -> Result<()> {
+    if SHUTDOWN.set(shutdown.clone()).is_err() {
+        eyre::bail!("Console events are already handled");
+    }
+    unsafe { SetConsoleCtrlHandler(Some(on_console_event), true)? };
+    Ok(())
+}
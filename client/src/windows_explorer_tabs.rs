@@ -0,0 +1,175 @@
+use crate::explorer_tabs::ExplorerShell;
+use crate::explorer_tabs::TabSnapshot;
+use crate::windows_shell::browsers;
+use crate::windows_shell::path_from_url;
+use crate::windows_shell::shell_windows;
+use crate::windows_window_list::top_level_windows;
+use eyre::OptionExt;
+use eyre::Result;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::time::Duration;
+use std::time::Instant;
+use windows::core::w;
+use windows::core::ComInterface;
+use windows::core::BSTR;
+use windows::Win32::Foundation::HWND;
+use windows::Win32::Foundation::LPARAM;
+use windows::Win32::Foundation::WPARAM;
+use windows::Win32::System::Com::IServiceProvider;
+use windows::Win32::UI::Shell::IShellBrowser;
+use windows::Win32::UI::Shell::IShellWindows;
+use windows::Win32::UI::Shell::IWebBrowserApp;
+use windows::Win32::UI::Shell::SID_STopLevelBrowser;
+use windows::Win32::UI::WindowsAndMessaging::FindWindowExW;
+use windows::Win32::UI::WindowsAndMessaging::GetForegroundWindow;
+use windows::Win32::UI::WindowsAndMessaging::PostMessageW;
+use windows::Win32::UI::WindowsAndMessaging::WM_CLOSE;
+use windows::Win32::UI::WindowsAndMessaging::WM_COMMAND;
+
+/// Undocumented Explorer command behind "Open in new tab", sent to the active tab.
+const NEW_TAB_COMMAND: usize = 0xA21B;
+
+/// Each tab of a Windows 11 Explorer window is its own browser in `ShellWindows`.
+pub struct WindowsExplorerShell {
+    shell_windows: IShellWindows,
+}
+
+struct Tab {
+    window: HWND,
+    tab: HWND,
+    browser: IWebBrowserApp,
+}
+
+impl WindowsExplorerShell {
+    pub fn new() -> Result<Self> {
+        Ok(WindowsExplorerShell {
+            shell_windows: shell_windows()?,
+        })
+    }
+
+    fn open_tabs(&self) -> Result<Vec<Tab>> {
+        Ok(browsers(&self.shell_windows)?
+            .into_iter()
+            .filter_map(|browser| {
+                let window = unsafe { browser.HWND() }.ok()?;
+                let tab = tab_window(&browser).ok()?;
+                Some(Tab {
+                    window: HWND(window.0),
+                    tab,
+                    browser,
+                })
+            })
+            .collect())
+    }
+}
+
+/// The `ShellTabWindowClass` window hosting a browser.
+fn tab_window(browser: &IWebBrowserApp) -> Result<HWND> {
+    unsafe {
+        let shell_browser = browser
+            .cast::<IServiceProvider>()?
+            .QueryService::<IShellBrowser>(&SID_STopLevelBrowser)?;
+        Ok(shell_browser.GetWindow()?)
+    }
+}
+
+/// The selected tab of an Explorer window, which is the first tab in z-order.
+fn active_tab(window: HWND) -> HWND {
+    unsafe { FindWindowExW(window, None, w!("ShellTabWindowClass"), None) }
+}
+
+impl ExplorerShell for WindowsExplorerShell {
+    fn tabs(&mut self) -> Result<Vec<TabSnapshot>> {
+        let open_tabs = self.open_tabs()?;
+        // Windows are listed top to bottom in z-order, so the most recently focused comes first
+        let explorer_windows = open_tabs.iter().map(|x| x.window.0).collect::<HashSet<_>>();
+        let focus_ranks = top_level_windows()?
+            .into_iter()
+            .map(|x| x.hwnd.0)
+            .filter(|x| explorer_windows.contains(x))
+            .enumerate()
+            .map(|(rank, hwnd)| (hwnd, rank as u32))
+            .collect::<HashMap<_, _>>();
+        let mut tabs = Vec::new();
+        for tab in open_tabs {
+            let (Ok(url), Ok(title)) =
+                (unsafe { (tab.browser.LocationURL(), tab.browser.LocationName()) })
+            else {
+                continue;
+            };
+            tabs.push(TabSnapshot {
+                window_id: tab.window.0 as u64,
+                tab_id: tab.tab.0 as u64,
+                path: path_from_url(&url.to_string()),
+                title: title.to_string(),
+                active: active_tab(tab.window) == tab.tab,
+                focus_rank: focus_ranks.get(&tab.window.0).copied().unwrap_or(u32::MAX),
+            });
+        }
+        Ok(tabs)
+    }
+
+    fn open_tab(&mut self, window_id: u64, path: &str) -> Result<()> {
+        let window = HWND(window_id as isize);
+        let before = self
+            .open_tabs()?
+            .into_iter()
+            .map(|x| x.tab.0)
+            .collect::<HashSet<_>>();
+        let active = active_tab(window);
+        eyre::ensure!(
+            active.0 != 0,
+            "Window {window_id:#x} is not an Explorer window"
+        );
+        unsafe { PostMessageW(active, WM_COMMAND, WPARAM(NEW_TAB_COMMAND), LPARAM(0))? };
+
+        // The new tab shows up in ShellWindows once Explorer has created it
+        let started = Instant::now();
+        let tab = loop {
+            if let Some(tab) = self
+                .open_tabs()?
+                .into_iter()
+                .find(|x| x.window == window && !before.contains(&x.tab.0))
+            {
+                break tab;
+            }
+            eyre::ensure!(
+                started.elapsed() < Duration::from_secs(5),
+                "Window {window_id:#x} did not open a new tab"
+            );
+            std::thread::sleep(Duration::from_millis(100));
+        };
+        unsafe {
+            tab.browser
+                .Navigate(&BSTR::from(path), None, None, None, None)?
+        };
+        Ok(())
+    }
+
+    /// Closes the tab's own `ShellTabWindowClass` window, as `IWebBrowserApp::Quit` would close
+    /// every tab of its Explorer window.
+    fn close_tab(&mut self, tab_id: u64) -> Result<()> {
+        let tab = self
+            .open_tabs()?
+            .into_iter()
+            .find(|x| x.tab.0 as u64 == tab_id)
+            .ok_or_eyre(format!("No Explorer tab {tab_id:#x}"))?;
+        unsafe { PostMessageW(tab.tab, WM_CLOSE, WPARAM(0), LPARAM(0))? };
+
+        let started = Instant::now();
+        while self.open_tabs()?.iter().any(|x| x.tab.0 as u64 == tab_id) {
+            eyre::ensure!(
+                started.elapsed() < Duration::from_secs(5),
+                "Explorer tab {tab_id:#x} did not close"
+            );
+            std::thread::sleep(Duration::from_millis(100));
+        }
+        Ok(())
+    }
+
+    fn foreground_window(&self) -> Option<u64> {
+        let hwnd = unsafe { GetForegroundWindow() };
+        (hwnd.0 != 0).then_some(hwnd.0 as u64)
+    }
+}
@@ -0,0 +1,113 @@
+use crate::focus_events::FocusEvent;
+use crate::focus_events::FocusEventSource;
+use crate::window_source::WindowInfo;
+use crate::window_source::WindowSource;
+use crate::windows_window_list::window_title;
+use crate::windows_window_source::process_name;
+use crate::windows_window_source::WindowsWindowSource;
+use eyre::Result;
+use std::sync::mpsc::Receiver;
+use std::sync::mpsc::Sender;
+use std::sync::OnceLock;
+use std::time::SystemTime;
+use windows::Win32::Foundation::HWND;
+use windows::Win32::UI::Accessibility::SetWinEventHook;
+use windows::Win32::UI::Accessibility::HWINEVENTHOOK;
+use windows::Win32::UI::WindowsAndMessaging::DispatchMessageW;
+use windows::Win32::UI::WindowsAndMessaging::GetForegroundWindow;
+use windows::Win32::UI::WindowsAndMessaging::GetMessageW;
+use windows::Win32::UI::WindowsAndMessaging::EVENT_SYSTEM_FOREGROUND;
+use windows::Win32::UI::WindowsAndMessaging::MSG;
+use windows::Win32::UI::WindowsAndMessaging::OBJID_WINDOW;
+use windows::Win32::UI::WindowsAndMessaging::WINEVENT_OUTOFCONTEXT;
+use windows::Win32::UI::WindowsAndMessaging::WINEVENT_SKIPOWNPROCESS;
+
+/// Where the hook callback, which gets no context pointer, sends foreground changes.
+static FOREGROUND_CHANGES: OnceLock<Sender<(isize, SystemTime)>> = OnceLock::new();
+
+/// Hears about foreground changes from a `EVENT_SYSTEM_FOREGROUND` hook on a thread of its own.
+pub struct WindowsFocusSource {
+    changes: Receiver<(isize, SystemTime)>,
+    windows: WindowsWindowSource,
+}
+
+impl WindowsFocusSource {
+    pub fn new() -> Result<Self> {
+        let (sender, changes) = std::sync::mpsc::channel();
+        // The window that already has focus is the first change
+        let _ = sender.send((unsafe { GetForegroundWindow() }.0, SystemTime::now()));
+        FOREGROUND_CHANGES
+            .set(sender)
+            .map_err(|_| eyre::eyre!("Only one focus source can run at a time"))?;
+        std::thread::spawn(|| unsafe {
+            let hook = SetWinEventHook(
+                EVENT_SYSTEM_FOREGROUND,
+                EVENT_SYSTEM_FOREGROUND,
+                None,
+                Some(on_foreground),
+                0,
+                0,
+                WINEVENT_OUTOFCONTEXT | WINEVENT_SKIPOWNPROCESS,
+            );
+            if hook.0 == 0 {
+                tracing::error!("Failed to hook foreground changes");
+                return;
+            }
+            // Out-of-context hooks are called from this thread's message loop
+            let mut message = MSG::default();
+            while GetMessageW(&mut message, None, 0, 0).as_bool() {
+                DispatchMessageW(&message);
+            }
+        });
+        Ok(WindowsFocusSource {
+            changes,
+            windows: WindowsWindowSource::new()?,
+        })
+    }
+}
+
+unsafe extern "system" fn on_foreground(
+    _hook: HWINEVENTHOOK,
+    _event: u32,
+    hwnd: HWND,
+    id_object: i32,
+    _id_child: i32,
+    _thread: u32,
+    _time: u32,
+) {
+    if id_object != OBJID_WINDOW.0 {
+        return;
+    }
+    if let Some(sender) = FOREGROUND_CHANGES.get() {
+        let _ = sender.send((hwnd.0, SystemTime::now()));
+    }
+}
+
+impl FocusEventSource for WindowsFocusSource {
+    fn poll(&mut self) -> Result<Vec<FocusEvent>> {
+        let changes = self.changes.try_iter().collect::<Vec<_>>();
+        if changes.is_empty() {
+            return Ok(Vec::new());
+        }
+        // Looked up now rather than when focus changed, so a title may have moved on since
+        let windows = self.windows.windows()?;
+        Ok(changes
+            .into_iter()
+            .map(|(hwnd, at)| {
+                let window = (hwnd != 0).then(|| {
+                    windows
+                        .iter()
+                        .find(|x| x.window_id == hwnd as u64)
+                        .cloned()
+                        .unwrap_or_else(|| WindowInfo {
+                            window_id: hwnd as u64,
+                            title: window_title(HWND(hwnd)).unwrap_or_default(),
+                            process: process_name(HWND(hwnd)),
+                            path: None,
+                        })
+                });
+                FocusEvent { window, at }
+            })
+            .collect())
+    }
+}
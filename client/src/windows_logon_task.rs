@@ -0,0 +1,239 @@
+use crate::logon_task::task_xml;
+use crate::service::ServiceBody;
+use crate::service::ServiceController;
+use crate::service::SERVICE_NAME;
+use crate::shutdown::Ending;
+use crate::shutdown::Shutdown;
+use eyre::Context;
+use eyre::Result;
+use std::ffi::OsStr;
+use std::process::Command;
+use std::sync::OnceLock;
+use std::time::Duration;
+use windows::core::w;
+use windows::core::HSTRING;
+use windows::Win32::Foundation::CloseHandle;
+use windows::Win32::Foundation::GetLastError;
+use windows::Win32::Foundation::ERROR_ALREADY_EXISTS;
+use windows::Win32::Foundation::HWND;
+use windows::Win32::Foundation::LPARAM;
+use windows::Win32::Foundation::LRESULT;
+use windows::Win32::Foundation::WAIT_OBJECT_0;
+use windows::Win32::Foundation::WPARAM;
+use windows::Win32::System::Console::FreeConsole;
+use windows::Win32::System::Threading::CreateEventW;
+use windows::Win32::System::Threading::OpenEventW;
+use windows::Win32::System::Threading::SetEvent;
+use windows::Win32::System::Threading::WaitForSingleObject;
+use windows::Win32::System::Threading::EVENT_MODIFY_STATE;
+use windows::Win32::System::Threading::INFINITE;
+use windows::Win32::UI::WindowsAndMessaging::CreateWindowExW;
+use windows::Win32::UI::WindowsAndMessaging::DefWindowProcW;
+use windows::Win32::UI::WindowsAndMessaging::DispatchMessageW;
+use windows::Win32::UI::WindowsAndMessaging::GetMessageW;
+use windows::Win32::UI::WindowsAndMessaging::RegisterClassW;
+use windows::Win32::UI::WindowsAndMessaging::MSG;
+use windows::Win32::UI::WindowsAndMessaging::WINDOW_EX_STYLE;
+use windows::Win32::UI::WindowsAndMessaging::WINDOW_STYLE;
+use windows::Win32::UI::WindowsAndMessaging::WM_ENDSESSION;
+use windows::Win32::UI::WindowsAndMessaging::WNDCLASSW;
+
+/// Set by `service stop` to ask the task running in the same session to leave and exit.
+const STOP_EVENT: &str = r"Local\explorer-wrangler-stop";
+
+/// The shutdown the session window requests when the user signs out, and the one `run` requests
+/// once the body returned. Window procedures take no context, so they live here.
+static SESSION_END: OnceLock<(Shutdown, Shutdown)> = OnceLock::new();
+
+unsafe extern "system" fn on_session_message(
+    window: HWND,
+    message: u32,
+    wparam: WPARAM,
+    lparam: LPARAM,
+) -> LRESULT {
+    if message != WM_ENDSESSION || wparam.0 == 0 {
+        return DefWindowProcW(window, message, wparam, lparam);
+    }
+    if let Some((shutdown, finished)) = SESSION_END.get() {
+        tracing::info!("Session ending");
+        shutdown.request();
+        // The process is ended when this returns, so give the sync loop the few seconds Windows
+        // allows to leave
+        finished.wait(Duration::from_secs(5));
+    }
+    LRESULT(0)
+}
+
+/// Hears about the user signing out or the machine shutting down from a hidden window.
+///
+/// Console control events don't reach a process without a console, and `CTRL_LOGOFF_EVENT` only
+/// reaches those without windows anyway, so `WM_ENDSESSION` is the way to hear about it.
+fn watch_session_end(shutdown: &Shutdown, finished: &Shutdown) -> Result<()> {
+    if SESSION_END
+        .set((shutdown.clone(), finished.clone()))
+        .is_err()
+    {
+        eyre::bail!("The end of the session is already watched");
+    }
+    std::thread::spawn(|| unsafe {
+        let class = WNDCLASSW {
+            lpfnWndProc: Some(on_session_message),
+            lpszClassName: w!("explorer-wrangler-session"),
+            ..Default::default()
+        };
+        if RegisterClassW(&class) == 0 {
+            tracing::error!("Failed to register the session window class");
+            return;
+        }
+        // Message-only windows miss the session messages, which are broadcast to top-level ones
+        let window = CreateWindowExW(
+            WINDOW_EX_STYLE::default(),
+            w!("explorer-wrangler-session"),
+            w!("explorer-wrangler"),
+            WINDOW_STYLE::default(),
+            0,
+            0,
+            0,
+            0,
+            None,
+            None,
+            None,
+            None,
+        );
+        if window.0 == 0 {
+            tracing::error!("Failed to create the session window");
+            return;
+        }
+        let mut message = MSG::default();
+        while GetMessageW(&mut message, None, 0, 0).as_bool() {
+            DispatchMessageW(&message);
+        }
+    });
+    Ok(())
+}
+
+/// Runs `body` until `service stop` sets the stop event or the session ends.
+pub fn run(body: ServiceBody) -> Result<Ending> {
+    // Task Scheduler gives the task a console window, which only gets in the user's way
+    let _ = unsafe { FreeConsole() };
+    let event = unsafe { CreateEventW(None, true, false, &HSTRING::from(STOP_EVENT))? };
+    if unsafe { GetLastError() }.is_err_and(|e| e.code() == ERROR_ALREADY_EXISTS.to_hresult()) {
+        eyre::bail!("The client already runs in the background in this session");
+    }
+    let shutdown = Shutdown::default();
+    let stop = shutdown.clone();
+    // The event is closed when the process exits
+    std::thread::spawn(move || {
+        if unsafe { WaitForSingleObject(event, INFINITE) } == WAIT_OBJECT_0 {
+            tracing::info!("Stop requested");
+            stop.request();
+        }
+    });
+    let finished = Shutdown::default();
+    watch_session_end(&shutdown, &finished)?;
+    let ending = body(shutdown);
+    finished.request();
+    ending
+}
+
+fn schtasks(args: &[&OsStr]) -> Result<()> {
+    let status = Command::new("schtasks")
+        .args(args)
+        .status()
+        .wrap_err("Failed to run schtasks")?;
+    eyre::ensure!(
+        status.success(),
+        "schtasks {} failed with {status}",
+        args.join(OsStr::new(" ")).to_string_lossy()
+    );
+    Ok(())
+}
+
+fn current_user() -> Result<String> {
+    let user = std::env::var("USERNAME").wrap_err("USERNAME is not set")?;
+    Ok(match std::env::var("USERDOMAIN") {
+        Ok(domain) => format!(r"{domain}\{user}"),
+        Err(_) => user,
+    })
+}
+
+fn task(args: &[String]) -> Result<String> {
+    let executable = std::env::current_exe()?;
+    Ok(task_xml(
+        &current_user()?,
+        &executable.to_string_lossy(),
+        args,
+    ))
+}
+
+/// Registers the client as a Task Scheduler task that starts when the current user signs in.
+///
+/// Services run in session 0, away from the user's desktop and taskbars, so the client runs in the
+/// user's session as the user instead.
+pub struct WindowsTaskController;
+
+impl ServiceController for WindowsTaskController {
+    fn install(&self, args: &[String]) -> Result<()> {
+        let path = std::env::temp_dir().join(format!("{SERVICE_NAME}-task.xml"));
+        // schtasks reads UTF-16 reliably, as the task's encoding declaration says
+        let xml = [0xFF, 0xFE]
+            .into_iter()
+            .chain(task(args)?.encode_utf16().flat_map(u16::to_le_bytes))
+            .collect::<Vec<u8>>();
+        std::fs::write(&path, xml)
+            .wrap_err_with(|| format!("Failed to write {}", path.display()))?;
+        let created = schtasks(&[
+            OsStr::new("/Create"),
+            OsStr::new("/TN"),
+            OsStr::new(SERVICE_NAME),
+            OsStr::new("/XML"),
+            path.as_os_str(),
+            OsStr::new("/F"),
+        ]);
+        let _ = std::fs::remove_file(&path);
+        created?;
+        tracing::info!("Installed the {SERVICE_NAME} task, it starts when you sign in");
+        Ok(())
+    }
+
+    fn describe(&self, args: &[String]) -> Result<String> {
+        Ok(format!(
+            "<!-- Task Scheduler task {SERVICE_NAME} -->\n{}",
+            task(args)?
+        ))
+    }
+
+    fn uninstall(&self) -> Result<()> {
+        if let Err(e) = self.stop() {
+            tracing::debug!("Not stopping the task: {e}");
+        }
+        schtasks(&[
+            OsStr::new("/Delete"),
+            OsStr::new("/TN"),
+            OsStr::new(SERVICE_NAME),
+            OsStr::new("/F"),
+        ])?;
+        tracing::info!("Uninstalled the {SERVICE_NAME} task");
+        Ok(())
+    }
+
+    fn start(&self) -> Result<()> {
+        schtasks(&[
+            OsStr::new("/Run"),
+            OsStr::new("/TN"),
+            OsStr::new(SERVICE_NAME),
+        ])
+    }
+
+    /// Asks the task to leave the servers and exit, which `schtasks /End` would not let it do.
+    fn stop(&self) -> Result<()> {
+        let event = unsafe {
+            OpenEventW(EVENT_MODIFY_STATE, false, &HSTRING::from(STOP_EVENT))
+                .wrap_err("The client is not running in the background in this session")?
+        };
+        let set = unsafe { SetEvent(event) };
+        unsafe { CloseHandle(event)? };
+        set?;
+        Ok(())
+    }
+}
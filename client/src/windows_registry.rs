@@ -0,0 +1,107 @@
+use eyre::Result;
+use windows::core::PCWSTR;
+use windows::Win32::Foundation::ERROR_FILE_NOT_FOUND;
+use windows::Win32::System::Registry::RegGetValueW;
+use windows::Win32::System::Registry::RegSetKeyValueW;
+use windows::Win32::System::Registry::HKEY;
+use windows::Win32::System::Registry::REG_BINARY;
+use windows::Win32::System::Registry::REG_DWORD;
+use windows::Win32::System::Registry::REG_ROUTINE_FLAGS;
+use windows::Win32::System::Registry::RRF_RT_REG_BINARY;
+use windows::Win32::System::Registry::RRF_RT_REG_DWORD;
+use windows::Win32::System::Registry::RRF_RT_REG_SZ;
+
+/// Returns `None` when the value is not set, which for most shell settings means "use the default".
+pub fn read_dword(key: HKEY, subkey: PCWSTR, value: PCWSTR) -> Result<Option<u32>> {
+    let mut data = 0u32;
+    let mut len = std::mem::size_of::<u32>() as u32;
+    let result = unsafe {
+        RegGetValueW(
+            key,
+            subkey,
+            value,
+            RRF_RT_REG_DWORD,
+            None,
+            Some((&mut data as *mut u32).cast()),
+            Some(&mut len),
+        )
+    };
+    match result {
+        Ok(()) => Ok(Some(data)),
+        Err(e) if e.code() == ERROR_FILE_NOT_FOUND.to_hresult() => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+pub fn read_binary(key: HKEY, subkey: PCWSTR, value: PCWSTR) -> Result<Option<Vec<u8>>> {
+    read_bytes(key, subkey, value, RRF_RT_REG_BINARY)
+}
+
+pub fn read_string(key: HKEY, subkey: PCWSTR, value: PCWSTR) -> Result<Option<String>> {
+    let Some(bytes) = read_bytes(key, subkey, value, RRF_RT_REG_SZ)? else {
+        return Ok(None);
+    };
+    let wide = bytes
+        .chunks_exact(2)
+        .map(|x| u16::from_le_bytes([x[0], x[1]]))
+        .take_while(|x| *x != 0)
+        .collect::<Vec<_>>();
+    Ok(Some(String::from_utf16(&wide)?))
+}
+
+fn read_bytes(
+    key: HKEY,
+    subkey: PCWSTR,
+    value: PCWSTR,
+    flags: REG_ROUTINE_FLAGS,
+) -> Result<Option<Vec<u8>>> {
+    let mut len = 0u32;
+    let result = unsafe { RegGetValueW(key, subkey, value, flags, None, None, Some(&mut len)) };
+    match result {
+        Ok(()) => {}
+        Err(e) if e.code() == ERROR_FILE_NOT_FOUND.to_hresult() => return Ok(None),
+        Err(e) => return Err(e.into()),
+    }
+    let mut data = vec![0u8; len as usize];
+    unsafe {
+        RegGetValueW(
+            key,
+            subkey,
+            value,
+            flags,
+            None,
+            Some(data.as_mut_ptr().cast()),
+            Some(&mut len),
+        )?;
+    }
+    data.truncate(len as usize);
+    Ok(Some(data))
+}
+
+pub fn write_dword(key: HKEY, subkey: PCWSTR, value: PCWSTR, data: u32) -> Result<()> {
+    unsafe {
+        RegSetKeyValueW(
+            key,
+            subkey,
+            value,
+            REG_DWORD.0,
+            Some((&data as *const u32).cast()),
+            std::mem::size_of::<u32>() as u32,
+        )?;
+    }
+    Ok(())
+}
+
+pub fn write_binary(key: HKEY, subkey: PCWSTR, value: PCWSTR, data: &[u8]) -> Result<()> {
+    unsafe {
+        RegSetKeyValueW(
+            key,
+            subkey,
+            value,
+            REG_BINARY.0,
+            Some(data.as_ptr().cast()),
+            data.len() as u32,
+        )?;
+    }
+    Ok(())
+}
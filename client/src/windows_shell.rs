@@ -0,0 +1,62 @@
+use eyre::Result;
+use std::mem::ManuallyDrop;
+use windows::core::ComInterface;
+use windows::core::HSTRING;
+use windows::core::PWSTR;
+use windows::Win32::System::Com::CoCreateInstance;
+use windows::Win32::System::Com::CoInitializeEx;
+use windows::Win32::System::Com::CLSCTX_ALL;
+use windows::Win32::System::Com::COINIT_APARTMENTTHREADED;
+use windows::Win32::System::Variant::VARIANT;
+use windows::Win32::System::Variant::VARIANT_0;
+use windows::Win32::System::Variant::VARIANT_0_0;
+use windows::Win32::System::Variant::VARIANT_0_0_0;
+use windows::Win32::System::Variant::VT_I4;
+use windows::Win32::UI::Shell::IShellWindows;
+use windows::Win32::UI::Shell::IWebBrowserApp;
+use windows::Win32::UI::Shell::PathCreateFromUrlW;
+use windows::Win32::UI::Shell::ShellWindows;
+
+/// The shell's list of open Explorer browsers, one per tab on Windows 11.
+pub fn shell_windows() -> Result<IShellWindows> {
+    unsafe {
+        // Fails harmlessly if COM is already initialized on this thread
+        let _ = CoInitializeEx(None, COINIT_APARTMENTTHREADED);
+        Ok(CoCreateInstance(&ShellWindows, None, CLSCTX_ALL)?)
+    }
+}
+
+/// Every Explorer browser currently open, skipping any that close while being listed.
+pub fn browsers(shell_windows: &IShellWindows) -> Result<Vec<IWebBrowserApp>> {
+    let count = unsafe { shell_windows.Count()? };
+    Ok((0..count)
+        .filter_map(|i| unsafe { shell_windows.Item(int_variant(i)) }.ok())
+        .filter_map(|x| x.cast::<IWebBrowserApp>().ok())
+        .collect())
+}
+
+fn int_variant(value: i32) -> VARIANT {
+    VARIANT {
+        Anonymous: VARIANT_0 {
+            Anonymous: ManuallyDrop::new(VARIANT_0_0 {
+                vt: VT_I4,
+                wReserved1: 0,
+                wReserved2: 0,
+                wReserved3: 0,
+                Anonymous: VARIANT_0_0_0 { lVal: value },
+            }),
+        },
+    }
+}
+
+/// Turns a `file:///C:/Users` URL into `C:\Users`, `None` for virtual folders like "This PC".
+pub fn path_from_url(url: &str) -> Option<String> {
+    if !url.starts_with("file:") {
+        return None;
+    }
+    let url = HSTRING::from(url);
+    let mut buf = [0u16; 1024];
+    let mut len = buf.len() as u32;
+    unsafe { PathCreateFromUrlW(&url, PWSTR(buf.as_mut_ptr()), &mut len, 0) }.ok()?;
+    String::from_utf16(&buf[..len as usize]).ok()
+}
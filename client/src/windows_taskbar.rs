@@ -1,23 +1,38 @@
-use crate::module_bindings::Taskbar;
+use crate::taskbar_snapshot::TaskbarSettings;
+use crate::taskbar_snapshot::TaskbarSnapshot;
 
 #[derive(Debug)]
 pub struct WindowsTaskbar {
+    pub machine: String,
     pub id: u32,
     pub rect: windows::Win32::Foundation::RECT,
     pub is_secondary: bool,
-    pub apps: Vec<String>
+    pub apps: Vec<String>,
+    pub settings: TaskbarSettings,
 }
 
-impl From<WindowsTaskbar> for Taskbar {
+impl From<WindowsTaskbar> for TaskbarSnapshot {
     fn from(value: WindowsTaskbar) -> Self {
-        Taskbar {
-            id: value.id,
+        TaskbarSnapshot {
+            machine: value.machine,
+            index: value.id,
+            rect: value.rect.into(),
             is_secondary: value.is_secondary,
-            x: value.rect.left,
-            y: value.rect.top,
-            width: (value.rect.right - value.rect.left) as u32,
-            height: (value.rect.bottom - value.rect.top) as u32,
             apps: value.apps,
+            settings: value.settings,
+        }
+    }
+}
+
+impl From<TaskbarSnapshot> for WindowsTaskbar {
+    fn from(value: TaskbarSnapshot) -> Self {
+        WindowsTaskbar {
+            machine: value.machine,
+            id: value.index,
+            rect: value.rect.into(),
+            is_secondary: value.is_secondary,
+            apps: value.apps,
+            settings: value.settings,
         }
     }
 }
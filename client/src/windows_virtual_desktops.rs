@@ -0,0 +1,136 @@
+use crate::virtual_desktops::DesktopSnapshot;
+use crate::virtual_desktops::VirtualDesktopCollector;
+use crate::virtual_desktops::VirtualDesktopSnapshot;
+use crate::virtual_desktops::WindowSnapshot;
+use crate::windows_registry::read_binary;
+use crate::windows_registry::read_string;
+use crate::windows_window_list::top_level_windows;
+use eyre::Result;
+use windows::core::w;
+use windows::core::GUID;
+use windows::core::HSTRING;
+use windows::core::PCWSTR;
+use windows::Win32::System::Com::CoCreateInstance;
+use windows::Win32::System::Com::CoInitializeEx;
+use windows::Win32::System::Com::CLSCTX_ALL;
+use windows::Win32::System::Com::COINIT_APARTMENTTHREADED;
+use windows::Win32::System::Registry::HKEY_CURRENT_USER;
+use windows::Win32::UI::Shell::IVirtualDesktopManager;
+use windows::Win32::UI::Shell::VirtualDesktopManager;
+
+const VIRTUAL_DESKTOPS: PCWSTR =
+    w!("Software\\Microsoft\\Windows\\CurrentVersion\\Explorer\\VirtualDesktops");
+
+/// Lists desktops from the registry Explorer keeps them in, and asks the shell which desktop each window is on.
+pub struct WindowsDesktopCollector {
+    manager: IVirtualDesktopManager,
+}
+
+impl WindowsDesktopCollector {
+    pub fn new() -> Result<Self> {
+        let manager = unsafe {
+            // Fails harmlessly if COM is already initialized on this thread
+            let _ = CoInitializeEx(None, COINIT_APARTMENTTHREADED);
+            CoCreateInstance(&VirtualDesktopManager, None, CLSCTX_ALL)?
+        };
+        Ok(WindowsDesktopCollector { manager })
+    }
+}
+
+impl VirtualDesktopCollector for WindowsDesktopCollector {
+    fn collect(&mut self) -> Result<VirtualDesktopSnapshot> {
+        let ids = read_binary(HKEY_CURRENT_USER, VIRTUAL_DESKTOPS, w!("VirtualDesktopIDs"))?
+            .unwrap_or_default();
+        let current = read_binary(
+            HKEY_CURRENT_USER,
+            VIRTUAL_DESKTOPS,
+            w!("CurrentVirtualDesktop"),
+        )?
+        .and_then(|x| parse_guids(&x).into_iter().next());
+        let desktops = parse_guids(&ids)
+            .into_iter()
+            .enumerate()
+            .map(|(i, guid)| {
+                let key = HSTRING::from(format!(
+                    "Software\\Microsoft\\Windows\\CurrentVersion\\Explorer\\VirtualDesktops\\Desktops\\{}",
+                    guid_string(&guid)
+                ));
+                let name = read_string(HKEY_CURRENT_USER, PCWSTR(key.as_ptr()), w!("Name"))?
+                    .unwrap_or_else(|| format!("Desktop {}", i + 1));
+                Ok(DesktopSnapshot {
+                    guid: guid_string(&guid),
+                    name,
+                    is_current: current == Some(guid),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let windows = top_level_windows()?
+            .into_iter()
+            .map(|window| {
+                let desktop = unsafe { self.manager.GetWindowDesktopId(window.hwnd) }
+                    .ok()
+                    .filter(|x| *x != GUID::zeroed());
+                WindowSnapshot {
+                    window_id: window.hwnd.0 as u64,
+                    title: window.title,
+                    desktop_guid: desktop.as_ref().map(guid_string),
+                }
+            })
+            .collect();
+        Ok(VirtualDesktopSnapshot { desktops, windows })
+    }
+}
+
+/// The registry form of `guid`, like `{0A1B2C3D-4E5F-6071-8293-A4B5C6D7E8F9}`, which the server
+/// matches desktops by.
+fn guid_string(guid: &GUID) -> String {
+    format!(
+        "{{{:08X}-{:04X}-{:04X}-{:02X}{:02X}-{}}}",
+        guid.data1,
+        guid.data2,
+        guid.data3,
+        guid.data4[0],
+        guid.data4[1],
+        guid.data4[2..]
+            .iter()
+            .map(|x| format!("{x:02X}"))
+            .collect::<String>()
+    )
+}
+
+/// Splits a registry blob of packed 16-byte GUIDs.
+fn parse_guids(bytes: &[u8]) -> Vec<GUID> {
+    bytes
+        .chunks_exact(16)
+        .map(|x| {
+            GUID::from_values(
+                u32::from_le_bytes([x[0], x[1], x[2], x[3]]),
+                u16::from_le_bytes([x[4], x[5]]),
+                u16::from_le_bytes([x[6], x[7]]),
+                [x[8], x[9], x[10], x[11], x[12], x[13], x[14], x[15]],
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_guids_as_the_registry_does() {
+        let bytes = [
+            0x3D, 0x2C, 0x1B, 0x0A, 0x5F, 0x4E, 0x71, 0x60, 0x82, 0x93, 0xA4, 0xB5, 0xC6, 0xD7,
+            0xE8, 0xF9,
+        ];
+        let guids = parse_guids(&bytes);
+        assert_eq!(
+            guids.iter().map(guid_string).collect::<Vec<_>>(),
+            ["{0A1B2C3D-4E5F-6071-8293-A4B5C6D7E8F9}"]
+        );
+        assert_eq!(
+            guid_string(&GUID::zeroed()),
+            "{00000000-0000-0000-0000-000000000000}"
+        );
+    }
+}
@@ -0,0 +1,53 @@
+use eyre::Result;
+use windows::Win32::Foundation::BOOL;
+use windows::Win32::Foundation::HWND;
+use windows::Win32::Foundation::LPARAM;
+use windows::Win32::UI::WindowsAndMessaging::EnumWindows;
+use windows::Win32::UI::WindowsAndMessaging::GetWindow;
+use windows::Win32::UI::WindowsAndMessaging::GetWindowLongPtrW;
+use windows::Win32::UI::WindowsAndMessaging::GetWindowTextLengthW;
+use windows::Win32::UI::WindowsAndMessaging::GetWindowTextW;
+use windows::Win32::UI::WindowsAndMessaging::IsWindowVisible;
+use windows::Win32::UI::WindowsAndMessaging::GWL_EXSTYLE;
+use windows::Win32::UI::WindowsAndMessaging::GW_OWNER;
+use windows::Win32::UI::WindowsAndMessaging::WS_EX_TOOLWINDOW;
+
+/// A visible, unowned, titled top-level window, the kind that gets a taskbar button.
+#[derive(Debug, Clone)]
+pub struct TopLevelWindow {
+    pub hwnd: HWND,
+    pub title: String,
+}
+
+pub fn top_level_windows() -> Result<Vec<TopLevelWindow>> {
+    let mut windows = Vec::new();
+    unsafe {
+        EnumWindows(
+            Some(enum_windows_proc),
+            LPARAM(&mut windows as *mut Vec<TopLevelWindow> as isize),
+        )?;
+    }
+    Ok(windows)
+}
+
+unsafe extern "system" fn enum_windows_proc(hwnd: HWND, lparam: LPARAM) -> BOOL {
+    let windows: &mut Vec<TopLevelWindow> = &mut *(lparam.0 as *mut Vec<TopLevelWindow>);
+    let tool_window = GetWindowLongPtrW(hwnd, GWL_EXSTYLE) as u32 & WS_EX_TOOLWINDOW.0 != 0;
+    if !IsWindowVisible(hwnd).as_bool() || GetWindow(hwnd, GW_OWNER).0 != 0 || tool_window {
+        return true.into();
+    }
+    if let Some(title) = window_title(hwnd) {
+        windows.push(TopLevelWindow { hwnd, title });
+    }
+    true.into()
+}
+
+pub fn window_title(hwnd: HWND) -> Option<String> {
+    let len = unsafe { GetWindowTextLengthW(hwnd) };
+    if len <= 0 {
+        return None;
+    }
+    let mut buf = vec![0u16; len as usize + 1];
+    let len = unsafe { GetWindowTextW(hwnd, &mut buf) };
+    String::from_utf16(&buf[..len as usize]).ok()
+}
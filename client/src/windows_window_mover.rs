@@ -0,0 +1,115 @@
+use crate::taskbar_snapshot::Rect;
+use crate::window_move::Monitor;
+use crate::window_move::WindowMover;
+use crate::window_move::WindowPlacement;
+use crate::window_move::WindowState;
+use eyre::Result;
+use windows::Win32::Foundation::HWND;
+use windows::Win32::Foundation::RECT;
+use windows::Win32::Graphics::Gdi::GetMonitorInfoW;
+use windows::Win32::Graphics::Gdi::MonitorFromRect;
+use windows::Win32::Graphics::Gdi::HMONITOR;
+use windows::Win32::Graphics::Gdi::MONITORINFO;
+use windows::Win32::Graphics::Gdi::MONITOR_DEFAULTTONEAREST;
+use windows::Win32::UI::HiDpi::GetDpiForMonitor;
+use windows::Win32::UI::HiDpi::MDT_EFFECTIVE_DPI;
+use windows::Win32::UI::WindowsAndMessaging::GetWindowPlacement;
+use windows::Win32::UI::WindowsAndMessaging::IsWindow;
+use windows::Win32::UI::WindowsAndMessaging::SetWindowPlacement;
+use windows::Win32::UI::WindowsAndMessaging::SW_SHOWMAXIMIZED;
+use windows::Win32::UI::WindowsAndMessaging::SW_SHOWMINIMIZED;
+use windows::Win32::UI::WindowsAndMessaging::SW_SHOWNORMAL;
+use windows::Win32::UI::WindowsAndMessaging::WINDOWPLACEMENT;
+
+pub struct WindowsWindowMover;
+
+impl WindowMover for WindowsWindowMover {
+    fn placement(&self, window_id: u64) -> Result<WindowPlacement> {
+        let (_, placement) = window_placement(window_id)?;
+        // The restored rect is in workspace coordinates, relative to the work area of its monitor
+        let workspace: Rect = placement.rcNormalPosition.into();
+        let offset = workspace_offset(monitor_info(monitor_from_rect(workspace)));
+        let state = match placement.showCmd {
+            x if x == SW_SHOWMAXIMIZED.0 as u32 => WindowState::Maximized,
+            x if x == SW_SHOWMINIMIZED.0 as u32 => WindowState::Minimized,
+            _ => WindowState::Normal,
+        };
+        Ok(WindowPlacement {
+            rect: translate(workspace, offset),
+            state,
+        })
+    }
+
+    fn monitor(&self, rect: Rect) -> Result<Monitor> {
+        let monitor = monitor_from_rect(rect);
+        let (mut dpi_x, mut dpi_y) = (0, 0);
+        unsafe { GetDpiForMonitor(monitor, MDT_EFFECTIVE_DPI, &mut dpi_x, &mut dpi_y)? };
+        Ok(Monitor {
+            rect: monitor_info(monitor).rcMonitor.into(),
+            dpi: dpi_x,
+        })
+    }
+
+    fn work_area(&self, rect: Rect) -> Result<Rect> {
+        Ok(monitor_info(monitor_from_rect(rect)).rcWork.into())
+    }
+
+    fn set_placement(&mut self, window_id: u64, placement: WindowPlacement) -> Result<()> {
+        let (hwnd, mut native) = window_placement(window_id)?;
+        let offset = workspace_offset(monitor_info(monitor_from_rect(placement.rect)));
+        native.rcNormalPosition = translate(placement.rect, (-offset.0, -offset.1)).into();
+        native.showCmd = match placement.state {
+            WindowState::Normal => SW_SHOWNORMAL,
+            WindowState::Minimized => SW_SHOWMINIMIZED,
+            WindowState::Maximized => SW_SHOWMAXIMIZED,
+        }
+        .0 as u32;
+        unsafe { SetWindowPlacement(hwnd, &native)? };
+        Ok(())
+    }
+}
+
+fn window_placement(window_id: u64) -> Result<(HWND, WINDOWPLACEMENT)> {
+    let hwnd = HWND(window_id as isize);
+    eyre::ensure!(
+        unsafe { IsWindow(hwnd) }.as_bool(),
+        "Window {window_id:#x} does not exist"
+    );
+    let mut placement = WINDOWPLACEMENT {
+        length: std::mem::size_of::<WINDOWPLACEMENT>() as u32,
+        ..Default::default()
+    };
+    unsafe { GetWindowPlacement(hwnd, &mut placement)? };
+    Ok((hwnd, placement))
+}
+
+fn monitor_from_rect(rect: Rect) -> HMONITOR {
+    let rect: RECT = rect.into();
+    unsafe { MonitorFromRect(&rect, MONITOR_DEFAULTTONEAREST) }
+}
+
+fn monitor_info(monitor: HMONITOR) -> MONITORINFO {
+    let mut info = MONITORINFO {
+        cbSize: std::mem::size_of::<MONITORINFO>() as u32,
+        ..Default::default()
+    };
+    unsafe { GetMonitorInfoW(monitor, &mut info) };
+    info
+}
+
+/// How far the work area is inset from the monitor's top-left corner.
+fn workspace_offset(info: MONITORINFO) -> (i32, i32) {
+    (
+        info.rcWork.left - info.rcMonitor.left,
+        info.rcWork.top - info.rcMonitor.top,
+    )
+}
+
+fn translate(rect: Rect, (dx, dy): (i32, i32)) -> Rect {
+    Rect {
+        left: rect.left + dx,
+        top: rect.top + dy,
+        right: rect.right + dx,
+        bottom: rect.bottom + dy,
+    }
+}
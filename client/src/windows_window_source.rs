@@ -0,0 +1,81 @@
+use crate::window_source::WindowInfo;
+use crate::window_source::WindowSource;
+use crate::windows_shell::browsers;
+use crate::windows_shell::path_from_url;
+use crate::windows_shell::shell_windows;
+use crate::windows_window_list::top_level_windows;
+use eyre::Result;
+use std::collections::HashMap;
+use windows::core::PWSTR;
+use windows::Win32::Foundation::CloseHandle;
+use windows::Win32::Foundation::HWND;
+use windows::Win32::System::Threading::OpenProcess;
+use windows::Win32::System::Threading::QueryFullProcessImageNameW;
+use windows::Win32::System::Threading::PROCESS_NAME_WIN32;
+use windows::Win32::System::Threading::PROCESS_QUERY_LIMITED_INFORMATION;
+use windows::Win32::UI::Shell::IShellWindows;
+use windows::Win32::UI::WindowsAndMessaging::GetWindowThreadProcessId;
+
+/// Top-level windows with their process, and for Explorer windows the folder they show.
+pub struct WindowsWindowSource {
+    shell_windows: IShellWindows,
+}
+
+impl WindowsWindowSource {
+    pub fn new() -> Result<Self> {
+        Ok(WindowsWindowSource {
+            shell_windows: shell_windows()?,
+        })
+    }
+
+    /// Folder paths of the open Explorer windows, keyed by window handle.
+    fn explorer_paths(&self) -> Result<HashMap<isize, String>> {
+        let mut paths = HashMap::new();
+        for browser in browsers(&self.shell_windows)? {
+            let (Ok(hwnd), Ok(url)) = (unsafe { (browser.HWND(), browser.LocationURL()) }) else {
+                continue;
+            };
+            if let Some(path) = path_from_url(&url.to_string()) {
+                paths.insert(hwnd.0, path);
+            }
+        }
+        Ok(paths)
+    }
+}
+
+impl WindowSource for WindowsWindowSource {
+    fn windows(&mut self) -> Result<Vec<WindowInfo>> {
+        let paths = self.explorer_paths()?;
+        Ok(top_level_windows()?
+            .into_iter()
+            .map(|window| WindowInfo {
+                window_id: window.hwnd.0 as u64,
+                title: window.title,
+                process: process_name(window.hwnd),
+                path: paths.get(&window.hwnd.0).cloned(),
+            })
+            .collect())
+    }
+}
+
+pub fn process_name(hwnd: HWND) -> Option<String> {
+    let mut pid = 0u32;
+    unsafe { GetWindowThreadProcessId(hwnd, Some(&mut pid)) };
+    let process = unsafe { OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid) }.ok()?;
+    let mut buf = [0u16; 1024];
+    let mut len = buf.len() as u32;
+    let result = unsafe {
+        QueryFullProcessImageNameW(
+            process,
+            PROCESS_NAME_WIN32,
+            PWSTR(buf.as_mut_ptr()),
+            &mut len,
+        )
+    };
+    unsafe {
+        let _ = CloseHandle(process);
+    }
+    result.ok()?;
+    let path = String::from_utf16(&buf[..len as usize]).ok()?;
+    path.rsplit('\\').next().map(|x| x.to_string())
+}
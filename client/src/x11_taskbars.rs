@@ -0,0 +1,413 @@
+use crate::taskbar_collector::TaskbarCollector;
+use crate::taskbar_snapshot::local_machine_name;
+use crate::taskbar_snapshot::Edge;
+use crate::taskbar_snapshot::Rect;
+use crate::taskbar_snapshot::TaskbarSettings;
+use crate::taskbar_snapshot::TaskbarSnapshot;
+use eyre::Context;
+use eyre::Result;
+use x11rb::atom_manager;
+use x11rb::connection::Connection;
+use x11rb::protocol::randr::ConnectionExt as _;
+use x11rb::protocol::xproto::Atom;
+use x11rb::protocol::xproto::AtomEnum;
+use x11rb::protocol::xproto::ConnectionExt as _;
+use x11rb::protocol::xproto::GetPropertyReply;
+use x11rb::protocol::xproto::Window;
+use x11rb::rust_connection::RustConnection;
+
+atom_manager! {
+    pub Atoms: AtomsCookie {
+        _NET_CLIENT_LIST,
+        _NET_WM_NAME,
+        _NET_WM_STATE,
+        _NET_WM_STATE_SKIP_TASKBAR,
+        _NET_WM_STRUT,
+        _NET_WM_STRUT_PARTIAL,
+        _NET_WM_WINDOW_TYPE,
+        _NET_WM_WINDOW_TYPE_DESKTOP,
+        _NET_WM_WINDOW_TYPE_DOCK,
+        UTF8_STRING,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Monitor {
+    rect: Rect,
+    primary: bool,
+}
+
+/// A dock window such as a panel, with the edge its strut reserves if it sets one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Panel {
+    rect: Rect,
+    strut_edge: Option<Edge>,
+}
+
+/// A window that belongs on a taskbar.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct App {
+    rect: Rect,
+    title: String,
+}
+
+enum Client {
+    Panel(Panel),
+    App(App),
+}
+
+/// Treats EWMH dock windows as taskbars and lists each normal window on the panel of the monitor it is on.
+pub struct X11Collector {
+    connection: RustConnection,
+    root: Window,
+    atoms: Atoms,
+}
+
+impl X11Collector {
+    pub fn connect() -> Result<Self> {
+        let (connection, screen) =
+            x11rb::connect(None).wrap_err("Failed to connect to the X server")?;
+        let root = connection.setup().roots[screen].root;
+        let atoms = Atoms::new(&connection)?.reply()?;
+        Ok(X11Collector {
+            connection,
+            root,
+            atoms,
+        })
+    }
+
+    fn monitors(&self) -> Result<Vec<Monitor>> {
+        let monitors = match self.connection.randr_get_monitors(self.root, true) {
+            Ok(cookie) => cookie.reply().map(|x| x.monitors).unwrap_or_default(),
+            Err(_) => Vec::new(),
+        };
+        if monitors.is_empty() {
+            // No RandR, fall back to the whole screen
+            let geometry = self.connection.get_geometry(self.root)?.reply()?;
+            return Ok(vec![Monitor {
+                rect: Rect {
+                    left: 0,
+                    top: 0,
+                    right: geometry.width as i32,
+                    bottom: geometry.height as i32,
+                },
+                primary: true,
+            }]);
+        }
+        Ok(monitors
+            .into_iter()
+            .map(|x| Monitor {
+                rect: Rect {
+                    left: x.x as i32,
+                    top: x.y as i32,
+                    right: x.x as i32 + x.width as i32,
+                    bottom: x.y as i32 + x.height as i32,
+                },
+                primary: x.primary,
+            })
+            .collect())
+    }
+
+    fn property(&self, window: Window, property: impl Into<Atom>) -> Result<GetPropertyReply> {
+        Ok(self
+            .connection
+            .get_property(false, window, property, AtomEnum::ANY, 0, u32::MAX)?
+            .reply()?)
+    }
+
+    fn atoms_of(&self, window: Window, property: Atom) -> Result<Vec<u32>> {
+        let reply = self.property(window, property)?;
+        Ok(reply.value32().map(|x| x.collect()).unwrap_or_default())
+    }
+
+    fn rect(&self, window: Window) -> Result<Rect> {
+        let geometry = self.connection.get_geometry(window)?.reply()?;
+        let origin = self
+            .connection
+            .translate_coordinates(window, self.root, 0, 0)?
+            .reply()?;
+        Ok(Rect {
+            left: origin.dst_x as i32,
+            top: origin.dst_y as i32,
+            right: origin.dst_x as i32 + geometry.width as i32,
+            bottom: origin.dst_y as i32 + geometry.height as i32,
+        })
+    }
+
+    fn title(&self, window: Window) -> Result<Option<String>> {
+        let reply = self.property(window, self.atoms._NET_WM_NAME)?;
+        if reply.type_ == self.atoms.UTF8_STRING && !reply.value.is_empty() {
+            return Ok(Some(String::from_utf8_lossy(&reply.value).into_owned()));
+        }
+        let reply = self.property(window, AtomEnum::WM_NAME)?;
+        if reply.value.is_empty() {
+            return Ok(None);
+        }
+        // WM_NAME is Latin-1
+        Ok(Some(reply.value.iter().map(|x| *x as char).collect()))
+    }
+
+    fn strut_edge(&self, window: Window) -> Result<Option<Edge>> {
+        let mut strut = self.atoms_of(window, self.atoms._NET_WM_STRUT_PARTIAL)?;
+        if strut.len() < 4 {
+            strut = self.atoms_of(window, self.atoms._NET_WM_STRUT)?;
+        }
+        let Some(&[left, right, top, bottom]) = strut.get(..4) else {
+            return Ok(None);
+        };
+        let edge = [
+            (left, Edge::Left),
+            (right, Edge::Right),
+            (top, Edge::Top),
+            (bottom, Edge::Bottom),
+        ]
+        .into_iter()
+        .filter(|(size, _)| *size > 0)
+        .max_by_key(|(size, _)| *size)
+        .map(|(_, edge)| edge);
+        Ok(edge)
+    }
+
+    /// Sorts one window from the client list into a panel or an app, `None` if it is neither.
+    fn classify(&self, window: Window) -> Result<Option<Client>> {
+        let types = self.atoms_of(window, self.atoms._NET_WM_WINDOW_TYPE)?;
+        if types.contains(&self.atoms._NET_WM_WINDOW_TYPE_DOCK) {
+            return Ok(Some(Client::Panel(Panel {
+                rect: self.rect(window)?,
+                strut_edge: self.strut_edge(window)?,
+            })));
+        }
+        if types.contains(&self.atoms._NET_WM_WINDOW_TYPE_DESKTOP) {
+            return Ok(None);
+        }
+        let state = self.atoms_of(window, self.atoms._NET_WM_STATE)?;
+        if state.contains(&self.atoms._NET_WM_STATE_SKIP_TASKBAR) {
+            return Ok(None);
+        }
+        let Some(title) = self.title(window)? else {
+            return Ok(None);
+        };
+        Ok(Some(Client::App(App {
+            rect: self.rect(window)?,
+            title,
+        })))
+    }
+}
+
+impl TaskbarCollector for X11Collector {
+    fn collect(&mut self) -> Result<Vec<TaskbarSnapshot>> {
+        let monitors = self.monitors()?;
+        let clients = self.atoms_of(self.root, self.atoms._NET_CLIENT_LIST)?;
+        let mut panels = Vec::new();
+        let mut apps = Vec::new();
+        for window in clients {
+            // Windows can disappear between listing and inspecting them
+            match self.classify(window) {
+                Ok(Some(Client::Panel(panel))) => panels.push(panel),
+                Ok(Some(Client::App(app))) => apps.push(app),
+                Ok(None) => {}
+                Err(e) => tracing::debug!("Skipping window {window:#x}: {e}"),
+            }
+        }
+        Ok(assemble(&local_machine_name(), &monitors, panels, apps))
+    }
+}
+
+fn center(rect: Rect) -> (i32, i32) {
+    ((rect.left + rect.right) / 2, (rect.top + rect.bottom) / 2)
+}
+
+fn monitor_of(monitors: &[Monitor], rect: Rect) -> Option<usize> {
+    let (x, y) = center(rect);
+    monitors
+        .iter()
+        .position(|m| x >= m.rect.left && x < m.rect.right && y >= m.rect.top && y < m.rect.bottom)
+}
+
+/// Turns the panels into taskbars, primary monitor first, and lists each app on the panel of its monitor.
+///
+/// Apps on a monitor without a panel, or off every monitor, go to the first taskbar. Desktops without
+/// any panel, like GNOME's, get a zero-height taskbar along the bottom of each monitor instead.
+fn assemble(
+    machine: &str,
+    monitors: &[Monitor],
+    panels: Vec<Panel>,
+    apps: Vec<App>,
+) -> Vec<TaskbarSnapshot> {
+    let primary = monitors.iter().position(|x| x.primary).unwrap_or(0);
+    let mut panels = if panels.is_empty() {
+        monitors
+            .iter()
+            .enumerate()
+            .map(|(index, monitor)| {
+                let rect = Rect {
+                    top: monitor.rect.bottom,
+                    ..monitor.rect
+                };
+                let panel = Panel {
+                    rect,
+                    strut_edge: Some(Edge::Bottom),
+                };
+                (Some(index), panel)
+            })
+            .collect::<Vec<_>>()
+    } else {
+        panels
+            .into_iter()
+            .map(|panel| (monitor_of(monitors, panel.rect), panel))
+            .collect()
+    };
+    panels.sort_by_key(|(monitor, panel)| {
+        (*monitor != Some(primary), panel.rect.top, panel.rect.left)
+    });
+
+    let mut taskbars = panels
+        .iter()
+        .enumerate()
+        .map(|(index, (monitor, panel))| {
+            let edge = panel.strut_edge.unwrap_or_else(|| match monitor {
+                Some(monitor) => Edge::from_rects(panel.rect, monitors[*monitor].rect),
+                None => Edge::default(),
+            });
+            TaskbarSnapshot {
+                machine: machine.to_string(),
+                index: index as u32,
+                rect: panel.rect,
+                is_secondary: *monitor != Some(primary),
+                apps: Vec::new(),
+                settings: TaskbarSettings {
+                    edge,
+                    locked: true,
+                    ..Default::default()
+                },
+            }
+        })
+        .collect::<Vec<_>>();
+    if taskbars.is_empty() {
+        return taskbars;
+    }
+
+    for app in apps {
+        let monitor = monitor_of(monitors, app.rect);
+        let taskbar = panels
+            .iter()
+            .position(|(x, _)| monitor.is_some() && *x == monitor)
+            .unwrap_or(0);
+        taskbars[taskbar].apps.push(app.title);
+    }
+    taskbars
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rect(left: i32, top: i32, right: i32, bottom: i32) -> Rect {
+        Rect {
+            left,
+            top,
+            right,
+            bottom,
+        }
+    }
+
+    fn app(title: &str, left: i32, top: i32) -> App {
+        App {
+            rect: rect(left, top, left + 400, top + 300),
+            title: title.to_string(),
+        }
+    }
+
+    /// A 1920x1080 primary monitor with a 1280x1024 one to its left.
+    fn monitors() -> Vec<Monitor> {
+        vec![
+            Monitor {
+                rect: rect(-1280, 0, 0, 1024),
+                primary: false,
+            },
+            Monitor {
+                rect: rect(0, 0, 1920, 1080),
+                primary: true,
+            },
+        ]
+    }
+
+    fn apps_of(taskbars: &[TaskbarSnapshot]) -> Vec<Vec<&str>> {
+        taskbars
+            .iter()
+            .map(|x| x.apps.iter().map(String::as_str).collect())
+            .collect()
+    }
+
+    #[test]
+    fn lists_the_primary_monitors_panel_first() {
+        let panels = vec![
+            Panel {
+                rect: rect(-1280, 0, 0, 30),
+                strut_edge: None,
+            },
+            Panel {
+                rect: rect(0, 1040, 1920, 1080),
+                strut_edge: Some(Edge::Bottom),
+            },
+        ];
+        let taskbars = assemble(
+            "box",
+            &monitors(),
+            panels,
+            vec![app("Editor", 100, 100), app("Mail", -900, 200)],
+        );
+        assert_eq!(taskbars.len(), 2);
+        assert_eq!(taskbars[0].rect, rect(0, 1040, 1920, 1080));
+        assert!(!taskbars[0].is_secondary);
+        assert_eq!(taskbars[0].settings.edge, Edge::Bottom);
+        assert_eq!(taskbars[1].index, 1);
+        assert!(taskbars[1].is_secondary);
+        // No strut, so the edge comes from where the panel sits on its monitor
+        assert_eq!(taskbars[1].settings.edge, Edge::Top);
+        assert_eq!(apps_of(&taskbars), vec![vec!["Editor"], vec!["Mail"]]);
+    }
+
+    #[test]
+    fn puts_apps_without_a_panel_of_their_own_on_the_first_taskbar() {
+        let panels = vec![Panel {
+            rect: rect(0, 1040, 1920, 1080),
+            strut_edge: Some(Edge::Bottom),
+        }];
+        let taskbars = assemble(
+            "box",
+            &monitors(),
+            panels,
+            vec![
+                app("Mail", -900, 200),
+                app("Editor", 100, 100),
+                app("Lost", 5000, 5000),
+            ],
+        );
+        assert_eq!(apps_of(&taskbars), vec![vec!["Mail", "Editor", "Lost"]]);
+    }
+
+    #[test]
+    fn gives_each_monitor_a_taskbar_when_there_are_no_panels() {
+        let taskbars = assemble(
+            "box",
+            &monitors(),
+            Vec::new(),
+            vec![app("Mail", -900, 200), app("Editor", 100, 100)],
+        );
+        assert_eq!(taskbars.len(), 2);
+        assert_eq!(taskbars[0].rect, rect(0, 1080, 1920, 1080));
+        assert!(!taskbars[0].is_secondary);
+        assert_eq!(taskbars[1].rect, rect(-1280, 1024, 0, 1024));
+        assert!(taskbars[1].is_secondary);
+        assert!(taskbars
+            .iter()
+            .all(|x| x.settings.edge == Edge::Bottom && x.machine == "box"));
+        assert_eq!(apps_of(&taskbars), vec![vec!["Editor"], vec!["Mail"]]);
+    }
+
+    #[test]
+    fn reports_nothing_without_monitors_or_panels() {
+        assert!(assemble("box", &[], Vec::new(), vec![app("Editor", 0, 0)]).is_empty());
+    }
+}
@@ -0,0 +1,306 @@
+use crate::module_bindings::ZoneLayout;
+use crate::taskbar_snapshot::Edge;
+use crate::taskbar_snapshot::Rect;
+use crate::taskbar_snapshot::TaskbarSnapshot;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A `zone_layout` row.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LayoutSpec {
+    pub name: String,
+    pub width: u32,
+    pub height: u32,
+    pub columns: Vec<f32>,
+    pub rows: Vec<f32>,
+    #[serde(default)]
+    pub gap: u32,
+}
+
+impl From<ZoneLayout> for LayoutSpec {
+    fn from(value: ZoneLayout) -> Self {
+        LayoutSpec {
+            name: value.name,
+            width: value.width,
+            height: value.height,
+            columns: value.columns,
+            rows: value.rows,
+            gap: value.gap,
+        }
+    }
+}
+
+/// The variant of the layout called `name` made for the resolution closest to `monitor`'s.
+pub fn pick_layout<'a>(
+    layouts: &'a [LayoutSpec],
+    name: &str,
+    monitor: Rect,
+) -> Option<&'a LayoutSpec> {
+    layouts
+        .iter()
+        .filter(|x| x.name == name)
+        .min_by_key(|x| x.width.abs_diff(monitor.width()) + x.height.abs_diff(monitor.height()))
+}
+
+/// Whether the center of `rect` is on `monitor`.
+pub fn on_monitor(rect: Rect, monitor: Rect) -> bool {
+    let (x, y) = ((rect.left + rect.right) / 2, (rect.top + rect.bottom) / 2);
+    x >= monitor.left && x < monitor.right && y >= monitor.top && y < monitor.bottom
+}
+
+/// What is left of `monitor` once the taskbars docked on it have taken their space.
+///
+/// Auto-hidden taskbars only cover the monitor while shown, so they reserve nothing.
+pub fn work_area(monitor: Rect, taskbars: &[TaskbarSnapshot]) -> Rect {
+    let mut area = monitor;
+    for taskbar in taskbars.iter().filter(|x| !x.settings.auto_hide) {
+        if !on_monitor(taskbar.rect, monitor) {
+            continue;
+        }
+        match Edge::from_rects(taskbar.rect, monitor) {
+            Edge::Left => area.left = area.left.max(taskbar.rect.right),
+            Edge::Top => area.top = area.top.max(taskbar.rect.bottom),
+            Edge::Right => area.right = area.right.min(taskbar.rect.left),
+            Edge::Bottom => area.bottom = area.bottom.min(taskbar.rect.top),
+        }
+    }
+    area
+}
+
+/// Converts a length at 96 DPI into physical pixels at `dpi`.
+pub fn scale(length: u32, dpi: u32) -> i32 {
+    (length as f64 * dpi as f64 / 96.0).round() as i32
+}
+
+/// Splits `length` pixels from `start` into tracks sized by `weights`, with `gap` pixels around each.
+///
+/// Boundaries are rounded from the running total, so the tracks and gaps add up to exactly `length`.
+/// Gaps shrink to fit lengths too small for them, and weights that sum to nothing split evenly.
+fn tracks(weights: &[f32], start: i32, length: i32, gap: i32) -> Vec<(i32, i32)> {
+    let length = length.max(0);
+    let count = weights.len() as i32;
+    let gap = gap.clamp(0, length / (count + 1));
+    let available = (length - gap * (count + 1)) as f64;
+    let weights = weights
+        .iter()
+        .map(|x| (*x as f64).max(0.0))
+        .collect::<Vec<_>>();
+    let total = weights.iter().sum::<f64>();
+    let mut sum = 0.0;
+    let mut previous = 0;
+    weights
+        .iter()
+        .enumerate()
+        .map(|(i, weight)| {
+            sum += if total > 0.0 {
+                weight / total
+            } else {
+                1.0 / count as f64
+            };
+            let end = (sum * available).round() as i32;
+            let from = start + gap * (i as i32 + 1) + previous;
+            let track = (from, from + end - previous);
+            previous = end;
+            track
+        })
+        .collect()
+}
+
+/// The zones of `layout` laid out over `work_area`, numbered row by row from the top left.
+pub fn zone_rects(layout: &LayoutSpec, work_area: Rect, dpi: u32) -> Vec<Rect> {
+    let gap = scale(layout.gap, dpi);
+    let columns = tracks(
+        &layout.columns,
+        work_area.left,
+        work_area.width() as i32,
+        gap,
+    );
+    let rows = tracks(&layout.rows, work_area.top, work_area.height() as i32, gap);
+    rows.iter()
+        .flat_map(|(top, bottom)| {
+            columns.iter().map(move |(left, right)| Rect {
+                left: *left,
+                top: *top,
+                right: *right,
+                bottom: *bottom,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rect(left: i32, top: i32, right: i32, bottom: i32) -> Rect {
+        Rect {
+            left,
+            top,
+            right,
+            bottom,
+        }
+    }
+
+    fn layout(columns: &[f32], rows: &[f32], gap: u32) -> LayoutSpec {
+        LayoutSpec {
+            name: "test".to_string(),
+            width: 1920,
+            height: 1080,
+            columns: columns.to_vec(),
+            rows: rows.to_vec(),
+            gap,
+        }
+    }
+
+    #[test]
+    fn hands_remainder_pixels_out_without_losing_any() {
+        assert_eq!(
+            tracks(&[1.0, 1.0, 1.0], 0, 1000, 0),
+            vec![(0, 333), (333, 667), (667, 1000)]
+        );
+        assert_eq!(
+            tracks(&[1.0, 1.0, 1.0], 10, 1001, 5),
+            vec![(15, 342), (347, 674), (679, 1006)]
+        );
+    }
+
+    #[test]
+    fn normalizes_ratios_that_do_not_sum_to_one() {
+        assert_eq!(
+            tracks(&[1.0, 2.0, 1.0], 0, 1000, 0),
+            tracks(&[0.25, 0.5, 0.25], 0, 1000, 0)
+        );
+        assert_eq!(tracks(&[3.0, 1.0], 0, 800, 0), vec![(0, 600), (600, 800)]);
+    }
+
+    #[test]
+    fn splits_evenly_when_the_ratios_sum_to_nothing() {
+        assert_eq!(tracks(&[0.0, 0.0], 0, 100, 0), vec![(0, 50), (50, 100)]);
+        assert_eq!(tracks(&[-1.0, 0.0], 0, 100, 0), vec![(0, 50), (50, 100)]);
+    }
+
+    #[test]
+    fn keeps_zones_of_a_zero_size_monitor_on_it() {
+        let zones = zone_rects(&layout(&[1.0, 1.0], &[1.0], 8), rect(500, 0, 500, 0), 96);
+        assert_eq!(zones, vec![rect(500, 0, 500, 0), rect(500, 0, 500, 0)]);
+    }
+
+    #[test]
+    fn shrinks_gaps_that_do_not_fit() {
+        // Three gaps of 3 leave a single pixel, which goes to the first track
+        assert_eq!(tracks(&[1.0, 1.0], 0, 10, 8), vec![(3, 4), (7, 7)]);
+    }
+
+    #[test]
+    fn scales_gaps_with_the_dpi() {
+        let zones = zone_rects(&layout(&[1.0, 1.0], &[1.0], 10), rect(0, 0, 1000, 500), 144);
+        assert_eq!(zones, vec![rect(15, 15, 493, 485), rect(508, 15, 985, 485)]);
+    }
+
+    #[test]
+    fn lays_zones_out_row_by_row() {
+        let zones = zone_rects(
+            &layout(&[1.0, 1.0], &[1.0, 1.0], 0),
+            rect(0, 0, 100, 100),
+            96,
+        );
+        assert_eq!(
+            zones,
+            vec![
+                rect(0, 0, 50, 50),
+                rect(50, 0, 100, 50),
+                rect(0, 50, 50, 100),
+                rect(50, 50, 100, 100)
+            ]
+        );
+    }
+
+    #[test]
+    fn has_no_zones_without_tracks() {
+        assert!(zone_rects(&layout(&[], &[1.0], 4), rect(0, 0, 100, 100), 96).is_empty());
+    }
+
+    #[test]
+    fn leaves_room_for_docked_taskbars_only() {
+        let monitor = rect(0, 0, 1920, 1080);
+        let taskbar = |rect, auto_hide| TaskbarSnapshot {
+            machine: "box".to_string(),
+            index: 0,
+            rect,
+            is_secondary: false,
+            apps: Vec::new(),
+            settings: crate::taskbar_snapshot::TaskbarSettings {
+                auto_hide,
+                ..Default::default()
+            },
+        };
+        let taskbars = [
+            taskbar(rect(0, 1040, 1920, 1080), false),
+            taskbar(rect(0, 0, 48, 1080), true),
+            taskbar(rect(1920, 1040, 3840, 1080), false),
+        ];
+        assert_eq!(work_area(monitor, &taskbars), rect(0, 0, 1920, 1040));
+    }
+
+    #[test]
+    fn picks_the_variant_closest_to_the_monitor() {
+        let layouts = [
+            LayoutSpec {
+                width: 3840,
+                height: 2160,
+                ..layout(&[1.0], &[1.0], 0)
+            },
+            layout(&[1.0, 1.0], &[1.0], 0),
+        ];
+        let picked = pick_layout(&layouts, "test", rect(0, 0, 2560, 1440)).unwrap();
+        assert_eq!(picked.width, 1920);
+        assert!(pick_layout(&layouts, "other", rect(0, 0, 2560, 1440)).is_none());
+    }
+
+    /// The zones of the fixture layout `name` on `monitor`, as the `zones` command prints them.
+    fn fixture_zones(name: &str, monitor: Rect, dpi: u32) -> Vec<Rect> {
+        let fixture = |file: &str| {
+            std::fs::read_to_string(
+                std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                    .join("tests/fixtures/zones")
+                    .join(file),
+            )
+            .unwrap()
+        };
+        let layouts: Vec<LayoutSpec> = serde_json::from_str(&fixture("layouts.json")).unwrap();
+        let taskbars: Vec<TaskbarSnapshot> =
+            serde_json::from_str(&fixture("taskbars.json")).unwrap();
+        let layout = pick_layout(&layouts, name, monitor).unwrap();
+        zone_rects(layout, work_area(monitor, &taskbars), dpi)
+    }
+
+    #[test]
+    fn scales_gaps_and_leaves_room_for_the_taskbar() {
+        // 8px gaps at 150% are 12px, the 48px bottom taskbar is excluded and the auto-hidden one is not
+        assert_eq!(
+            fixture_zones("focus", rect(0, 0, 2560, 1440), 144),
+            [
+                rect(12, 12, 1695, 690),
+                rect(1707, 12, 2548, 690),
+                rect(12, 702, 1695, 1380),
+                rect(1707, 702, 2548, 1380),
+            ]
+        );
+    }
+
+    #[test]
+    fn picks_the_variant_for_the_monitor_resolution() {
+        assert_eq!(
+            fixture_zones("focus", rect(2560, 0, 4480, 1080), 96),
+            [rect(2616, 8, 3540, 1072), rect(3548, 8, 4472, 1072)]
+        );
+    }
+
+    #[test]
+    fn tiles_the_work_area_exactly_without_gaps() {
+        assert_eq!(
+            fixture_zones("halves", rect(2560, 0, 4480, 1080), 96),
+            [rect(2608, 0, 3544, 1080), rect(3544, 0, 4480, 1080)]
+        );
+    }
+}
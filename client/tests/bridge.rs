@@ -0,0 +1,160 @@
+//! Runs `bridge --replay` over a fixture recording against local stand-ins for its sinks.
+
+use serde_json::Value;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Read;
+use std::io::Write;
+use std::net::TcpListener;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process::Command;
+use std::process::Output;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+fn bridge(args: &[&str]) -> Output {
+    let recording =
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/bridge/recording.jsonl");
+    let output = Command::new(env!("CARGO_BIN_EXE_explorer_wrangler_client"))
+        .arg("bridge")
+        .arg("--replay")
+        .arg(recording)
+        .args(args)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    output
+}
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("bridge-{}-{name}", std::process::id()))
+}
+
+/// The kind, app list after the change and app list before it, of each event.
+fn summary(events: &[Value]) -> Vec<(String, Value, Value)> {
+    events
+        .iter()
+        .map(|x| {
+            (
+                x["kind"].as_str().unwrap().to_string(),
+                x["after"]["apps"].clone(),
+                x["before"]["apps"].clone(),
+            )
+        })
+        .collect()
+}
+
+fn expected() -> Vec<(String, Value, Value)> {
+    vec![
+        (
+            "inserted".into(),
+            serde_json::json!(["Terminal"]),
+            Value::Null,
+        ),
+        (
+            "updated".into(),
+            serde_json::json!(["Terminal", "Files — Downloads"]),
+            serde_json::json!(["Terminal"]),
+        ),
+        (
+            "inserted".into(),
+            serde_json::json!(["Browser"]),
+            Value::Null,
+        ),
+    ]
+}
+
+/// The status each post was answered with and the event it carried.
+type Posts = Arc<Mutex<Vec<(u16, Value)>>>;
+
+/// Accepts HTTP posts, answering the first with a 503 and the rest with a 200.
+fn http_stand_in() -> (String, Posts) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/events", listener.local_addr().unwrap());
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let log = received.clone();
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut reader = BufReader::new(stream.unwrap());
+            loop {
+                let mut length = None;
+                let mut line = String::new();
+                loop {
+                    line.clear();
+                    if reader.read_line(&mut line).unwrap_or(0) == 0 {
+                        break;
+                    }
+                    let lower = line.to_ascii_lowercase();
+                    if let Some(value) = lower.strip_prefix("content-length:") {
+                        length = value.trim().parse::<usize>().ok();
+                    }
+                    if line == "\r\n" {
+                        break;
+                    }
+                }
+                let Some(length) = length else {
+                    break;
+                };
+                let mut body = vec![0; length];
+                reader.read_exact(&mut body).unwrap();
+                let mut log = log.lock().unwrap();
+                let status = if log.is_empty() { 503 } else { 200 };
+                log.push((status, serde_json::from_slice(&body).unwrap()));
+                let response = format!("HTTP/1.1 {status} Stand-in\r\nContent-Length: 0\r\n\r\n");
+                reader.get_mut().write_all(response.as_bytes()).unwrap();
+            }
+        }
+    });
+    (url, received)
+}
+
+#[test]
+fn posts_events_and_retries_failures() {
+    let (url, received) = http_stand_in();
+    bridge(&["--http", &url]);
+    let received = received.lock().unwrap();
+    let statuses = received.iter().map(|(x, _)| *x).collect::<Vec<_>>();
+    assert_eq!(statuses, [503, 200, 200, 200]);
+    let delivered = received[1..]
+        .iter()
+        .map(|(_, x)| x.clone())
+        .collect::<Vec<_>>();
+    assert_eq!(summary(&delivered), expected());
+    // The retry carries the same event
+    assert_eq!(received[0].1, received[1].1);
+}
+
+#[test]
+fn appends_events_to_a_file() {
+    let path = temp_path("events.jsonl");
+    let _ = std::fs::remove_file(&path);
+    bridge(&["--file", path.to_str().unwrap()]);
+    let written = std::fs::read_to_string(&path).unwrap();
+    let _ = std::fs::remove_file(&path);
+    let events = written
+        .lines()
+        .map(|x| serde_json::from_str(x).unwrap())
+        .collect::<Vec<Value>>();
+    assert_eq!(summary(&events), expected());
+    assert_eq!(events[1]["at"], "2026-10-02T09:00:10Z");
+}
+
+#[cfg(unix)]
+#[test]
+fn writes_events_to_a_unix_socket() {
+    let path = temp_path("events.sock");
+    let _ = std::fs::remove_file(&path);
+    let listener = std::os::unix::net::UnixListener::bind(&path).unwrap();
+    let reader = std::thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        BufReader::new(stream)
+            .lines()
+            .map(|x| serde_json::from_str(&x.unwrap()).unwrap())
+            .collect::<Vec<Value>>()
+    });
+    bridge(&["--unix-socket", path.to_str().unwrap()]);
+    let events = reader.join().unwrap();
+    let _ = std::fs::remove_file(&path);
+    assert_eq!(summary(&events), expected());
+}
@@ -0,0 +1,60 @@
+//! Runs `report` over fixture recordings from two machines and compares it to the expected output.
+
+use std::path::Path;
+use std::path::PathBuf;
+use std::process::Command;
+
+fn fixtures() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/report")
+}
+
+fn report(args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_explorer_wrangler_client"))
+        .arg("report")
+        .arg(fixtures().join("office.jsonl"))
+        .arg(fixtures().join("laptop.jsonl"))
+        .args(args)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    String::from_utf8(output.stdout).unwrap()
+}
+
+fn expected(name: &str) -> String {
+    std::fs::read_to_string(fixtures().join(name)).unwrap()
+}
+
+#[test]
+fn renders_markdown() {
+    assert_eq!(report(&["--format", "markdown"]), expected("expected.md"));
+}
+
+#[test]
+fn renders_html() {
+    assert_eq!(report(&["--format", "html"]), expected("expected.html"));
+}
+
+#[test]
+fn renders_csv() {
+    assert_eq!(report(&["--format", "csv"]), expected("expected.csv"));
+}
+
+#[test]
+fn caps_gaps_between_samples() {
+    // The office recording has a 10 minute gap while only the terminal was open
+    let csv = report(&["--format", "csv", "--max-gap", "600"]);
+    assert!(
+        csv.contains("\noffice-pc,2026-10-02,Terminal,610\n"),
+        "{csv}"
+    );
+}
+
+#[test]
+fn writes_to_a_file() {
+    let path = std::env::temp_dir().join(format!("presence-{}.csv", std::process::id()));
+    let stdout = report(&["--format", "csv", "--output", path.to_str().unwrap()]);
+    let written = std::fs::read_to_string(&path).unwrap();
+    let _ = std::fs::remove_file(&path);
+    assert!(stdout.is_empty());
+    assert_eq!(written, expected("expected.csv"));
+}
@@ -0,0 +1,41 @@
+//! Checks the systemd unit `service install` would write, without touching systemd.
+#![cfg(target_os = "linux")]
+
+use std::process::Command;
+
+fn install_dry_run(args: &[&str]) -> std::process::Output {
+    Command::new(env!("CARGO_BIN_EXE_explorer_wrangler_client"))
+        .args(["service", "install", "--dry-run", "--"])
+        .args(args)
+        .env("XDG_CONFIG_HOME", "/tmp/wrangler-config")
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn unit_runs_the_service_with_the_given_arguments() {
+    let output = install_dry_run(&["--interval", "10", "--log-file", "/var/log/100% $HOME.log"]);
+    assert!(output.status.success(), "{output:?}");
+    let unit = String::from_utf8(output.stdout).unwrap();
+    assert!(unit.starts_with("# /tmp/wrangler-config/systemd/user/explorer-wrangler.service\n"));
+    let exec_start = unit
+        .lines()
+        .find_map(|x| x.strip_prefix("ExecStart="))
+        .unwrap();
+    assert_eq!(
+        exec_start,
+        format!(
+            r#""{}" "service" "run" "--interval" "10" "--log-file" "/var/log/100%% $$HOME.log""#,
+            env!("CARGO_BIN_EXE_explorer_wrangler_client")
+        )
+    );
+    assert!(unit.contains("\nWantedBy=graphical-session.target\n"));
+}
+
+#[test]
+fn arguments_the_service_could_not_start_with_are_refused() {
+    let output = install_dry_run(&["--interval", "soon"]);
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--interval"));
+}
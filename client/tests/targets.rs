@@ -0,0 +1,27 @@
+//! Checks how `--target` is read, which needs no server as long as it is refused.
+
+use std::process::Command;
+
+fn sync_with_target(target: &str) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_explorer_wrangler_client"))
+        .args(["sync", "--target", target])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(2), "{output:?}");
+    String::from_utf8(output.stderr).unwrap()
+}
+
+#[test]
+fn target_without_module_is_refused() {
+    let stderr = sync_with_target("http://home:3000");
+    assert!(
+        stderr.contains("Expected `URI,MODULE` or `URI,MODULE,TOKEN_FILE`, got `http://home:3000`"),
+        "{stderr}"
+    );
+}
+
+#[test]
+fn target_with_empty_module_is_refused() {
+    let stderr = sync_with_target("http://home:3000,,token.txt");
+    assert!(stderr.contains("Expected `URI,MODULE`"), "{stderr}");
+}
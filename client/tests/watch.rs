@@ -0,0 +1,43 @@
+//! Runs `notify --replay` over a fixture recording and checks which alerts each change raises.
+
+use std::path::Path;
+use std::process::Command;
+
+fn notify(rules: &str) -> Vec<String> {
+    let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/watch");
+    let output = Command::new(env!("CARGO_BIN_EXE_explorer_wrangler_client"))
+        .args(["notify", "--replay"])
+        .arg(fixtures.join("recording.jsonl"))
+        .arg("--rules")
+        .arg(fixtures.join(rules))
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .map(|x| x.to_string())
+        .collect()
+}
+
+#[test]
+fn raises_each_change_once_per_machine() {
+    assert_eq!(
+        notify("rules.json"),
+        [
+            "2026-10-02 09:00:05 Files — Downloads appeared on office-pc (explorer opened)",
+            "2026-10-02 09:00:15 Terminal disappeared from office-pc (terminal closed)",
+            "2026-10-02 09:00:20 Files — Music appeared on home-pc (explorer opened)",
+            "2026-10-02 09:00:20 Steam appeared on home-pc (games at home)",
+        ]
+    );
+}
+
+#[test]
+fn needs_rules_to_replay() {
+    let output = Command::new(env!("CARGO_BIN_EXE_explorer_wrangler_client"))
+        .args(["notify", "--replay", "recording.jsonl"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(2));
+}
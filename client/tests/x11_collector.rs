@@ -0,0 +1,214 @@
+//! Runs `collect --collector x11` against windows created on a throwaway Xvfb display.
+#![cfg(unix)]
+
+use serde_json::Value;
+use std::path::Path;
+use std::process::Child;
+use std::process::Command;
+use std::process::Stdio;
+use std::time::Duration;
+use std::time::Instant;
+use x11rb::connection::Connection;
+use x11rb::protocol::xproto::AtomEnum;
+use x11rb::protocol::xproto::ConnectionExt;
+use x11rb::protocol::xproto::CreateWindowAux;
+use x11rb::protocol::xproto::PropMode;
+use x11rb::protocol::xproto::Window;
+use x11rb::protocol::xproto::WindowClass;
+use x11rb::rust_connection::RustConnection;
+use x11rb::wrapper::ConnectionExt as _;
+
+struct Xvfb {
+    display: String,
+    process: Child,
+}
+
+impl Xvfb {
+    /// Starts Xvfb on an unused display, or `None` when it is not installed.
+    fn start() -> Option<Xvfb> {
+        let number = (100..200)
+            .find(|n| !Path::new(&format!("/tmp/.X11-unix/X{n}")).exists())
+            .expect("No free X display");
+        let display = format!(":{number}");
+        let process = Command::new("Xvfb")
+            .args([
+                display.as_str(),
+                "-screen",
+                "0",
+                "1280x800x24",
+                "-nolisten",
+                "tcp",
+            ])
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn();
+        let process = match process {
+            Ok(x) => x,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return None,
+            Err(e) => panic!("Failed to start Xvfb: {e}"),
+        };
+        let xvfb = Xvfb { display, process };
+        let socket = format!("/tmp/.X11-unix/X{number}");
+        let started = Instant::now();
+        while !Path::new(&socket).exists() {
+            assert!(
+                started.elapsed() < Duration::from_secs(10),
+                "Xvfb did not start"
+            );
+            std::thread::sleep(Duration::from_millis(50));
+        }
+        Some(xvfb)
+    }
+}
+
+impl Drop for Xvfb {
+    fn drop(&mut self) {
+        let _ = self.process.kill();
+        let _ = self.process.wait();
+    }
+}
+
+struct Desktop {
+    connection: RustConnection,
+    root: Window,
+    clients: Vec<Window>,
+}
+
+impl Desktop {
+    fn connect(display: &str) -> Desktop {
+        let (connection, screen) = x11rb::connect(Some(display)).unwrap();
+        let root = connection.setup().roots[screen].root;
+        Desktop {
+            connection,
+            root,
+            clients: Vec::new(),
+        }
+    }
+
+    fn atom(&self, name: &str) -> u32 {
+        self.connection
+            .intern_atom(false, name.as_bytes())
+            .unwrap()
+            .reply()
+            .unwrap()
+            .atom
+    }
+
+    fn window(&mut self, x: i16, y: i16, width: u16, height: u16) -> Window {
+        let window = self.connection.generate_id().unwrap();
+        let screen = &self.connection.setup().roots[0];
+        self.connection
+            .create_window(
+                screen.root_depth,
+                window,
+                self.root,
+                x,
+                y,
+                width,
+                height,
+                0,
+                WindowClass::INPUT_OUTPUT,
+                screen.root_visual,
+                &CreateWindowAux::new(),
+            )
+            .unwrap();
+        self.connection.map_window(window).unwrap();
+        self.clients.push(window);
+        window
+    }
+
+    fn app(&mut self, title: &str, x: i16, y: i16) -> Window {
+        let window = self.window(x, y, 400, 300);
+        let (name, utf8) = (self.atom("_NET_WM_NAME"), self.atom("UTF8_STRING"));
+        self.connection
+            .change_property8(PropMode::REPLACE, window, name, utf8, title.as_bytes())
+            .unwrap();
+        window
+    }
+
+    fn set_atoms(&self, window: Window, property: &str, values: &[u32]) {
+        let property = self.atom(property);
+        self.connection
+            .change_property32(PropMode::REPLACE, window, property, AtomEnum::ATOM, values)
+            .unwrap();
+    }
+
+    fn set_cardinals(&self, window: Window, property: &str, values: &[u32]) {
+        let property = self.atom(property);
+        self.connection
+            .change_property32(
+                PropMode::REPLACE,
+                window,
+                property,
+                AtomEnum::CARDINAL,
+                values,
+            )
+            .unwrap();
+    }
+
+    /// Publishes the windows the way a window manager would.
+    fn publish_client_list(&self) {
+        let property = self.atom("_NET_CLIENT_LIST");
+        self.connection
+            .change_property32(
+                PropMode::REPLACE,
+                self.root,
+                property,
+                AtomEnum::WINDOW,
+                &self.clients,
+            )
+            .unwrap();
+        self.connection.sync().unwrap();
+    }
+}
+
+#[test]
+fn collects_panel_and_apps_from_x11() {
+    let Some(xvfb) = Xvfb::start() else {
+        eprintln!("Skipping, Xvfb is not on PATH");
+        return;
+    };
+    let mut desktop = Desktop::connect(&xvfb.display);
+
+    let panel = desktop.window(0, 760, 1280, 40);
+    let dock = desktop.atom("_NET_WM_WINDOW_TYPE_DOCK");
+    desktop.set_atoms(panel, "_NET_WM_WINDOW_TYPE", &[dock]);
+    desktop.set_cardinals(
+        panel,
+        "_NET_WM_STRUT_PARTIAL",
+        &[0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 1279],
+    );
+    desktop.app("Terminal", 10, 10);
+    desktop.app("Files — home", 500, 200);
+    let hidden = desktop.app("Tooltip", 50, 50);
+    let skip_taskbar = desktop.atom("_NET_WM_STATE_SKIP_TASKBAR");
+    desktop.set_atoms(hidden, "_NET_WM_STATE", &[skip_taskbar]);
+    desktop.publish_client_list();
+
+    let output = std::env::temp_dir().join(format!("x11_collector_{}.json", std::process::id()));
+    let status = Command::new(env!("CARGO_BIN_EXE_explorer_wrangler_client"))
+        .args(["collect", "--collector", "x11", "--output"])
+        .arg(&output)
+        .env("DISPLAY", &xvfb.display)
+        .status()
+        .unwrap();
+    assert!(status.success());
+    let taskbars: Value = serde_json::from_str(&std::fs::read_to_string(&output).unwrap()).unwrap();
+    std::fs::remove_file(&output).unwrap();
+
+    let taskbars = taskbars.as_array().unwrap();
+    assert_eq!(taskbars.len(), 1);
+    let taskbar = &taskbars[0];
+    assert!(!taskbar["machine"].as_str().unwrap().is_empty());
+    assert_eq!(taskbar["index"], 0);
+    assert_eq!(taskbar["is_secondary"], false);
+    assert_eq!(
+        taskbar["rect"],
+        serde_json::json!({"left": 0, "top": 760, "right": 1280, "bottom": 800})
+    );
+    assert_eq!(taskbar["settings"]["edge"], "Bottom");
+    assert_eq!(
+        taskbar["apps"],
+        serde_json::json!(["Terminal", "Files — home"])
+    );
+}
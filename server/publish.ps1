@@ -1 +1,24 @@
-spacetime publish explorer-wrangler --delete-data
\ No newline at end of file
+# Publishes the module over the existing database, then brings its rows up to the module's schema.
+#
+# `migrate_schema` only covers what a republish keeps: new tables appear on their own, but SpacetimeDB
+# refuses to publish over tables whose columns changed, like the taskbar settings added after
+# databases first held taskbars. Those cannot be upgraded without deleting their data: pass -Reset
+# then, which deletes every row and starts over. Only the publisher may call `migrate_schema`.
+param(
+    # Publish with --delete-data, wiping the database first
+    [switch]$Reset
+)
+$ErrorActionPreference = 'Stop'
+
+if ($Reset) {
+    spacetime publish explorer-wrangler --delete-data
+} else {
+    spacetime publish explorer-wrangler
+}
+if ($LASTEXITCODE -ne 0) {
+    Write-Error ("Publishing failed. Databases whose taskbars predate the taskbar settings, or whose " +
+        "tables otherwise changed shape, cannot be upgraded without deleting their data. Rerun " +
+        "with -Reset to delete every row and start over.")
+}
+spacetime call explorer-wrangler migrate_schema
+exit $LASTEXITCODE
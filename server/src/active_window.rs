@@ -0,0 +1,190 @@
+use log::info;
+use spacetimedb::reducer;
+use spacetimedb::table;
+use spacetimedb::ReducerContext;
+use spacetimedb::Table;
+use spacetimedb::Timestamp;
+
+/// A stretch of time one window spent in the foreground of a machine.
+#[table(name = active_window_span, public)]
+pub struct ActiveWindowSpan {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub machine: String,
+    /// Native window handle, the `HWND` on Windows
+    pub window_id: u64,
+    pub title: String,
+    /// Executable file name, like `explorer.exe`
+    pub process: Option<String>,
+    /// Folder shown by an Explorer window
+    pub path: Option<String>,
+    pub started_at: Timestamp,
+    /// `None` while the window is still in the foreground
+    pub ended_at: Option<Timestamp>,
+}
+
+/// Ends the open span of `machine` at `at`, returning whether there was one.
+pub fn end_open_span(ctx: &ReducerContext, machine: &str, at: Timestamp) -> bool {
+    let open = ctx
+        .db
+        .active_window_span()
+        .machine()
+        .filter(machine)
+        .find(|x| x.ended_at.is_none());
+    let Some(open) = open else {
+        return false;
+    };
+    ctx.db.active_window_span().id().update(ActiveWindowSpan {
+        ended_at: Some(at),
+        ..open
+    });
+    true
+}
+
+/// What a focus change does to the open span of its machine.
+#[derive(Debug, PartialEq)]
+enum SpanChange {
+    /// Focus bounced back to the window of the open span, which carries on
+    Continue,
+    /// The open span, if any, ends and a new one starts
+    Start,
+}
+
+fn span_change(
+    open: Option<&ActiveWindowSpan>,
+    window_id: u64,
+    path: &Option<String>,
+    at: Timestamp,
+) -> Result<SpanChange, String> {
+    let Some(open) = open else {
+        return Ok(SpanChange::Start);
+    };
+    if open.window_id == window_id && open.path == *path {
+        return Ok(SpanChange::Continue);
+    }
+    if at < open.started_at {
+        return Err(format!(
+            "Focus change at {at} is older than the open span of {}",
+            open.machine
+        ));
+    }
+    Ok(SpanChange::Start)
+}
+
+/// Records that `window_id` came to the foreground of `machine` at `at`, ending the previous span.
+#[reducer]
+pub fn report_focus(
+    ctx: &ReducerContext,
+    machine: String,
+    window_id: u64,
+    title: String,
+    process: Option<String>,
+    path: Option<String>,
+    at: Timestamp,
+) -> Result<(), String> {
+    if machine.is_empty() {
+        return Err("Machine name must not be empty".into());
+    }
+    let open = ctx
+        .db
+        .active_window_span()
+        .machine()
+        .filter(&machine)
+        .find(|x| x.ended_at.is_none());
+    if span_change(open.as_ref(), window_id, &path, at)? == SpanChange::Continue {
+        return Ok(());
+    }
+    end_open_span(ctx, &machine, at);
+    ctx.db.active_window_span().insert(ActiveWindowSpan {
+        id: 0,
+        machine,
+        window_id,
+        title,
+        process,
+        path,
+        started_at: at,
+        ended_at: None,
+    });
+    Ok(())
+}
+
+/// Records that nothing on `machine` has focus anymore, like when it locks or the client stops.
+#[reducer]
+pub fn end_focus(ctx: &ReducerContext, machine: String, at: Timestamp) -> Result<(), String> {
+    if machine.is_empty() {
+        return Err("Machine name must not be empty".into());
+    }
+    if end_open_span(ctx, &machine, at) {
+        info!("Focus on {machine} ended at {at}.");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(seconds: i64) -> Timestamp {
+        Timestamp::from_micros_since_unix_epoch(seconds * 1_000_000)
+    }
+
+    fn open(window_id: u64, path: Option<&str>, started_at: Timestamp) -> ActiveWindowSpan {
+        ActiveWindowSpan {
+            id: 1,
+            machine: "office".to_string(),
+            window_id,
+            title: "Downloads".to_string(),
+            process: Some("explorer.exe".to_string()),
+            path: path.map(String::from),
+            started_at,
+            ended_at: None,
+        }
+    }
+
+    #[test]
+    fn starts_a_span_when_none_is_open() {
+        assert_eq!(span_change(None, 7, &None, at(10)), Ok(SpanChange::Start));
+    }
+
+    #[test]
+    fn continues_the_span_when_focus_bounces_back() {
+        let span = open(7, Some(r"C:\Users"), at(10));
+        let path = Some(r"C:\Users".to_string());
+        assert_eq!(
+            span_change(Some(&span), 7, &path, at(20)),
+            Ok(SpanChange::Continue)
+        );
+        // Even when the change arrives late, as it changes nothing
+        assert_eq!(
+            span_change(Some(&span), 7, &path, at(5)),
+            Ok(SpanChange::Continue)
+        );
+    }
+
+    #[test]
+    fn starts_a_span_for_another_window_or_folder() {
+        let span = open(7, Some(r"C:\Users"), at(10));
+        assert_eq!(
+            span_change(Some(&span), 8, &None, at(20)),
+            Ok(SpanChange::Start)
+        );
+        // An Explorer window navigating elsewhere is a new span
+        assert_eq!(
+            span_change(Some(&span), 7, &Some(r"D:\".to_string()), at(20)),
+            Ok(SpanChange::Start)
+        );
+        assert_eq!(
+            span_change(Some(&span), 8, &None, at(10)),
+            Ok(SpanChange::Start)
+        );
+    }
+
+    #[test]
+    fn refuses_changes_older_than_the_open_span() {
+        let span = open(7, None, at(10));
+        let error = span_change(Some(&span), 8, &None, at(9)).unwrap_err();
+        assert!(error.contains("office"), "{error}");
+    }
+}
@@ -0,0 +1,258 @@
+use crate::explorer_tab::explorer_tab;
+use crate::explorer_tab::ExplorerTab;
+use log::info;
+use spacetimedb::reducer;
+use spacetimedb::table;
+use spacetimedb::Identity;
+use spacetimedb::ReducerContext;
+use spacetimedb::Table;
+use spacetimedb::Timestamp;
+
+/// Explorer tabs on one machine showing the same folder or folders inside it.
+#[table(name = duplicate_window_group, public)]
+pub struct DuplicateWindowGroup {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub machine: String,
+    /// Folder every tab of the group shows or is inside of
+    pub path: String,
+    pub tab_ids: Vec<u64>,
+    /// The most recently focused tab, which consolidating keeps open
+    pub keep_tab_id: u64,
+}
+
+/// Duplicates waiting to be closed by the client running on `machine`.
+#[table(name = consolidate_duplicates_request, public)]
+pub struct ConsolidateDuplicatesRequest {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub machine: String,
+    pub requested_by: Identity,
+    pub requested_at: Timestamp,
+}
+
+/// Case-insensitive form of a Windows path without trailing separators.
+fn normalize(path: &str) -> String {
+    path.replace('/', "\\")
+        .trim_end_matches('\\')
+        .to_lowercase()
+}
+
+/// Groups the tabs by the outermost open folder they are in, keeping only groups with more than one tab.
+fn find_groups(tabs: Vec<ExplorerTab>) -> Vec<(String, Vec<ExplorerTab>)> {
+    let mut tabs = tabs
+        .into_iter()
+        .filter_map(|tab| Some((normalize(tab.path.as_deref()?), tab)))
+        .collect::<Vec<_>>();
+    // Outer folders first, so every tab finds the group of its outermost open ancestor
+    tabs.sort_by_key(|(path, _)| path.len());
+    let mut groups: Vec<(String, String, Vec<ExplorerTab>)> = Vec::new();
+    for (path, tab) in tabs {
+        let group = groups
+            .iter_mut()
+            .find(|(root, _, _)| path == *root || path.starts_with(&format!("{root}\\")));
+        match group {
+            Some((_, _, members)) => members.push(tab),
+            None => groups.push((path, tab.path.clone().unwrap_or_default(), vec![tab])),
+        }
+    }
+    groups
+        .into_iter()
+        .filter(|(_, _, members)| members.len() > 1)
+        .map(|(_, path, members)| (path, members))
+        .collect()
+}
+
+/// Recomputes the duplicate groups of `machine` from its current Explorer tabs.
+pub fn refresh_duplicate_groups(ctx: &ReducerContext, machine: &str) {
+    let stale = ctx
+        .db
+        .duplicate_window_group()
+        .machine()
+        .filter(machine)
+        .collect::<Vec<_>>();
+    for group in stale {
+        ctx.db.duplicate_window_group().id().delete(group.id);
+    }
+    let tabs = ctx.db.explorer_tab().machine().filter(machine).collect();
+    for (path, members) in find_groups(tabs) {
+        let keep = members
+            .iter()
+            .min_by_key(|x| (x.focus_rank, !x.active))
+            .map(|x| x.tab_id)
+            .unwrap_or_default();
+        ctx.db
+            .duplicate_window_group()
+            .insert(DuplicateWindowGroup {
+                id: 0,
+                machine: machine.to_string(),
+                path,
+                tab_ids: members.iter().map(|x| x.tab_id).collect(),
+                keep_tab_id: keep,
+            });
+    }
+}
+
+/// Asks the client on `machine` to close every duplicate tab except the one each group keeps.
+#[reducer]
+pub fn consolidate_duplicates(ctx: &ReducerContext, machine: String) -> Result<(), String> {
+    if ctx
+        .db
+        .duplicate_window_group()
+        .machine()
+        .filter(&machine)
+        .next()
+        .is_none()
+    {
+        return Err(format!("{machine} has no duplicate Explorer windows"));
+    }
+    let request = ctx
+        .db
+        .consolidate_duplicates_request()
+        .insert(ConsolidateDuplicatesRequest {
+            id: 0,
+            machine,
+            requested_by: ctx.sender,
+            requested_at: ctx.timestamp,
+        });
+    info!(
+        "Duplicates on {} queued for consolidation (request {}).",
+        request.machine, request.id
+    );
+    Ok(())
+}
+
+#[reducer]
+pub fn report_duplicates_consolidated(
+    ctx: &ReducerContext,
+    request_id: u64,
+    closed: u32,
+    error: Option<String>,
+) -> Result<(), String> {
+    let Some(request) = ctx
+        .db
+        .consolidate_duplicates_request()
+        .id()
+        .find(request_id)
+    else {
+        return Err(format!("No pending consolidation with ID {request_id}"));
+    };
+    ctx.db
+        .consolidate_duplicates_request()
+        .id()
+        .delete(request_id);
+    match error {
+        None => info!(
+            "Closed {closed} duplicate tabs on {} (request {request_id}).",
+            request.machine
+        ),
+        Some(error) => info!(
+            "Closed {closed} duplicate tabs on {} (request {request_id}), then failed: {error}",
+            request.machine
+        ),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tab(tab_id: u64, path: &str) -> ExplorerTab {
+        ExplorerTab {
+            id: 0,
+            machine: "office".to_string(),
+            window_id: 1,
+            tab_id,
+            path: Some(path.to_string()),
+            title: path.to_string(),
+            active: false,
+            focus_rank: 0,
+        }
+    }
+
+    fn groups(tabs: Vec<ExplorerTab>) -> Vec<(String, Vec<u64>)> {
+        let mut groups = find_groups(tabs)
+            .into_iter()
+            .map(|(path, members)| {
+                let mut ids = members.iter().map(|x| x.tab_id).collect::<Vec<_>>();
+                ids.sort();
+                (path, ids)
+            })
+            .collect::<Vec<_>>();
+        groups.sort();
+        groups
+    }
+
+    #[test]
+    fn normalizes_case_separators_and_trailing_backslashes() {
+        assert_eq!(normalize(r"C:\Users\Me"), r"c:\users\me");
+        assert_eq!(normalize("C:/Users/Me/"), r"c:\users\me");
+        assert_eq!(normalize(r"C:\Users\Me\\"), r"c:\users\me");
+        assert_eq!(normalize(r"C:\"), "c:");
+        assert_eq!(normalize(r"\\NAS\Share\"), r"\\nas\share");
+    }
+
+    #[test]
+    fn groups_the_same_folder_however_it_is_written() {
+        assert_eq!(
+            groups(vec![
+                tab(1, r"C:\Users\Me\Downloads"),
+                tab(2, "c:/users/me/downloads/"),
+                tab(3, r"C:\USERS\ME\DOWNLOADS\"),
+            ]),
+            [(r"C:\Users\Me\Downloads".to_string(), vec![1, 2, 3])]
+        );
+    }
+
+    #[test]
+    fn groups_folders_inside_an_open_folder_under_it() {
+        assert_eq!(
+            groups(vec![
+                tab(1, r"C:\Projects\alpha\src"),
+                tab(2, r"C:\Projects"),
+                tab(3, r"C:\Projects\alpha"),
+                tab(4, r"D:\Music"),
+            ]),
+            [(r"C:\Projects".to_string(), vec![1, 2, 3])]
+        );
+    }
+
+    #[test]
+    fn groups_everything_on_an_open_drive_root() {
+        assert_eq!(
+            groups(vec![tab(1, r"C:\"), tab(2, r"C:\Windows"), tab(3, r"D:\")]),
+            [(r"C:\".to_string(), vec![1, 2])]
+        );
+    }
+
+    #[test]
+    fn groups_unc_paths_by_share() {
+        assert_eq!(
+            groups(vec![
+                tab(1, r"\\NAS\Projects"),
+                tab(2, r"\\nas\projects\alpha"),
+                tab(3, r"\\NAS\Music"),
+                tab(4, r"\\OTHER\Projects"),
+            ]),
+            [(r"\\NAS\Projects".to_string(), vec![1, 2])]
+        );
+    }
+
+    #[test]
+    fn keeps_sibling_folders_with_a_common_prefix_apart() {
+        assert!(groups(vec![tab(1, r"C:\foo"), tab(2, r"C:\foobar")]).is_empty());
+        assert!(groups(vec![tab(1, r"C:\foo"), tab(2, r"C:\foo bar\baz")]).is_empty());
+    }
+
+    #[test]
+    fn leaves_virtual_folders_out() {
+        let mut this_pc = tab(2, "");
+        this_pc.path = None;
+        assert!(groups(vec![tab(1, r"C:\"), this_pc]).is_empty());
+    }
+}
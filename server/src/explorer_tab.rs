@@ -0,0 +1,63 @@
+use crate::duplicate_window::refresh_duplicate_groups;
+use log::info;
+use spacetimedb::reducer;
+use spacetimedb::table;
+use spacetimedb::ReducerContext;
+use spacetimedb::Table;
+
+/// One tab of an Explorer window.
+#[table(name = explorer_tab, public)]
+pub struct ExplorerTab {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub machine: String,
+    /// Native handle of the Explorer window the tab is in
+    pub window_id: u64,
+    /// Native handle of the tab itself
+    pub tab_id: u64,
+    /// Folder the tab shows, `None` for virtual folders like "This PC"
+    pub path: Option<String>,
+    pub title: String,
+    /// Whether this is the selected tab of its window
+    pub active: bool,
+    /// Position of the tab's window among Explorer windows by last focus, 0 being the most recent
+    pub focus_rank: u32,
+}
+
+/// Replaces what `machine` last reported about its Explorer tabs.
+#[reducer]
+pub fn sync_explorer_tabs(
+    ctx: &ReducerContext,
+    machine: String,
+    tabs: Vec<ExplorerTab>,
+) -> Result<(), String> {
+    if machine.is_empty() {
+        return Err("Machine name must not be empty".into());
+    }
+
+    let mut existing = ctx
+        .db
+        .explorer_tab()
+        .machine()
+        .filter(&machine)
+        .collect::<Vec<_>>();
+    let tab_count = tabs.len();
+    for mut tab in tabs {
+        tab.machine = machine.clone();
+        if let Some(i) = existing.iter().position(|x| x.tab_id == tab.tab_id) {
+            tab.id = existing.swap_remove(i).id;
+            ctx.db.explorer_tab().id().update(tab);
+        } else {
+            tab.id = 0;
+            ctx.db.explorer_tab().insert(tab);
+        }
+    }
+    for stale in existing {
+        ctx.db.explorer_tab().id().delete(stale.id);
+    }
+    refresh_duplicate_groups(ctx, &machine);
+    info!("Synced {tab_count} Explorer tabs of {machine}.");
+    Ok(())
+}
@@ -0,0 +1,187 @@
+use log::info;
+use spacetimedb::reducer;
+use spacetimedb::table;
+use spacetimedb::ReducerContext;
+use spacetimedb::Table;
+use spacetimedb::Timestamp;
+use std::time::Duration;
+
+/// How long it takes a visit to count half as much towards a folder's score.
+pub const HALF_LIFE: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// A stretch of time an Explorer tab spent showing one folder.
+#[table(name = folder_visit, public)]
+pub struct FolderVisit {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub machine: String,
+    pub path: String,
+    /// When the tab navigated to the folder
+    pub visited_at: Timestamp,
+    /// How long the tab was the selected tab of the foreground window
+    pub focused_ms: u64,
+}
+
+/// How often and how recently a folder was visited, on one machine or on all of them.
+#[table(name = folder_score, public)]
+pub struct FolderScore {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub path: String,
+    /// `None` for the score across every machine
+    pub machine: Option<String>,
+    pub visits: u32,
+    pub focused_ms: u64,
+    pub last_visit: Timestamp,
+    /// Visits weighted by age as of `last_visit`, each counting half as much per [`HALF_LIFE`]
+    pub score: f64,
+}
+
+fn decay(age: Duration) -> f64 {
+    0.5f64.powf(age.as_secs_f64() / HALF_LIFE.as_secs_f64())
+}
+
+fn add_visit(ctx: &ReducerContext, machine: Option<String>, visit: &FolderVisit) {
+    let existing = ctx
+        .db
+        .folder_score()
+        .path()
+        .filter(&visit.path)
+        .find(|x| x.machine == machine);
+    let Some(mut score) = existing else {
+        ctx.db.folder_score().insert(FolderScore {
+            id: 0,
+            path: visit.path.clone(),
+            machine,
+            visits: 1,
+            focused_ms: visit.focused_ms,
+            last_visit: visit.visited_at,
+            score: 1.0,
+        });
+        return;
+    };
+    fold_visit(&mut score, visit);
+    ctx.db.folder_score().id().update(score);
+}
+
+/// Adds `visit` to `score`, decaying the score to the later of the two visits.
+fn fold_visit(score: &mut FolderScore, visit: &FolderVisit) {
+    score.visits += 1;
+    score.focused_ms += visit.focused_ms;
+    match visit.visited_at.duration_since(score.last_visit) {
+        Some(age) => {
+            score.score = score.score * decay(age) + 1.0;
+            score.last_visit = visit.visited_at;
+        }
+        // Visits can be reported out of order, an older one counts for less
+        None => {
+            let age = score
+                .last_visit
+                .duration_since(visit.visited_at)
+                .unwrap_or_default();
+            score.score += decay(age);
+        }
+    }
+}
+
+/// Records a finished visit and folds it into the machine's and the global score of the folder.
+#[reducer]
+pub fn record_folder_visit(
+    ctx: &ReducerContext,
+    machine: String,
+    path: String,
+    visited_at: Timestamp,
+    focused_ms: u64,
+) -> Result<(), String> {
+    if machine.is_empty() || path.is_empty() {
+        return Err("Machine name and path must not be empty".into());
+    }
+    let visit = ctx.db.folder_visit().insert(FolderVisit {
+        id: 0,
+        machine,
+        path,
+        visited_at,
+        focused_ms,
+    });
+    add_visit(ctx, Some(visit.machine.clone()), &visit);
+    add_visit(ctx, None, &visit);
+    info!(
+        "Visit to {} on {} recorded ({} ms focused).",
+        visit.path, visit.machine, visit.focused_ms
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(seconds: u64) -> Timestamp {
+        Timestamp::UNIX_EPOCH + Duration::from_secs(seconds)
+    }
+
+    fn visit(visited_at: Timestamp, focused_ms: u64) -> FolderVisit {
+        FolderVisit {
+            id: 0,
+            machine: "office".to_string(),
+            path: r"C:\Projects".to_string(),
+            visited_at,
+            focused_ms,
+        }
+    }
+
+    fn first_visit(visited_at: Timestamp) -> FolderScore {
+        FolderScore {
+            id: 1,
+            path: r"C:\Projects".to_string(),
+            machine: None,
+            visits: 1,
+            focused_ms: 1_000,
+            last_visit: visited_at,
+            score: 1.0,
+        }
+    }
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!((actual - expected).abs() < 1e-9, "{actual} != {expected}");
+    }
+
+    #[test]
+    fn halves_the_score_every_half_life() {
+        assert_close(decay(Duration::ZERO), 1.0);
+        assert_close(decay(HALF_LIFE), 0.5);
+        assert_close(decay(HALF_LIFE * 3), 0.125);
+    }
+
+    #[test]
+    fn decays_the_score_to_a_later_visit() {
+        let mut score = first_visit(at(0));
+        let later = at(HALF_LIFE.as_secs());
+        fold_visit(&mut score, &visit(later, 2_500));
+        assert_close(score.score, 1.5);
+        assert_eq!(score.last_visit, later);
+        assert_eq!((score.visits, score.focused_ms), (2, 3_500));
+    }
+
+    #[test]
+    fn counts_visits_at_the_same_time_in_full() {
+        let mut score = first_visit(at(100));
+        fold_visit(&mut score, &visit(at(100), 0));
+        assert_close(score.score, 2.0);
+        assert_eq!(score.last_visit, at(100));
+    }
+
+    #[test]
+    fn counts_an_older_visit_for_less_without_moving_the_last_visit() {
+        let latest = at(2 * HALF_LIFE.as_secs());
+        let mut score = first_visit(latest);
+        fold_visit(&mut score, &visit(at(0), 500));
+        assert_close(score.score, 1.25);
+        assert_eq!(score.last_visit, latest);
+        assert_eq!((score.visits, score.focused_ms), (2, 1_500));
+    }
+}
@@ -1,7 +1,12 @@
+use crate::schema_info::record_owner;
+use crate::schema_info::record_schema_version;
+use crate::schema_info::SCHEMA_VERSION;
 use spacetimedb::reducer;
 use spacetimedb::ReducerContext;
 
 #[reducer(init)]
-pub fn init(_ctx: &ReducerContext) {
-    // Called when the module is initially published
+pub fn init(ctx: &ReducerContext) {
+    // Called when the module is initially published, so the tables start out current
+    record_schema_version(ctx, SCHEMA_VERSION);
+    record_owner(ctx);
 }
@@ -1,3 +1,17 @@
+pub mod active_window;
+pub mod duplicate_window;
+pub mod explorer_tab;
+pub mod folder_visit;
 pub mod init;
-pub mod taskbar;
+pub mod machine_status;
+pub mod placement_rule;
+pub mod schema_info;
+pub mod stats;
 pub mod sync_taskbars;
+pub mod taskbar;
+pub mod taskbar_policy;
+pub mod taskbar_policy_report;
+pub mod virtual_desktop;
+pub mod watch_rule;
+pub mod window_move;
+pub mod zone_layout;
@@ -0,0 +1,133 @@
+use crate::active_window::end_open_span;
+use log::info;
+use spacetimedb::reducer;
+use spacetimedb::table;
+use spacetimedb::ConnectionId;
+use spacetimedb::ReducerContext;
+use spacetimedb::Table;
+use spacetimedb::Timestamp;
+
+/// Whether a machine's client is running, so dashboards can grey out the taskbars it left behind.
+#[table(name = machine_status, public)]
+pub struct MachineStatus {
+    #[primary_key]
+    pub machine: String,
+    pub online: bool,
+    /// When `online` last changed
+    pub since: Timestamp,
+}
+
+/// A connection a machine's taskbars were synced over, so the machine can go offline when it drops.
+#[table(name = machine_connection)]
+pub struct MachineConnection {
+    #[primary_key]
+    pub connection_id: ConnectionId,
+    #[index(btree)]
+    pub machine: String,
+}
+
+/// Records whether `machine` is online, leaving `since` alone when nothing changed.
+pub fn set_online(ctx: &ReducerContext, machine: &str, online: bool) {
+    let status = MachineStatus {
+        machine: machine.to_string(),
+        online,
+        since: ctx.timestamp,
+    };
+    match ctx.db.machine_status().machine().find(machine.to_string()) {
+        Some(existing) if existing.online == online => {}
+        Some(_) => {
+            ctx.db.machine_status().machine().update(status);
+        }
+        None => {
+            ctx.db.machine_status().insert(status);
+        }
+    }
+}
+
+/// Marks `machine` online and remembers the connection that synced it.
+pub fn machine_synced(ctx: &ReducerContext, machine: &str) {
+    set_online(ctx, machine, true);
+    let Some(connection_id) = ctx.connection_id else {
+        return;
+    };
+    let connection = MachineConnection {
+        connection_id,
+        machine: machine.to_string(),
+    };
+    match ctx
+        .db
+        .machine_connection()
+        .connection_id()
+        .find(connection_id)
+    {
+        Some(existing) if existing.machine == machine => {}
+        Some(_) => {
+            ctx.db
+                .machine_connection()
+                .connection_id()
+                .update(connection);
+        }
+        None => {
+            ctx.db.machine_connection().insert(connection);
+        }
+    }
+}
+
+fn go_offline(ctx: &ReducerContext, machine: &str) {
+    set_online(ctx, machine, false);
+    end_open_span(ctx, machine, ctx.timestamp);
+}
+
+/// Called by a client that is shutting down, marking its machine offline and ending its focus.
+#[reducer]
+pub fn client_leaving(ctx: &ReducerContext, machine: String) -> Result<(), String> {
+    if machine.is_empty() {
+        return Err("Machine name must not be empty".into());
+    }
+    if let Some(connection_id) = ctx.connection_id {
+        ctx.db
+            .machine_connection()
+            .connection_id()
+            .delete(connection_id);
+    }
+    go_offline(ctx, &machine);
+    info!("{machine} went offline.");
+    Ok(())
+}
+
+/// Marks the machine a dropped connection synced offline, for clients that crashed or lost the
+/// network without calling `client_leaving`.
+#[reducer(client_disconnected)]
+pub fn client_disconnected(ctx: &ReducerContext) {
+    let Some(connection_id) = ctx.connection_id else {
+        return;
+    };
+    let Some(connection) = ctx
+        .db
+        .machine_connection()
+        .connection_id()
+        .find(connection_id)
+    else {
+        return;
+    };
+    ctx.db
+        .machine_connection()
+        .connection_id()
+        .delete(connection_id);
+    // A client restarted before the old connection timed out still has the machine online
+    if ctx
+        .db
+        .machine_connection()
+        .machine()
+        .filter(&connection.machine)
+        .next()
+        .is_some()
+    {
+        return;
+    }
+    go_offline(ctx, &connection.machine);
+    info!(
+        "{} went offline, its connection dropped.",
+        connection.machine
+    );
+}
@@ -0,0 +1,92 @@
+use log::info;
+use spacetimedb::reducer;
+use spacetimedb::table;
+use spacetimedb::ReducerContext;
+use spacetimedb::SpacetimeType;
+use spacetimedb::Table;
+
+#[derive(SpacetimeType, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindowShowState {
+    Normal,
+    Minimized,
+    Maximized,
+}
+
+/// Part of a monitor's work area, as fractions of its width and height.
+#[derive(SpacetimeType, Debug, Clone, Copy, PartialEq)]
+pub struct PlacementRegion {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+/// Where to put newly opened windows that match every condition that is set.
+#[table(name = placement_rule, public)]
+pub struct PlacementRule {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+    pub name: String,
+    /// Machine the rule applies to, `None` for every machine
+    pub machine: Option<String>,
+    /// Glob over the folder an Explorer window shows, like `\\nas\projects\**`
+    pub path_glob: Option<String>,
+    /// Executable file name, like `explorer.exe`
+    pub process: Option<String>,
+    pub title_regex: Option<String>,
+    /// Index of the taskbar, and so the monitor, to move the window to
+    pub target_taskbar_index: u32,
+    /// `None` keeps the window's size and centers it
+    pub region: Option<PlacementRegion>,
+    pub state: Option<WindowShowState>,
+    /// Rules with a higher priority are tried first
+    pub priority: i32,
+    pub enabled: bool,
+}
+
+fn validate(rule: &PlacementRule) -> Result<(), String> {
+    if rule.path_glob.is_none() && rule.process.is_none() && rule.title_regex.is_none() {
+        return Err("A placement rule needs a path glob, process or title regex".into());
+    }
+    if let Some(region) = rule.region {
+        let valid = |start: f32, size: f32| {
+            (0.0..=1.0).contains(&start) && size > 0.0 && start + size <= 1.0
+        };
+        if !valid(region.x, region.width) || !valid(region.y, region.height) {
+            return Err(format!("Region {region:?} is not inside the work area"));
+        }
+    }
+    Ok(())
+}
+
+#[reducer]
+pub fn add_placement_rule(ctx: &ReducerContext, rule: PlacementRule) -> Result<(), String> {
+    validate(&rule)?;
+    let rule = ctx
+        .db
+        .placement_rule()
+        .insert(PlacementRule { id: 0, ..rule });
+    info!("Placement rule {} ({}) added.", rule.id, rule.name);
+    Ok(())
+}
+
+#[reducer]
+pub fn update_placement_rule(ctx: &ReducerContext, rule: PlacementRule) -> Result<(), String> {
+    validate(&rule)?;
+    if ctx.db.placement_rule().id().find(rule.id).is_none() {
+        return Err(format!("No placement rule with ID {}", rule.id));
+    }
+    let rule = ctx.db.placement_rule().id().update(rule);
+    info!("Placement rule {} ({}) updated.", rule.id, rule.name);
+    Ok(())
+}
+
+#[reducer]
+pub fn delete_placement_rule(ctx: &ReducerContext, id: u32) -> Result<(), String> {
+    if !ctx.db.placement_rule().id().delete(id) {
+        return Err(format!("No placement rule with ID {id}"));
+    }
+    info!("Placement rule {id} deleted.");
+    Ok(())
+}
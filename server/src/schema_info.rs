@@ -0,0 +1,151 @@
+use crate::machine_status::machine_status;
+use crate::machine_status::MachineStatus;
+use crate::taskbar::taskbar;
+use log::info;
+use spacetimedb::reducer;
+use spacetimedb::table;
+use spacetimedb::Identity;
+use spacetimedb::ReducerContext;
+use spacetimedb::Table;
+use spacetimedb::Timestamp;
+use std::collections::BTreeSet;
+
+/// Bumped whenever the tables or reducers change, with a step added to `migrate_schema`.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// Oldest client schema that still works against this one, raised when a change breaks clients.
+pub const MIN_CLIENT_VERSION: u32 = 1;
+
+/// The single row describing the schema the database's rows are in.
+#[table(name = schema_info, public)]
+pub struct SchemaInfo {
+    /// Always 0
+    #[primary_key]
+    pub id: u32,
+    pub version: u32,
+    pub min_client_version: u32,
+    /// Version of the module crate that last wrote this row
+    pub module_version: String,
+    pub migrated_at: Timestamp,
+}
+
+/// The identity that published the database, the only one allowed to migrate it.
+#[table(name = database_owner)]
+pub struct DatabaseOwner {
+    /// Always 0
+    #[primary_key]
+    pub id: u32,
+    pub identity: Identity,
+}
+
+/// Remembers the publisher as the owner, called by `init`, which only the publisher can trigger.
+pub fn record_owner(ctx: &ReducerContext) {
+    ctx.db.database_owner().insert(DatabaseOwner {
+        id: 0,
+        identity: ctx.sender,
+    });
+}
+
+/// Refuses `caller` unless it is the `owner`, returning the owner to record if none was.
+///
+/// Databases published before owners were recorded have none, so the first caller claims them,
+/// which is `publish.ps1` right after publishing.
+fn authorize(owner: Option<Identity>, caller: Identity) -> Result<Option<Identity>, String> {
+    match owner {
+        Some(owner) if owner == caller => Ok(None),
+        Some(_) => Err("Only the database owner can migrate the schema".into()),
+        None => Ok(Some(caller)),
+    }
+}
+
+/// The schema version the rows are in, 0 for databases published before `schema_info` existed.
+fn current_version(ctx: &ReducerContext) -> u32 {
+    ctx.db.schema_info().id().find(0).map_or(0, |x| x.version)
+}
+
+pub fn record_schema_version(ctx: &ReducerContext, version: u32) {
+    let info = SchemaInfo {
+        id: 0,
+        version,
+        min_client_version: MIN_CLIENT_VERSION,
+        module_version: env!("CARGO_PKG_VERSION").to_string(),
+        migrated_at: ctx.timestamp,
+    };
+    if ctx.db.schema_info().id().find(0).is_some() {
+        ctx.db.schema_info().id().update(info);
+    } else {
+        ctx.db.schema_info().insert(info);
+    }
+}
+
+/// Gives machines whose taskbars predate `machine_status` a status, offline until they sync again.
+fn migrate_to_1(ctx: &ReducerContext) {
+    let machines = ctx
+        .db
+        .taskbar()
+        .iter()
+        .map(|x| x.machine)
+        .collect::<BTreeSet<_>>();
+    for machine in machines {
+        if ctx.db.machine_status().machine().find(&machine).is_none() {
+            ctx.db.machine_status().insert(MachineStatus {
+                machine,
+                online: false,
+                since: ctx.timestamp,
+            });
+        }
+    }
+}
+
+/// Brings the rows of a database published by an older module up to `SCHEMA_VERSION`, one step at
+/// a time, for the database owner only.
+///
+/// Only what a republish keeps can be migrated. Databases whose `taskbar` rows predate the taskbar
+/// settings columns cannot be upgraded without deleting their data: SpacetimeDB refuses to publish
+/// over a table whose columns changed, leaving `publish.ps1 -Reset`, which deletes every row.
+#[reducer]
+pub fn migrate_schema(ctx: &ReducerContext) -> Result<(), String> {
+    let owner = ctx.db.database_owner().id().find(0).map(|x| x.identity);
+    if let Some(identity) = authorize(owner, ctx.sender)? {
+        ctx.db
+            .database_owner()
+            .insert(DatabaseOwner { id: 0, identity });
+        info!("{identity} claimed the database as its owner.");
+    }
+    let mut version = current_version(ctx);
+    if version > SCHEMA_VERSION {
+        return Err(format!(
+            "The database is at schema {version}, newer than this module's {SCHEMA_VERSION}"
+        ));
+    }
+    while version < SCHEMA_VERSION {
+        match version {
+            0 => migrate_to_1(ctx),
+            _ => return Err(format!("No migration from schema {version}")),
+        }
+        version += 1;
+        info!("Migrated to schema {version}.");
+    }
+    // Also refreshes `min_client_version` and `module_version` when only the code changed
+    record_schema_version(ctx, version);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_the_owner_may_migrate() {
+        let owner = Identity::from_byte_array([1; 32]);
+        let other = Identity::from_byte_array([2; 32]);
+        assert_eq!(authorize(Some(owner), owner), Ok(None));
+        assert!(authorize(Some(owner), other).is_err());
+    }
+
+    #[test]
+    fn the_first_caller_claims_a_database_without_owner() {
+        let caller = Identity::from_byte_array([3; 32]);
+        assert_eq!(authorize(None, caller), Ok(Some(caller)));
+    }
+}
@@ -0,0 +1,41 @@
+use spacetimedb::table;
+use spacetimedb::ReducerContext;
+use spacetimedb::Table;
+use spacetimedb::Timestamp;
+
+/// How often each machine has synced its taskbars, for telling a quiet machine from a broken one.
+#[table(name = stats, public)]
+pub struct SyncStats {
+    #[primary_key]
+    pub machine: String,
+    pub syncs: u64,
+    pub taskbars_inserted: u64,
+    pub taskbars_updated: u64,
+    pub first_sync: Timestamp,
+    pub last_sync: Timestamp,
+}
+
+/// Counts one sync of `machine` that inserted and updated the given number of taskbars.
+pub fn record_sync(ctx: &ReducerContext, machine: &str, inserted: u64, updated: u64) {
+    match ctx.db.stats().machine().find(machine.to_string()) {
+        Some(stats) => {
+            ctx.db.stats().machine().update(SyncStats {
+                syncs: stats.syncs + 1,
+                taskbars_inserted: stats.taskbars_inserted + inserted,
+                taskbars_updated: stats.taskbars_updated + updated,
+                last_sync: ctx.timestamp,
+                ..stats
+            });
+        }
+        None => {
+            ctx.db.stats().insert(SyncStats {
+                machine: machine.to_string(),
+                syncs: 1,
+                taskbars_inserted: inserted,
+                taskbars_updated: updated,
+                first_sync: ctx.timestamp,
+                last_sync: ctx.timestamp,
+            });
+        }
+    }
+}
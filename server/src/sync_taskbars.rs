@@ -1,21 +1,49 @@
+use crate::machine_status::machine_synced;
+use crate::stats::record_sync;
 use crate::taskbar::taskbar;
 use crate::taskbar::Taskbar;
 use log::info;
 use spacetimedb::reducer;
 use spacetimedb::ReducerContext;
 use spacetimedb::Table;
+use std::collections::BTreeMap;
 
 #[reducer]
 pub fn sync_taskbars(ctx: &ReducerContext, taskbars: Vec<Taskbar>) -> Result<(), String> {
-    for taskbar in taskbars {
-        let taskbar_id = taskbar.id.clone();
-        if let Some(_taskbar) = ctx.db.taskbar().id().find(&taskbar_id) {
-            ctx.db.taskbar().id().update(taskbar);
-            info!("Taskbar with ID {} updated.", taskbar_id);
+    // Inserted and updated taskbars per machine
+    let mut counts = BTreeMap::<String, (u64, u64)>::new();
+    for mut taskbar in taskbars {
+        if taskbar.machine.is_empty() {
+            return Err(format!("Taskbar {} has no machine name", taskbar.index));
+        }
+        let existing = ctx
+            .db
+            .taskbar()
+            .machine()
+            .filter(&taskbar.machine)
+            .find(|x| x.index == taskbar.index);
+        let count = counts.entry(taskbar.machine.clone()).or_default();
+        if let Some(existing) = existing {
+            count.1 += 1;
+            taskbar.id = existing.id;
+            let taskbar = ctx.db.taskbar().id().update(taskbar);
+            info!(
+                "Taskbar {} of {} updated (ID {}).",
+                taskbar.index, taskbar.machine, taskbar.id
+            );
         } else {
-            ctx.db.taskbar().insert(taskbar);
-            info!("Taskbar with ID {} inserted.", taskbar_id);
+            count.0 += 1;
+            taskbar.id = 0;
+            let taskbar = ctx.db.taskbar().insert(taskbar);
+            info!(
+                "Taskbar {} of {} inserted (ID {}).",
+                taskbar.index, taskbar.machine, taskbar.id
+            );
         }
     }
+    for (machine, (inserted, updated)) in counts {
+        record_sync(ctx, &machine, inserted, updated);
+        machine_synced(ctx, &machine);
+    }
     Ok(())
 }
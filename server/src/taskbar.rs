@@ -1,15 +1,49 @@
 use spacetimedb::table;
+use spacetimedb::SpacetimeType;
 
 pub type TaskbarId = u32;
 
+#[derive(SpacetimeType, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskbarEdge {
+    Left,
+    Top,
+    Right,
+    Bottom,
+}
+
+#[derive(SpacetimeType, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskbarAlignment {
+    Left,
+    Center,
+}
+
+/// How the buttons of a taskbar's windows are grouped, stored as `TaskbarGlomLevel` on Windows.
+#[derive(SpacetimeType, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskbarCombineButtons {
+    Always,
+    WhenFull,
+    Never,
+}
+
 #[table(name = taskbar, public)]
 pub struct Taskbar {
     #[primary_key]
+    #[auto_inc]
     pub id: TaskbarId,
+    /// Host name of the machine that reported this taskbar
+    #[index(btree)]
+    pub machine: String,
+    /// Position of the taskbar in the machine's own enumeration order
+    pub index: u32,
     pub is_secondary: bool,
     pub width: u32,
     pub height: u32,
     pub x: i32,
     pub y: i32,
     pub apps: Vec<String>,
+    pub auto_hide: bool,
+    pub edge: TaskbarEdge,
+    pub alignment: TaskbarAlignment,
+    pub locked: bool,
+    pub combine_buttons: TaskbarCombineButtons,
 }
@@ -0,0 +1,71 @@
+use crate::taskbar::TaskbarAlignment;
+use crate::taskbar::TaskbarCombineButtons;
+use crate::taskbar::TaskbarEdge;
+use log::info;
+use spacetimedb::reducer;
+use spacetimedb::table;
+use spacetimedb::ReducerContext;
+use spacetimedb::Table;
+
+/// Desired taskbar settings, `None` fields are left as the user has them.
+///
+/// A policy without a machine applies everywhere, a machine policy overrides it field by field.
+#[table(name = taskbar_policy, public)]
+pub struct TaskbarPolicy {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+    pub machine: Option<String>,
+    pub auto_hide: Option<bool>,
+    pub edge: Option<TaskbarEdge>,
+    pub alignment: Option<TaskbarAlignment>,
+    pub combine_buttons: Option<TaskbarCombineButtons>,
+}
+
+fn find_policy(ctx: &ReducerContext, machine: &Option<String>) -> Option<TaskbarPolicy> {
+    ctx.db
+        .taskbar_policy()
+        .iter()
+        .find(|x| &x.machine == machine)
+}
+
+#[reducer]
+pub fn set_taskbar_policy(
+    ctx: &ReducerContext,
+    machine: Option<String>,
+    auto_hide: Option<bool>,
+    edge: Option<TaskbarEdge>,
+    alignment: Option<TaskbarAlignment>,
+    combine_buttons: Option<TaskbarCombineButtons>,
+) -> Result<(), String> {
+    if machine.as_deref() == Some("") {
+        return Err("Machine name must not be empty, use None for the global policy".into());
+    }
+    let mut policy = TaskbarPolicy {
+        id: 0,
+        machine,
+        auto_hide,
+        edge,
+        alignment,
+        combine_buttons,
+    };
+    if let Some(existing) = find_policy(ctx, &policy.machine) {
+        policy.id = existing.id;
+        let policy = ctx.db.taskbar_policy().id().update(policy);
+        info!("Taskbar policy for {:?} updated.", policy.machine);
+    } else {
+        let policy = ctx.db.taskbar_policy().insert(policy);
+        info!("Taskbar policy for {:?} inserted.", policy.machine);
+    }
+    Ok(())
+}
+
+#[reducer]
+pub fn clear_taskbar_policy(ctx: &ReducerContext, machine: Option<String>) -> Result<(), String> {
+    let Some(existing) = find_policy(ctx, &machine) else {
+        return Err(format!("No taskbar policy for {machine:?}"));
+    };
+    ctx.db.taskbar_policy().id().delete(existing.id);
+    info!("Taskbar policy for {:?} cleared.", machine);
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use log::info;
+use spacetimedb::reducer;
+use spacetimedb::table;
+use spacetimedb::ReducerContext;
+use spacetimedb::SpacetimeType;
+use spacetimedb::Table;
+use spacetimedb::Timestamp;
+
+/// What came of a client's attempt to bring a drifted setting in line with policy.
+#[derive(SpacetimeType, Debug, Clone, PartialEq)]
+pub enum DriftOutcome {
+    Applied,
+    /// Only logged, the client was told to dry run
+    DryRun,
+    Failed(String),
+}
+
+/// A setting that differed from policy, and what happened when the client tried to fix it.
+#[derive(SpacetimeType, Debug, Clone, PartialEq)]
+pub struct TaskbarSettingDrift {
+    pub setting: String,
+    pub expected: String,
+    pub actual: String,
+    pub outcome: DriftOutcome,
+}
+
+/// The most recent reconciliation each machine performed against its taskbar policy.
+#[table(name = taskbar_policy_report, public)]
+pub struct TaskbarPolicyReport {
+    #[primary_key]
+    pub machine: String,
+    pub reported_at: Timestamp,
+    pub drift: Vec<TaskbarSettingDrift>,
+}
+
+#[reducer]
+pub fn report_taskbar_policy(
+    ctx: &ReducerContext,
+    machine: String,
+    drift: Vec<TaskbarSettingDrift>,
+) -> Result<(), String> {
+    if machine.is_empty() {
+        return Err("Machine name must not be empty".into());
+    }
+    let dry_run = drift
+        .iter()
+        .filter(|x| x.outcome == DriftOutcome::DryRun)
+        .count();
+    let failed = drift
+        .iter()
+        .filter(|x| matches!(x.outcome, DriftOutcome::Failed(_)))
+        .count();
+    info!(
+        "Machine {} reported {} drifted taskbar settings, {} failed to apply, {} dry run.",
+        machine,
+        drift.len(),
+        failed,
+        dry_run
+    );
+    let report = TaskbarPolicyReport {
+        machine,
+        reported_at: ctx.timestamp,
+        drift,
+    };
+    if ctx
+        .db
+        .taskbar_policy_report()
+        .machine()
+        .find(&report.machine)
+        .is_some()
+    {
+        ctx.db.taskbar_policy_report().machine().update(report);
+    } else {
+        ctx.db.taskbar_policy_report().insert(report);
+    }
+    Ok(())
+}
@@ -0,0 +1,144 @@
+use log::info;
+use spacetimedb::reducer;
+use spacetimedb::table;
+use spacetimedb::ReducerContext;
+use spacetimedb::Table;
+
+#[table(name = virtual_desktop, public)]
+pub struct VirtualDesktop {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub machine: String,
+    pub guid: String,
+    pub name: String,
+    pub is_current: bool,
+}
+
+/// A top-level window and the virtual desktop it lives on.
+#[table(name = desktop_window, public)]
+pub struct DesktopWindow {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub machine: String,
+    /// Native window handle, the `HWND` on Windows
+    pub window_id: u64,
+    pub title: String,
+    /// GUID of the window's virtual desktop, `None` when pinned to every desktop or unknown
+    pub desktop_guid: Option<String>,
+}
+
+/// Pairs each reported row with the ID of the stored row it replaces, `None` when it is new,
+/// and lists the IDs of the stored rows nothing was reported for.
+fn merge<T, K: PartialEq>(
+    mut existing: Vec<(u64, K)>,
+    reported: Vec<T>,
+    key: impl Fn(&T) -> K,
+) -> (Vec<(Option<u64>, T)>, Vec<u64>) {
+    let rows = reported
+        .into_iter()
+        .map(|row| {
+            let id = existing
+                .iter()
+                .position(|(_, x)| *x == key(&row))
+                .map(|i| existing.swap_remove(i).0);
+            (id, row)
+        })
+        .collect();
+    (rows, existing.into_iter().map(|(id, _)| id).collect())
+}
+
+/// Replaces what `machine` last reported about its virtual desktops and windows.
+#[reducer]
+pub fn sync_virtual_desktops(
+    ctx: &ReducerContext,
+    machine: String,
+    desktops: Vec<VirtualDesktop>,
+    windows: Vec<DesktopWindow>,
+) -> Result<(), String> {
+    if machine.is_empty() {
+        return Err("Machine name must not be empty".into());
+    }
+
+    let existing = ctx
+        .db
+        .virtual_desktop()
+        .machine()
+        .filter(&machine)
+        .map(|x| (x.id, x.guid))
+        .collect();
+    let desktop_count = desktops.len();
+    let (desktops, stale) = merge(existing, desktops, |x| x.guid.clone());
+    for (id, mut desktop) in desktops {
+        desktop.machine = machine.clone();
+        desktop.id = id.unwrap_or(0);
+        if id.is_some() {
+            ctx.db.virtual_desktop().id().update(desktop);
+        } else {
+            ctx.db.virtual_desktop().insert(desktop);
+        }
+    }
+    for id in stale {
+        ctx.db.virtual_desktop().id().delete(id);
+    }
+
+    let existing = ctx
+        .db
+        .desktop_window()
+        .machine()
+        .filter(&machine)
+        .map(|x| (x.id, x.window_id))
+        .collect();
+    let window_count = windows.len();
+    let (windows, stale) = merge(existing, windows, |x| x.window_id);
+    for (id, mut window) in windows {
+        window.machine = machine.clone();
+        window.id = id.unwrap_or(0);
+        if id.is_some() {
+            ctx.db.desktop_window().id().update(window);
+        } else {
+            ctx.db.desktop_window().insert(window);
+        }
+    }
+    for id in stale {
+        ctx.db.desktop_window().id().delete(id);
+    }
+    info!("Synced {desktop_count} virtual desktops and {window_count} windows of {machine}.");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_the_ids_of_rows_reported_again() {
+        let existing = vec![(1, "{A}"), (2, "{B}")];
+        let (rows, stale) = merge(existing, vec!["{B}", "{C}", "{A}"], |x| *x);
+        assert_eq!(rows, [(Some(2), "{B}"), (None, "{C}"), (Some(1), "{A}")]);
+        assert!(stale.is_empty());
+    }
+
+    #[test]
+    fn deletes_rows_no_longer_reported() {
+        let existing = vec![(1, 100), (2, 200), (3, 300)];
+        let (rows, mut stale) = merge(existing, vec![200], |x| *x);
+        stale.sort();
+        assert_eq!(rows, [(Some(2), 200)]);
+        assert_eq!(stale, [1, 3]);
+
+        let (rows, stale) = merge(vec![(4, 400)], Vec::<u64>::new(), |x| *x);
+        assert!(rows.is_empty());
+        assert_eq!(stale, [4]);
+    }
+
+    #[test]
+    fn matches_each_stored_row_once() {
+        let (rows, stale) = merge(vec![(1, "{A}")], vec!["{A}", "{A}"], |x| *x);
+        assert_eq!(rows, [(Some(1), "{A}"), (None, "{A}")]);
+        assert!(stale.is_empty());
+    }
+}
@@ -0,0 +1,64 @@
+use log::info;
+use spacetimedb::reducer;
+use spacetimedb::table;
+use spacetimedb::ReducerContext;
+use spacetimedb::SpacetimeType;
+use spacetimedb::Table;
+
+#[derive(SpacetimeType, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WatchEvent {
+    /// The app got a taskbar button
+    Appear,
+    /// The app's last taskbar button went away
+    Disappear,
+}
+
+/// An app whose taskbar button coming or going should raise an alert.
+#[table(name = watch_rule, public)]
+pub struct WatchRule {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+    pub name: String,
+    /// Regex matched against the app titles of a taskbar
+    pub title_regex: String,
+    /// Machine the rule applies to, `None` for every machine
+    pub machine: Option<String>,
+    pub event: WatchEvent,
+    pub enabled: bool,
+}
+
+fn validate(rule: &WatchRule) -> Result<(), String> {
+    if rule.title_regex.is_empty() {
+        return Err("A watch rule needs a title regex".into());
+    }
+    Ok(())
+}
+
+#[reducer]
+pub fn add_watch_rule(ctx: &ReducerContext, rule: WatchRule) -> Result<(), String> {
+    validate(&rule)?;
+    let rule = ctx.db.watch_rule().insert(WatchRule { id: 0, ..rule });
+    info!("Watch rule {} ({}) added.", rule.id, rule.name);
+    Ok(())
+}
+
+#[reducer]
+pub fn update_watch_rule(ctx: &ReducerContext, rule: WatchRule) -> Result<(), String> {
+    validate(&rule)?;
+    if ctx.db.watch_rule().id().find(rule.id).is_none() {
+        return Err(format!("No watch rule with ID {}", rule.id));
+    }
+    let rule = ctx.db.watch_rule().id().update(rule);
+    info!("Watch rule {} ({}) updated.", rule.id, rule.name);
+    Ok(())
+}
+
+#[reducer]
+pub fn delete_watch_rule(ctx: &ReducerContext, id: u32) -> Result<(), String> {
+    if !ctx.db.watch_rule().id().delete(id) {
+        return Err(format!("No watch rule with ID {id}"));
+    }
+    info!("Watch rule {id} deleted.");
+    Ok(())
+}
@@ -0,0 +1,95 @@
+use crate::taskbar::taskbar;
+use crate::taskbar::TaskbarId;
+use log::info;
+use spacetimedb::reducer;
+use spacetimedb::table;
+use spacetimedb::Identity;
+use spacetimedb::ReducerContext;
+use spacetimedb::Table;
+use spacetimedb::Timestamp;
+
+/// A window waiting to be moved by the client running on `machine`.
+#[table(name = window_move_request, public)]
+pub struct WindowMoveRequest {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub machine: String,
+    /// Native window handle, the `HWND` on Windows
+    pub window_id: u64,
+    pub target_taskbar_id: TaskbarId,
+    pub requested_by: Identity,
+    pub requested_at: Timestamp,
+}
+
+/// Outcome of a [`WindowMoveRequest`], keyed by the request's ID.
+#[table(name = window_move_result, public)]
+pub struct WindowMoveResult {
+    #[primary_key]
+    pub request_id: u64,
+    pub machine: String,
+    pub window_id: u64,
+    pub target_taskbar_id: TaskbarId,
+    pub requested_by: Identity,
+    /// `None` when the window was moved
+    pub error: Option<String>,
+    pub completed_at: Timestamp,
+}
+
+#[reducer]
+pub fn move_window(
+    ctx: &ReducerContext,
+    machine: String,
+    window_id: u64,
+    target_taskbar_id: TaskbarId,
+) -> Result<(), String> {
+    let Some(target) = ctx.db.taskbar().id().find(target_taskbar_id) else {
+        return Err(format!("No taskbar with ID {target_taskbar_id}"));
+    };
+    if target.machine != machine {
+        return Err(format!(
+            "Taskbar {target_taskbar_id} belongs to {}, not {machine}",
+            target.machine
+        ));
+    }
+    let request = ctx.db.window_move_request().insert(WindowMoveRequest {
+        id: 0,
+        machine,
+        window_id,
+        target_taskbar_id,
+        requested_by: ctx.sender,
+        requested_at: ctx.timestamp,
+    });
+    info!(
+        "Window {} on {} queued to move to taskbar {} (request {}).",
+        request.window_id, request.machine, request.target_taskbar_id, request.id
+    );
+    Ok(())
+}
+
+#[reducer]
+pub fn report_window_move(
+    ctx: &ReducerContext,
+    request_id: u64,
+    error: Option<String>,
+) -> Result<(), String> {
+    let Some(request) = ctx.db.window_move_request().id().find(request_id) else {
+        return Err(format!("No pending window move with ID {request_id}"));
+    };
+    ctx.db.window_move_request().id().delete(request_id);
+    match &error {
+        None => info!("Window move {request_id} completed."),
+        Some(e) => info!("Window move {request_id} failed: {e}"),
+    }
+    ctx.db.window_move_result().insert(WindowMoveResult {
+        request_id,
+        machine: request.machine,
+        window_id: request.window_id,
+        target_taskbar_id: request.target_taskbar_id,
+        requested_by: request.requested_by,
+        error,
+        completed_at: ctx.timestamp,
+    });
+    Ok(())
+}
@@ -0,0 +1,129 @@
+use log::info;
+use spacetimedb::reducer;
+use spacetimedb::table;
+use spacetimedb::ReducerContext;
+use spacetimedb::Table;
+
+/// A grid of snapping zones, one variant of a named layout for a given monitor resolution.
+#[table(name = zone_layout, public)]
+pub struct ZoneLayout {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+    #[index(btree)]
+    pub name: String,
+    /// Monitor resolution in physical pixels this variant is meant for
+    pub width: u32,
+    pub height: u32,
+    /// Relative widths of the columns, left to right
+    pub columns: Vec<f32>,
+    /// Relative heights of the rows, top to bottom
+    pub rows: Vec<f32>,
+    /// Space around and between zones, in pixels at 96 DPI
+    pub gap: u32,
+}
+
+/// The layout used by the monitor of one taskbar.
+#[table(name = monitor_zone_layout, public)]
+pub struct MonitorZoneLayout {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+    #[index(btree)]
+    pub machine: String,
+    pub taskbar_index: u32,
+    pub layout: String,
+}
+
+fn validate(layout: &ZoneLayout) -> Result<(), String> {
+    if layout.name.is_empty() {
+        return Err("A zone layout needs a name".into());
+    }
+    if layout.width == 0 || layout.height == 0 {
+        return Err("A zone layout needs a resolution".into());
+    }
+    let valid = |weights: &[f32]| !weights.is_empty() && weights.iter().all(|x| *x > 0.0);
+    if !valid(&layout.columns) || !valid(&layout.rows) {
+        return Err("Columns and rows need at least one positive weight each".into());
+    }
+    Ok(())
+}
+
+/// Adds a layout variant, or replaces the one with the same name and resolution.
+#[reducer]
+pub fn set_zone_layout(ctx: &ReducerContext, layout: ZoneLayout) -> Result<(), String> {
+    validate(&layout)?;
+    let existing = ctx
+        .db
+        .zone_layout()
+        .name()
+        .filter(&layout.name)
+        .find(|x| x.width == layout.width && x.height == layout.height);
+    match existing {
+        Some(existing) => {
+            ctx.db.zone_layout().id().update(ZoneLayout {
+                id: existing.id,
+                ..layout
+            });
+        }
+        None => {
+            ctx.db.zone_layout().insert(ZoneLayout { id: 0, ..layout });
+        }
+    }
+    Ok(())
+}
+
+#[reducer]
+pub fn delete_zone_layout(ctx: &ReducerContext, id: u32) -> Result<(), String> {
+    let Some(layout) = ctx.db.zone_layout().id().find(id) else {
+        return Err(format!("No zone layout with ID {id}"));
+    };
+    ctx.db.zone_layout().id().delete(id);
+    info!(
+        "Zone layout {} ({}x{}) deleted.",
+        layout.name, layout.width, layout.height
+    );
+    Ok(())
+}
+
+/// Assigns the layout called `layout` to the monitor of a taskbar, `None` unassigns it.
+#[reducer]
+pub fn assign_zone_layout(
+    ctx: &ReducerContext,
+    machine: String,
+    taskbar_index: u32,
+    layout: Option<String>,
+) -> Result<(), String> {
+    if let Some(name) = &layout {
+        if ctx.db.zone_layout().name().filter(name).next().is_none() {
+            return Err(format!("No zone layout named {name}"));
+        }
+    }
+    let existing = ctx
+        .db
+        .monitor_zone_layout()
+        .machine()
+        .filter(&machine)
+        .find(|x| x.taskbar_index == taskbar_index);
+    match (existing, layout) {
+        (Some(existing), Some(layout)) => {
+            ctx.db
+                .monitor_zone_layout()
+                .id()
+                .update(MonitorZoneLayout { layout, ..existing });
+        }
+        (None, Some(layout)) => {
+            ctx.db.monitor_zone_layout().insert(MonitorZoneLayout {
+                id: 0,
+                machine,
+                taskbar_index,
+                layout,
+            });
+        }
+        (Some(existing), None) => {
+            ctx.db.monitor_zone_layout().id().delete(existing.id);
+        }
+        (None, None) => {}
+    }
+    Ok(())
+}